@@ -2,18 +2,31 @@
 //!
 //! This module provides:
 //! - Backup creation with timestamp generation
+//! - Incremental backups layered over the latest full backup
 //! - Garbage collection for old backups based on retention policy
 //! - Backup listing and metadata queries
 
+use crate::backup_stats::update_statistics;
 use crate::config as config_module;
-use crate::config::ConfigError;
-use crate::file_ops::{create_tar_gz, delete_file, get_file_size, FileOpsError, FileOpsResult};
+use crate::config::{ConfigError, RetentionMode};
+use crate::file_ops::{
+    calculate_checksum_sha256, check_cancelled, create_tar_gz_with_options,
+    create_tar_gz_with_progress, create_zip_with_options, delete_dir_recursive, delete_file,
+    extract_archive_with_options, extract_tar_gz_over, get_file_size_human, is_excluded,
+    list_tar_gz_files, normalize_path_for_display, read_archive_meta, read_tar_gz_pax_metadata,
+    verify_archive, ArchiveFormat, ArchiveMeta, ArchiveOptions, ArchivedFile, CancelFlag,
+    ExtractOptions, FileOpsError, FileOpsResult,
+};
+use crate::restore::{get_nested_undo_snapshot_root, get_undo_snapshot_dir, UNDO_SNAPSHOT_ROOT};
 use crate::tags::Tag;
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, TimeZone, Utc};
 use serde::{Deserialize, Serialize, Serializer};
 use std::fs;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::SystemTime;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 /// Backup information returned to the frontend.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +49,73 @@ pub struct BackupInfo {
     /// Base64-encoded thumbnail image (thumb.png) data URL, if exists in backup
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_data: Option<String>,
+    /// Optional label given when the backup was created
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Archive format, detected from the file name
+    #[serde(default)]
+    pub archive_format: ArchiveFormat,
+    /// Whether the archive matches its `.sha256` sidecar (None if there is no sidecar)
+    #[serde(default)]
+    pub checksum_verified: Option<bool>,
+    /// Whether the backup is self-contained or layered over another backup
+    #[serde(default)]
+    pub kind: BackupKind,
+    /// Whether the backup is pinned, i.e. never deleted by GC (see `set_backup_pinned`)
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// Whether a backup holds the whole save or only the files changed since its parent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    /// Self-contained backup of the whole save
+    #[default]
+    Full,
+    /// Holds only files changed since a full backup (`BackupManifest::parent`)
+    Incremental,
+}
+
+impl BackupInfo {
+    /// Resolves this backup's path to an absolute path.
+    ///
+    /// # Arguments
+    /// * `backup_base` - Current backup base directory
+    ///
+    /// # Returns
+    /// `path` joined onto `backup_base` if it is relative, otherwise `path` unchanged
+    pub fn resolve_path(&self, backup_base: &Path) -> PathBuf {
+        resolve_against_base(&self.path, backup_base)
+    }
+}
+
+/// Joins a reported path onto `backup_base` unless it is already absolute.
+pub(crate) fn resolve_against_base(path: &str, backup_base: &Path) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        backup_base.join(path)
+    }
+}
+
+/// Returns `path` relative to `backup_base_path` if `use_relative_paths` is enabled.
+///
+/// # Returns
+/// `None` when relative paths are disabled or `path` is outside the backup base,
+/// in which case callers report the absolute path.
+pub(crate) fn relative_backup_path(
+    config: &config_module::Config,
+    backup_base_path: &Path,
+    path: &Path,
+) -> Option<String> {
+    if !config.use_relative_paths {
+        return None;
+    }
+    path.strip_prefix(backup_base_path)
+        .ok()
+        .map(crate::file_ops::normalize_path_for_display)
 }
 
 /// Result of a backup creation operation.
@@ -43,12 +123,348 @@ pub struct BackupInfo {
 pub struct BackupResult {
     /// Path to the created backup
     pub backup_path: String,
+    /// Directory containing the created backup
+    pub backup_dir: String,
     /// Name of the backup directory
     pub backup_name: String,
     /// Number of backups retained after GC
+    /// (if GC runs in the background: number of backups before GC)
     pub retained_count: usize,
-    /// Number of backups deleted by GC
+    /// Number of backups deleted by GC (0 if GC runs in the background)
     pub deleted_count: usize,
+    /// Set if the backup completed but is missing some files
+    #[serde(default)]
+    pub warning: Option<BackupWarning>,
+}
+
+/// Problems that did not stop a backup from being created.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupWarning {
+    /// Files left out because another process (usually the game) had them locked
+    pub skipped_files: Vec<PathBuf>,
+}
+
+/// Options controlling a backup operation.
+///
+/// Use `BackupOptions::from_config` to start from the user's configured defaults.
+/// Missing fields deserialize to the built-in defaults; options sent by the frontend
+/// go through `BackupOptionsPatch` instead so they keep the configured ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupOptions {
+    /// Archive format to write
+    pub compression: ArchiveFormat,
+    /// Gzip compression level from 0 (fastest) to 9 (smallest)
+    pub compression_level: u8,
+    /// Number of threads compressing tar.gz archives (see `create_tar_gz_parallel`)
+    pub threads: usize,
+    /// Wildcard patterns for files and directories to leave out of the backup
+    pub exclude_patterns: Vec<String>,
+    /// Verify the archive after creating it, deleting it if it is corrupt
+    pub verify_after_create: bool,
+    /// Emit Tauri progress events while the backup runs (handled by the command layer)
+    pub emit_progress: bool,
+    /// Label stored alongside the backup
+    pub label: Option<String>,
+    /// Wait for garbage collection to finish before returning (default: true)
+    pub await_gc: bool,
+    /// Only store files changed since the latest full tar.gz backup (see
+    /// `create_incremental_backup`); ignored for zip backups
+    pub incremental: bool,
+    /// Set to stop the backup early with `FileOpsError::Cancelled` (see
+    /// `CancellationRegistry`); never sent by the frontend
+    #[serde(skip)]
+    pub cancel: Option<CancelFlag>,
+    /// The caller's permit for the save (see `GlobalBackupSemaphores`); garbage collection
+    /// that isn't awaited keeps it until it is done. Never sent by the frontend
+    #[serde(skip)]
+    pub permit: Option<Arc<SavePermit>>,
+}
+
+impl BackupOptions {
+    /// Builds backup options from the configured defaults.
+    pub fn from_config(config: &config_module::Config) -> Self {
+        Self {
+            compression: config.archive_format,
+            compression_level: config.compression_level,
+            threads: config.backup_threads,
+            exclude_patterns: config.exclude_patterns.clone(),
+            verify_after_create: config.verify_after_create,
+            emit_progress: false,
+            label: None,
+            await_gc: true,
+            incremental: false,
+            cancel: None,
+            permit: None,
+        }
+    }
+}
+
+impl Default for BackupOptions {
+    fn default() -> Self {
+        Self::from_config(&config_module::Config::default())
+    }
+}
+
+/// Backup options as sent by the frontend, where every field is optional.
+///
+/// The fields mean the same as in `BackupOptions`. Fields left out keep the value of
+/// the options the patch is applied to (see `BackupOptionsPatch::apply`), usually
+/// `BackupOptions::from_config`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupOptionsPatch {
+    pub compression: Option<ArchiveFormat>,
+    pub compression_level: Option<u8>,
+    pub threads: Option<usize>,
+    pub exclude_patterns: Option<Vec<String>>,
+    pub verify_after_create: Option<bool>,
+    pub emit_progress: Option<bool>,
+    pub label: Option<String>,
+    pub await_gc: Option<bool>,
+    pub incremental: Option<bool>,
+}
+
+impl BackupOptionsPatch {
+    /// Overrides the fields of `base` that are set in this patch.
+    pub fn apply(self, base: BackupOptions) -> BackupOptions {
+        BackupOptions {
+            compression: self.compression.unwrap_or(base.compression),
+            compression_level: self.compression_level.unwrap_or(base.compression_level),
+            threads: self.threads.unwrap_or(base.threads),
+            exclude_patterns: self.exclude_patterns.unwrap_or(base.exclude_patterns),
+            verify_after_create: self.verify_after_create.unwrap_or(base.verify_after_create),
+            emit_progress: self.emit_progress.unwrap_or(base.emit_progress),
+            label: self.label.or(base.label),
+            await_gc: self.await_gc.unwrap_or(base.await_gc),
+            incremental: self.incremental.unwrap_or(base.incremental),
+            cancel: base.cancel,
+            permit: base.permit,
+        }
+    }
+}
+
+/// Sidecar metadata stored next to a backup archive (`<backup>.json`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Optional label given when the backup was created
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Files in the archive (None for backups made before the inventory was recorded)
+    #[serde(default)]
+    pub file_inventory: Option<Vec<ManifestFileEntry>>,
+    /// True if the archive holds more files than `file_inventory` lists
+    #[serde(default)]
+    pub file_count_truncated: bool,
+    /// SHA-256 checksum of the archive (None for backups made before it was recorded)
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// File count and uncompressed size of the archive (None for older backups)
+    #[serde(default)]
+    pub archive_meta: Option<ArchiveMeta>,
+    /// Full or incremental; backups made before incremental backups existed are full
+    #[serde(default)]
+    pub kind: BackupKind,
+    /// Name of the full backup an incremental backup is layered over
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// For incremental backups: every file of the save, mapped to the name of the
+    /// backup (this one or `parent`) that holds it
+    #[serde(default)]
+    pub file_sources: Option<BTreeMap<String, String>>,
+}
+
+/// A file listed in a backup manifest's inventory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestFileEntry {
+    /// Path relative to the save directory (using `/` separators)
+    pub path: String,
+    /// File size in bytes
+    pub size_bytes: u64,
+    /// ISO 8601 timestamp of the file's last modification
+    #[serde(default)]
+    pub modified_at: Option<String>,
+}
+
+impl From<ArchivedFile> for ManifestFileEntry {
+    fn from(file: ArchivedFile) -> Self {
+        Self {
+            path: file.path,
+            size_bytes: file.size_bytes,
+            modified_at: file
+                .modified
+                .map(|modified| DateTime::<Utc>::from(modified).to_rfc3339()),
+        }
+    }
+}
+
+/// Returns the path of the sidecar manifest for a backup archive.
+fn backup_manifest_path(backup_path: &Path) -> PathBuf {
+    let mut name = backup_path.as_os_str().to_os_string();
+    name.push(".json");
+    PathBuf::from(name)
+}
+
+/// Reads a backup's manifest, if there is one.
+pub(crate) fn read_backup_manifest(backup_path: &Path) -> Option<BackupManifest> {
+    let content = fs::read_to_string(backup_manifest_path(backup_path)).ok()?;
+    serde_json::from_str::<BackupManifest>(&content).ok()
+}
+
+/// Writes a backup's manifest, replacing any existing one.
+fn write_backup_manifest(backup_path: &Path, manifest: &BackupManifest) -> FileOpsResult<()> {
+    let json = serde_json::to_string_pretty(manifest).map_err(std::io::Error::from)?;
+    fs::write(backup_manifest_path(backup_path), json)?;
+    Ok(())
+}
+
+/// Returns the path of the SHA-256 checksum sidecar for a backup archive.
+fn backup_checksum_path(backup_path: &Path) -> PathBuf {
+    let mut name = backup_path.as_os_str().to_os_string();
+    name.push(".sha256");
+    PathBuf::from(name)
+}
+
+/// Writes a backup's checksum sidecar in `sha256sum` format (`<hex>  <file name>`).
+fn write_backup_checksum(backup_path: &Path, sha256: &str) -> std::io::Result<()> {
+    let file_name = backup_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    fs::write(backup_checksum_path(backup_path), format!("{}  {}\n", sha256, file_name))?;
+    cache_backup_checksum(backup_path, sha256);
+    Ok(())
+}
+
+/// SHA-256 of each archive seen by `check_backup_checksum`, with the size and
+/// modification time it had when hashed, so listings don't rehash unchanged archives.
+static CHECKSUM_CACHE: Mutex<BTreeMap<PathBuf, (u64, SystemTime, String)>> =
+    Mutex::new(BTreeMap::new());
+
+/// Maximum number of archives kept in `CHECKSUM_CACHE`.
+const CHECKSUM_CACHE_MAX_ENTRIES: usize = 1024;
+
+/// Returns the archive's size and modification time, the key of `CHECKSUM_CACHE`.
+fn checksum_cache_key(backup_path: &Path) -> std::io::Result<(u64, SystemTime)> {
+    let metadata = fs::metadata(backup_path)?;
+    Ok((metadata.len(), metadata.modified()?))
+}
+
+/// Remembers the SHA-256 of an archive as it is now.
+fn cache_backup_checksum(backup_path: &Path, sha256: &str) {
+    if let Ok((len, modified)) = checksum_cache_key(backup_path) {
+        let mut cache = CHECKSUM_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        if cache.len() >= CHECKSUM_CACHE_MAX_ENTRIES && !cache.contains_key(backup_path) {
+            // Archives deleted outside the app go first, then the oldest archive
+            cache.retain(|path, _| path.exists());
+            if cache.len() >= CHECKSUM_CACHE_MAX_ENTRIES {
+                let oldest = cache
+                    .iter()
+                    .min_by_key(|(_, (_, modified, _))| *modified)
+                    .map(|(path, _)| path.clone());
+                if let Some(oldest) = oldest {
+                    cache.remove(&oldest);
+                }
+            }
+        }
+        cache.insert(backup_path.to_path_buf(), (len, modified, sha256.to_string()));
+    }
+}
+
+/// Forgets the cached SHA-256 of an archive, e.g. once it has been deleted.
+fn forget_backup_checksum(backup_path: &Path) {
+    let mut cache = CHECKSUM_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    cache.remove(backup_path);
+}
+
+/// Returns the cached SHA-256 of an archive if it hasn't changed since it was hashed.
+fn cached_backup_checksum(backup_path: &Path) -> Option<String> {
+    let (len, modified) = checksum_cache_key(backup_path).ok()?;
+    let cache = CHECKSUM_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    let (cached_len, cached_modified, sha256) = cache.get(backup_path)?;
+    (*cached_len == len && *cached_modified == modified).then(|| sha256.clone())
+}
+
+/// Compares a backup archive against its checksum sidecar.
+///
+/// # Arguments
+/// * `backup_path` - Path to the backup archive
+/// * `use_cache` - Reuse the archive's hash from `CHECKSUM_CACHE` if it hasn't changed;
+///   otherwise the archive is always hashed again (and the cache refreshed)
+///
+/// # Returns
+/// `FileOpsResult<Option<bool>>` - Whether the checksum matches, or None if there is no sidecar
+fn check_backup_checksum(backup_path: &Path, use_cache: bool) -> FileOpsResult<Option<bool>> {
+    let content = match fs::read_to_string(backup_checksum_path(backup_path)) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let Some(expected) = content.split_whitespace().next() else {
+        // An empty sidecar can't vouch for the archive
+        return Ok(Some(false));
+    };
+    let actual = match cached_backup_checksum(backup_path).filter(|_| use_cache) {
+        Some(actual) => actual,
+        None => {
+            let actual = calculate_checksum_sha256(backup_path)?;
+            cache_backup_checksum(backup_path, &actual);
+            actual
+        }
+    };
+    Ok(Some(actual.eq_ignore_ascii_case(expected)))
+}
+
+/// Checks a backup's checksum for a listing: unchanged archives aren't hashed again,
+/// and an archive that can't be read shows as unverified instead of failing the listing.
+fn listed_checksum_verified(backup_path: &Path) -> Option<bool> {
+    check_backup_checksum(backup_path, true).unwrap_or(None)
+}
+
+/// Checks whether a file name is a completed backup archive.
+///
+/// # Arguments
+/// * `name` - File name (e.g. "2024-12-28_14-30-45.tar.gz")
+///
+/// # Returns
+/// `true` only for names ending in a supported archive extension (`.tar.gz`, `.zip`), so
+/// sidecars (`.tar.gz.sha256`, `.tar.gz.json`, `.tar.gz.sig`) and `.tmp` files are excluded
+pub fn is_backup_archive(name: &str) -> bool {
+    ArchiveFormat::from_path(Path::new(name)).is_some()
+}
+
+/// Extensions of sidecar files stored next to a backup archive
+/// (`<backup_name>.<ext>`): checksum, manifest and signature.
+const BACKUP_SIDECAR_EXTENSIONS: [&str; 3] = ["sha256", "json", "sig"];
+
+/// Deletes a backup archive together with its sidecar files.
+///
+/// # Arguments
+/// * `backup_path` - Path to the backup archive
+///
+/// # Returns
+/// `FileOpsResult<usize>` - Number of files deleted (the archive plus any sidecars found)
+///
+/// # Behavior
+/// Missing sidecars are ignored; a missing archive is an error.
+pub fn delete_backup_with_sidecars(backup_path: &Path) -> FileOpsResult<usize> {
+    delete_file(backup_path)?;
+    forget_backup_checksum(backup_path);
+    let mut deleted = 1;
+
+    for extension in BACKUP_SIDECAR_EXTENSIONS {
+        let mut name = backup_path.as_os_str().to_os_string();
+        name.push(".");
+        name.push(extension);
+        let sidecar_path = PathBuf::from(name);
+        if sidecar_path.exists() {
+            delete_file(&sidecar_path)?;
+            deleted += 1;
+        }
+    }
+
+    Ok(deleted)
 }
 
 /// Error type for backup operations.
@@ -62,8 +478,23 @@ pub enum BackupError {
     SaveNotFound(String),
     /// Invalid backup name format
     InvalidBackupName(String),
+    /// Save name is not a plain relative path inside the saves directory
+    InvalidSaveName(String),
     /// Backup not found
     BackupNotFound(String),
+    /// Failed to create a backup directory
+    CreateDirectoryFailed(PathBuf, std::io::Error),
+    /// Newly created backup failed verification
+    VerificationFailed(String),
+    /// Another backup, restore or delete of the same save is still running
+    AlreadyInProgress(String),
+    /// Save is below `Config::min_save_size_bytes` (likely created but not yet written)
+    SaveTooSmall { save_name: String, size_bytes: u64 },
+    /// Not enough free space on the backup volume (see `check_backup_space`)
+    InsufficientSpace { needed: u64, available: u64 },
+    /// Incremental backup whose manifest is missing or lacks `file_sources`, so it
+    /// can't be told apart from (or rebuilt as) a full backup
+    ManifestMissing(String),
 }
 
 impl From<FileOpsError> for BackupError {
@@ -72,6 +503,12 @@ impl From<FileOpsError> for BackupError {
     }
 }
 
+impl From<std::io::Error> for BackupError {
+    fn from(err: std::io::Error) -> Self {
+        BackupError::FileOp(FileOpsError::Io(err))
+    }
+}
+
 impl From<ConfigError> for BackupError {
     fn from(err: ConfigError) -> Self {
         BackupError::Config(err)
@@ -87,7 +524,33 @@ impl std::fmt::Display for BackupError {
             BackupError::InvalidBackupName(name) => {
                 write!(f, "Invalid backup name format: {}", name)
             }
+            BackupError::InvalidSaveName(name) => write!(f, "Invalid save name: {}", name),
             BackupError::BackupNotFound(name) => write!(f, "Backup not found: {}", name),
+            BackupError::CreateDirectoryFailed(path, err) => {
+                write!(f, "Failed to create directory {}: {}", path.display(), err)
+            }
+            BackupError::VerificationFailed(msg) => {
+                write!(f, "Backup verification failed: {}", msg)
+            }
+            BackupError::AlreadyInProgress(name) => {
+                write!(f, "Another operation on {} is already in progress", name)
+            }
+            BackupError::SaveTooSmall { save_name, size_bytes } => write!(
+                f,
+                "Save {} is too small to back up ({} bytes), it may not have been saved yet",
+                save_name, size_bytes
+            ),
+            BackupError::InsufficientSpace { needed, available } => write!(
+                f,
+                "Not enough space on the backup drive: {} needed, {} available",
+                crate::file_ops::format_size(*needed),
+                crate::file_ops::format_size(*available)
+            ),
+            BackupError::ManifestMissing(name) => write!(
+                f,
+                "Incremental backup {} can't be restored: its manifest is missing or incomplete",
+                name
+            ),
         }
     }
 }
@@ -97,6 +560,7 @@ impl std::error::Error for BackupError {
         match self {
             BackupError::FileOp(err) => Some(err),
             BackupError::Config(err) => Some(err),
+            BackupError::CreateDirectoryFailed(_, err) => Some(err),
             _ => None,
         }
     }
@@ -133,9 +597,42 @@ pub type BackupResultT<T> = Result<T, BackupError>;
 /// // Returns: "2024-12-28_14-30-45.tar.gz"
 /// ```
 pub fn generate_backup_name(_save_name: &str) -> String {
-    let now = Local::now();
-    let timestamp = now.format("%Y-%m-%d_%H-%M-%S");
-    format!("{}.tar.gz", timestamp)
+    generate_backup_name_with_format(_save_name, ArchiveFormat::TarGz)
+}
+
+/// Generates a timestamped backup file name for the given archive format.
+///
+/// # Format
+/// `{YYYY-MM-DD}_{HH-mm-ss}.{extension}` (e.g. `.tar.gz` or `.zip`), in local time or
+/// UTC depending on `use_local_time` in the config
+pub fn generate_backup_name_with_format(_save_name: &str, format: ArchiveFormat) -> String {
+    backup_name_at(name_timestamp_now(configured_use_local_time()), format)
+}
+
+/// Formats a backup file name for the given name timestamp.
+fn backup_name_at(timestamp: chrono::NaiveDateTime, format: ArchiveFormat) -> String {
+    format!("{}.{}", timestamp.format("%Y-%m-%d_%H-%M-%S"), format.extension())
+}
+
+/// Returns the current time in the zone used for backup and undo snapshot names.
+pub(crate) fn name_timestamp_now(use_local_time: bool) -> chrono::NaiveDateTime {
+    if use_local_time {
+        Local::now().naive_local()
+    } else {
+        Utc::now().naive_utc()
+    }
+}
+
+/// Returns `use_local_time` from the config, for entry points that don't load it
+/// anyway; falls back to the default (UTC) if the config can't be loaded.
+pub(crate) fn configured_use_local_time() -> bool {
+    match config_module::load_config() {
+        Ok(config) => config.use_local_time,
+        Err(err) => {
+            eprintln!("Failed to load config, naming backups in UTC: {}", err);
+            config_module::Config::default().use_local_time
+        }
+    }
 }
 
 /// Gets the backup directory for a specific save.
@@ -150,10 +647,80 @@ pub fn get_save_backup_dir(backup_base_path: &Path, save_name: &str) -> PathBuf
     backup_base_path.join(save_name)
 }
 
+/// Returns the configured backup base directory, creating it if it doesn't exist.
+///
+/// # Arguments
+/// * `config` - Application configuration
+///
+/// # Returns
+/// `BackupResultT<PathBuf>` - Backup base directory, or `CreateDirectoryFailed` if it
+/// could not be created
+pub fn get_or_create_backup_path(config: &config_module::Config) -> BackupResultT<PathBuf> {
+    let backup_base_path = config.get_backup_path()?;
+    create_dir_if_missing(&backup_base_path)?;
+    Ok(backup_base_path)
+}
+
+/// Creates a directory (and its parents) if it doesn't exist yet.
+fn create_dir_if_missing(path: &Path) -> BackupResultT<()> {
+    if !path.exists() {
+        fs::create_dir_all(path)
+            .map_err(|err| BackupError::CreateDirectoryFailed(path.to_path_buf(), err))?;
+    }
+    Ok(())
+}
+
+/// Checks that a save name is a relative path that stays inside the saves directory.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+///
+/// # Returns
+/// `BackupResultT<()>` - `BackupError::InvalidSaveName` for empty names, absolute paths,
+/// `..` components or null bytes
+pub fn validate_save_name(save_name: &str) -> BackupResultT<()> {
+    let path = Path::new(save_name);
+    let escapes = save_name.split(['/', '\\']).any(|part| part == "..");
+    let absolute = path.has_root()
+        || save_name.starts_with(['/', '\\'])
+        || path
+            .components()
+            .any(|c| matches!(c, std::path::Component::Prefix(_)));
+
+    if save_name.is_empty() || save_name.contains('\0') || escapes || absolute {
+        return Err(BackupError::InvalidSaveName(save_name.to_string()));
+    }
+    Ok(())
+}
+
+/// Checks that a backup name is a plain file name without path components.
+///
+/// # Arguments
+/// * `backup_name` - Name of the backup file (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
+///
+/// # Returns
+/// `BackupResultT<()>` - `BackupError::InvalidBackupName` for names that could point
+/// outside the save's backup directory
+pub fn validate_backup_name(backup_name: &str) -> BackupResultT<()> {
+    if backup_name.is_empty()
+        || backup_name == "."
+        || backup_name == ".."
+        || backup_name.contains(['/', '\\', '\0'])
+    {
+        return Err(BackupError::InvalidBackupName(backup_name.to_string()));
+    }
+    Ok(())
+}
+
+/// Callback receiving `(processed_bytes, total_bytes)` while a backup is compressed.
+pub type BackupProgressFn = Box<dyn FnMut(u64, u64) + Send>;
+
 /// Creates a backup of the specified save directory (async version).
 ///
 /// # Arguments
 /// * `save_name` - Relative path of the save to backup (e.g., "sandbox/aaa")
+/// * `options` - Backup options (see `BackupOptions`)
+/// * `progress` - Optional compression progress callback (see `create_backup_with_progress`)
 ///
 /// # Returns
 /// `BackupResultT<BackupResult>` - Information about the created backup
@@ -165,14 +732,137 @@ pub fn get_save_backup_dir(backup_base_path: &Path, save_name: &str) -> PathBuf
 /// # Backup Path Structure
 /// For a save at `Saves/sandbox/aaa`:
 /// - Backup path: `$PZ_BACKUP_PATH/sandbox/aaa/aaa_2024-12-28_14-30-45.tar.gz`
-pub async fn create_backup_async(save_name: &str) -> BackupResultT<BackupResult> {
+pub async fn create_backup_async(
+    save_name: &str,
+    options: BackupOptions,
+    progress: Option<BackupProgressFn>,
+) -> BackupResultT<BackupResult> {
     let save_name = save_name.to_string();
-    tokio::task::spawn_blocking(move || create_backup(&save_name))
-        .await
-        .map_err(|e| BackupError::FileOp(FileOpsError::Io(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Task join error: {}", e),
-        ))))?
+    tokio::task::spawn_blocking(move || match progress {
+        Some(mut progress) => create_backup_with_progress(&save_name, &options, &mut progress),
+        None => create_backup_with_options(&save_name, &options),
+    })
+    .await
+    .map_err(|e| std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("Task join error: {}", e),
+    ))?
+}
+
+/// Per-save locks shared by every command that changes a save or its backups.
+///
+/// Managed as Tauri state so that backups, restores, deletes, pruning and
+/// renames of the same save never overlap, whichever command started them.
+/// Each save gets a semaphore with a single permit while someone holds it;
+/// operations on different saves still run in parallel.
+#[derive(Debug, Clone, Default)]
+pub struct GlobalBackupSemaphores(Arc<Mutex<HashMap<String, Arc<Semaphore>>>>);
+
+/// A held per-save lock from `GlobalBackupSemaphores::try_acquire`.
+///
+/// Releases the permit on drop and removes the save's semaphore from the map
+/// once nobody else references it, so the map only holds saves in use.
+#[derive(Debug)]
+pub struct SavePermit {
+    permit: Option<OwnedSemaphorePermit>,
+    semaphores: GlobalBackupSemaphores,
+    save_name: String,
+}
+
+impl Drop for SavePermit {
+    fn drop(&mut self) {
+        let mut semaphores = self.semaphores.0.lock().unwrap_or_else(|e| e.into_inner());
+        self.permit.take();
+        // Semaphores are only cloned under this lock, so a count of one means
+        // the map holds the last reference and no caller is about to use it
+        if semaphores
+            .get(&self.save_name)
+            .is_some_and(|semaphore| Arc::strong_count(semaphore) == 1)
+        {
+            semaphores.remove(&self.save_name);
+        }
+    }
+}
+
+impl GlobalBackupSemaphores {
+    /// Creates an empty set of semaphores.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the permit for a save without waiting.
+    ///
+    /// # Arguments
+    /// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+    ///
+    /// # Returns
+    /// `BackupResultT<SavePermit>` - The permit (released on drop), or
+    /// `BackupError::AlreadyInProgress` if another operation holds it
+    pub fn try_acquire(&self, save_name: &str) -> BackupResultT<SavePermit> {
+        let semaphore = {
+            let mut semaphores = self.0.lock().unwrap_or_else(|e| e.into_inner());
+            semaphores
+                .entry(save_name.to_string())
+                .or_insert_with(|| Arc::new(Semaphore::new(1)))
+                .clone()
+        };
+
+        // On failure the clone is dropped here; the holder's SavePermit still
+        // references the semaphore, so the entry stays until that drops
+        let permit = semaphore
+            .try_acquire_owned()
+            .map_err(|_| BackupError::AlreadyInProgress(save_name.to_string()))?;
+        Ok(SavePermit {
+            permit: Some(permit),
+            semaphores: self.clone(),
+            save_name: save_name.to_string(),
+        })
+    }
+
+    /// Takes the permits for two saves, or one if they are the same save.
+    ///
+    /// Used by commands that touch a source and a target save (rename,
+    /// restore-as). Fails without holding either if one is busy.
+    pub fn try_acquire_pair(
+        &self,
+        first: &str,
+        second: &str,
+    ) -> BackupResultT<(SavePermit, Option<SavePermit>)> {
+        let first_permit = self.try_acquire(first)?;
+        let second_permit = if first == second {
+            None
+        } else {
+            Some(self.try_acquire(second)?)
+        };
+        Ok((first_permit, second_permit))
+    }
+
+    /// Number of saves that currently have a semaphore in the map.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+}
+
+/// Creates a backup while holding the save's permit in `semaphores`.
+///
+/// # Arguments
+/// * `semaphores` - Shared per-save backup locks
+/// * `save_name` - Relative path of the save to backup (e.g., "sandbox/aaa")
+/// * `options` - Backup options (see `BackupOptions`)
+/// * `progress` - Optional compression progress callback (see `create_backup_with_progress`)
+///
+/// # Returns
+/// `BackupResultT<BackupResult>` - Information about the created backup, or
+/// `BackupError::AlreadyInProgress` if a backup of this save is already running
+pub async fn create_backup_exclusive(
+    semaphores: &GlobalBackupSemaphores,
+    save_name: &str,
+    mut options: BackupOptions,
+    progress: Option<BackupProgressFn>,
+) -> BackupResultT<BackupResult> {
+    options.permit = Some(Arc::new(semaphores.try_acquire(save_name)?));
+    create_backup_async(save_name, options, progress).await
 }
 
 /// Creates a backup of the specified save directory.
@@ -185,18 +875,133 @@ pub async fn create_backup_async(save_name: &str) -> BackupResultT<BackupResult>
 ///
 /// # Behavior
 /// 1. Cleans up any leftover temporary files from previous interrupted backups
-/// 2. Validates the save directory exists
+/// 2. Validates the save directory exists and creates the backup directory if missing;
+///    fails with `InsufficientSpace` if the backup volume lacks room (see `check_backup_space`)
 /// 3. Generates timestamped backup name (using only save leaf name)
-/// 4. Creates a compressed tar.gz archive (atomically)
+/// 4. Creates a compressed tar.gz archive (atomically), throttled to `max_io_bytes_per_sec`;
+///    files locked by another process are skipped and reported in `BackupResult::warning`
 /// 5. Runs garbage collection to remove old backups exceeding retention limit
 ///
+/// Archive format, compression settings, exclusions and verification come from the
+/// config (see `BackupOptions::from_config`).
+///
 /// # Backup Path Structure
 /// For a save at `Saves/sandbox/aaa`:
 /// - Backup path: `$PZ_BACKUP_PATH/sandbox/aaa/aaa_2024-12-28_14-30-45.tar.gz`
 pub fn create_backup(save_name: &str) -> BackupResultT<BackupResult> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    create_backup_from_config(&config, save_name, &BackupOptions::from_config(&config), None)
+}
+
+/// Creates a backup of the specified save directory with explicit options.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save to backup (e.g., "sandbox/aaa")
+/// * `options` - Backup options (see `BackupOptions`)
+///
+/// # Returns
+/// `BackupResultT<BackupResult>` - Information about the created backup
+///
+/// # Behavior
+/// Same as `create_backup`, except:
+/// - `compression_level` and `exclude_patterns` control archive creation
+/// - `verify_after_create` re-reads the archive and deletes it if it is corrupt
+/// - `label` is stored in a `<backup>.json` manifest next to the archive
+/// - `await_gc: false` runs garbage collection on a background thread
+/// - `incremental` stores only changed files (see `create_incremental_backup`)
+/// - `emit_progress` is ignored here; the Tauri command emits the events
+///
+/// # Example
+/// ```no_run
+/// use tauri_app_lib::backup::{create_backup_with_options, BackupOptions};
+///
+/// let options = BackupOptions {
+///     label: Some("Before horde night".to_string()),
+///     verify_after_create: true,
+///     ..Default::default()
+/// };
+/// let result = create_backup_with_options("Survival", &options).unwrap();
+/// println!("Created {}", result.backup_name);
+/// ```
+pub fn create_backup_with_options(
+    save_name: &str,
+    options: &BackupOptions,
+) -> BackupResultT<BackupResult> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    create_backup_from_config(&config, save_name, options, None)
+}
+
+/// Creates a backup with explicit options, reporting compression progress.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save to backup (e.g., "sandbox/aaa")
+/// * `options` - Backup options (see `BackupOptions`)
+/// * `progress` - Called with `(processed_bytes, total_bytes)`
+///
+/// # Returns
+/// `BackupResultT<BackupResult>` - Information about the created backup
+///
+/// # Behavior
+/// Same as `create_backup_with_options`. For tar.gz backups `progress` is called
+/// after each archived file (see `create_tar_gz_with_progress`); zip backups only
+/// report completion.
+pub fn create_backup_with_progress(
+    save_name: &str,
+    options: &BackupOptions,
+    progress: &mut dyn FnMut(u64, u64),
+) -> BackupResultT<BackupResult> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    create_backup_from_config(&config, save_name, options, Some(progress))
+}
+
+/// Creates an incremental backup that only stores files changed since the latest full backup.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save to backup (e.g., "sandbox/aaa")
+///
+/// # Returns
+/// `BackupResultT<BackupResult>` - Information about the created backup
+///
+/// # Behavior
+/// - Compares every file of the save against the newest full tar.gz backup; files whose
+///   size or modification time differ are written to a new tar.gz archive
+/// - The manifest records the parent backup and which backup holds each file, so a
+///   restore rebuilds the save from both (see `extract_backup`)
+/// - Falls back to a full backup if the save has no full tar.gz backup yet
+/// - Otherwise the same as `create_backup` (config defaults, sidecars, garbage collection)
+///
+/// # Example
+/// ```no_run
+/// use tauri_app_lib::backup::{create_incremental_backup, list_backups};
+///
+/// let result = create_incremental_backup("Survival").unwrap();
+/// let backups = list_backups("Survival").unwrap();
+/// println!("Created {} ({:?})", result.backup_name, backups[0].kind);
+/// ```
+pub fn create_incremental_backup(save_name: &str) -> BackupResultT<BackupResult> {
+    validate_save_name(save_name)?;
     let config = config_module::load_config()?;
+    let options = BackupOptions {
+        compression: ArchiveFormat::TarGz,
+        incremental: true,
+        ..BackupOptions::from_config(&config)
+    };
+    create_backup_from_config(&config, save_name, &options, None)
+}
+
+/// Shared implementation of `create_backup`, `create_backup_with_options` and
+/// `create_backup_with_progress`.
+fn create_backup_from_config(
+    config: &config_module::Config,
+    save_name: &str,
+    options: &BackupOptions,
+    progress: Option<&mut dyn FnMut(u64, u64)>,
+) -> BackupResultT<BackupResult> {
+    let started = std::time::Instant::now();
     let save_path = config.get_save_path()?;
-    let backup_base_path = config.get_backup_path()?;
 
     // Validate save directory exists
     let save_dir = save_path.join(save_name);
@@ -210,32 +1015,194 @@ pub fn create_backup(save_name: &str) -> BackupResultT<BackupResult> {
         )));
     }
 
+    // Skip saves the game created but hasn't written yet
+    let size_bytes = crate::file_ops::get_dir_size(&save_dir)?;
+    if config.min_save_size_bytes > 0 && size_bytes < config.min_save_size_bytes {
+        return Err(BackupError::SaveTooSmall {
+            save_name: save_name.to_string(),
+            size_bytes,
+        });
+    }
+
     // Create backup base directory if it doesn't exist
     // Use the relative path as the backup directory structure
+    let backup_base_path = get_or_create_backup_path(config)?;
     let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
-    if !save_backup_dir.exists() {
-        fs::create_dir_all(&save_backup_dir).map_err(FileOpsError::Io)?;
-    }
+    create_dir_if_missing(&save_backup_dir)?;
 
     // Clean up any leftover temporary files from previous interrupted backups
     cleanup_temp_files(&save_backup_dir);
 
+    // Fail before writing anything rather than leave a truncated archive behind. If the
+    // free space can't be determined, the backup goes ahead.
+    if let Ok(space) = estimate_backup_space(size_bytes, &save_backup_dir) {
+        if !space.sufficient {
+            return Err(BackupError::InsufficientSpace {
+                needed: space.needed_bytes,
+                available: space.available_bytes,
+            });
+        }
+    }
+
     // Generate backup name and path (backup_name uses only save leaf name)
-    let backup_name = generate_backup_name(save_name);
+    let backup_name =
+        backup_name_at(name_timestamp_now(config.use_local_time), options.compression);
     let backup_path = save_backup_dir.join(&backup_name);
 
     // Perform the backup compression (atomic write)
-    create_tar_gz(&save_dir, &backup_path)?;
-
-    // Run garbage collection
-    let retention_count = config.retention_count;
-    let (retained, deleted) = garbage_collection(&save_backup_dir, retention_count)?;
+    let mut archive_options = ArchiveOptions {
+        max_bytes_per_sec: config.max_io_bytes_per_sec,
+        compression_level: Some(u32::from(options.compression_level)),
+        exclude_patterns: options.exclude_patterns.clone(),
+        // The game may hold save files open; keep everything else rather than failing
+        skip_locked_files: true,
+        include_only: None,
+        threads: options.threads,
+        cancel: options.cancel.clone(),
+        pax_metadata: Vec::new(),
+    };
+
+    // Incremental backups only store the files that changed since the latest full backup
+    let parent_name = match options.compression {
+        ArchiveFormat::TarGz if options.incremental => {
+            latest_full_backup(&save_backup_dir, config.use_local_time)?
+        }
+        _ => None,
+    };
+    let incremental = match parent_name {
+        Some(parent_name) => Some(plan_incremental(
+            &save_dir,
+            &save_backup_dir,
+            parent_name,
+            &backup_name,
+            &archive_options.exclude_patterns,
+        )?),
+        None => None,
+    };
+    if let Some(plan) = &incremental {
+        archive_options.include_only = Some(plan.changed.clone());
+        archive_options.pax_metadata = vec![
+            (ARCHIVE_KIND_KEY.to_string(), ARCHIVE_KIND_INCREMENTAL.to_string()),
+            (ARCHIVE_PARENT_KEY.to_string(), plan.parent_name.clone()),
+        ];
+    }
+    let report = match (options.compression, progress) {
+        (ArchiveFormat::TarGz, Some(progress)) => {
+            create_tar_gz_with_progress(&save_dir, &backup_path, &archive_options, progress)?
+        }
+        (ArchiveFormat::TarGz, None) => {
+            create_tar_gz_with_options(&save_dir, &backup_path, &archive_options)?
+        }
+        (ArchiveFormat::Zip, progress) => {
+            let report = create_zip_with_options(&save_dir, &backup_path, &archive_options)?;
+            if let Some(progress) = progress {
+                let size = fs::metadata(&backup_path).map(|m| m.len()).unwrap_or(0);
+                progress(size, size);
+            }
+            report
+        }
+    };
+
+    // Verify the archive before it can replace older backups through GC
+    if options.verify_after_create {
+        let failure = match verify_archive(&backup_path) {
+            Ok(verification) => verification.error,
+            Err(err) => Some(err.to_string()),
+        };
+        if let Some(err) = failure {
+            let _ = delete_file(&backup_path);
+            return Err(BackupError::VerificationFailed(format!(
+                "{}: {}",
+                backup_path.display(),
+                err
+            )));
+        }
+    }
+
+    // Record the checksum for later verification (see `verify_backup`)
+    let sha256 = calculate_checksum_sha256(&backup_path)?;
+    write_backup_checksum(&backup_path, &sha256)?;
+
+    // Record the label and file inventory in a sidecar manifest
+    let (kind, parent, file_sources) = match incremental {
+        Some(plan) => {
+            let parent = plan.parent_name.clone();
+            let sources = plan.into_file_sources(&save_dir, &report.skipped_locked_files);
+            (BackupKind::Incremental, Some(parent), Some(sources))
+        }
+        None => (BackupKind::Full, None, None),
+    };
+    let manifest = BackupManifest {
+        label: options.label.clone(),
+        file_inventory: Some(report.file_inventory.into_iter().map(Into::into).collect()),
+        file_count_truncated: report.file_inventory_truncated,
+        sha256: Some(sha256),
+        archive_meta: read_archive_meta(&backup_path).ok(),
+        kind,
+        parent,
+        file_sources,
+    };
+    write_backup_manifest(&backup_path, &manifest)?;
+
+    // Statistics are best-effort and never fail the backup
+    let archive_size = fs::metadata(&backup_path).map(|m| m.len()).unwrap_or(0);
+    let elapsed = started.elapsed();
+    let _ = update_statistics(&backup_base_path, |stats| {
+        stats.record_backup(save_name, archive_size, elapsed)
+    });
+    let _ = config_module::record_last_backup_time(save_name, &Utc::now().to_rfc3339());
+
+    // Run garbage collection
+    let backup_dir = crate::file_ops::normalize_path_for_display(&save_backup_dir);
+    let record_gc = |backup_base_path: &Path, save_name: &str, deleted: usize, bytes_freed: u64| {
+        if deleted > 0 {
+            let _ = update_statistics(backup_base_path, |stats| {
+                stats.record_deletions(save_name, deleted, bytes_freed)
+            });
+        }
+    };
+    let max_age_days = config.max_backup_age_days;
+    let use_local_time = config.use_local_time;
+    let (retained, deleted) = if options.await_gc {
+        let (retained, deleted, bytes_freed) = garbage_collection(
+            &save_backup_dir,
+            config.retention_count,
+            max_age_days,
+            use_local_time,
+        )?;
+        record_gc(&backup_base_path, save_name, deleted, bytes_freed);
+        (retained, deleted)
+    } else {
+        let retained = list_backup_files(&save_backup_dir, use_local_time)?.len();
+        let retention = config.retention_count;
+        let save_name = save_name.to_string();
+        // Held until GC is done, so a restore or delete of the save can't run alongside it
+        let permit = options.permit.clone();
+        std::thread::spawn(move || {
+            let _permit = permit;
+            // Errors are ignored like in synchronous GC - a failed cleanup is not critical
+            if let Ok((_, deleted, bytes_freed)) =
+                garbage_collection(&save_backup_dir, retention, max_age_days, use_local_time)
+            {
+                record_gc(&backup_base_path, &save_name, deleted, bytes_freed);
+            }
+        });
+        (retained, 0)
+    };
 
     Ok(BackupResult {
         backup_path: crate::file_ops::normalize_path_for_display(&backup_path),
+        backup_dir,
         backup_name,
         retained_count: retained,
         deleted_count: deleted,
+        warning: if report.skipped_locked_files.is_empty() {
+            None
+        } else {
+            Some(BackupWarning {
+                skipped_files: report.skipped_locked_files,
+            })
+        },
     })
 }
 
@@ -245,7 +1212,7 @@ pub fn create_backup(save_name: &str) -> BackupResultT<BackupResult> {
 /// * `save_backup_dir` - Directory containing backups for a specific save
 ///
 /// # Behavior
-/// - Removes all `.tar.gz.tmp` files in the backup directory
+/// - Removes all `.tar.gz.tmp` and `.zip.tmp` files in the backup directory
 /// - Silently ignores errors (cleanup is best-effort)
 fn cleanup_temp_files(save_backup_dir: &Path) {
     if !save_backup_dir.exists() {
@@ -258,7 +1225,7 @@ fn cleanup_temp_files(save_backup_dir: &Path) {
             if path.is_file() {
                 if let Some(name) = path.file_name() {
                     if let Some(name_str) = name.to_str() {
-                        if name_str.ends_with(".tar.gz.tmp") {
+                        if name_str.ends_with(".tar.gz.tmp") || name_str.ends_with(".zip.tmp") {
                             // Silently ignore errors during cleanup
                             let _ = fs::remove_file(&path);
                         }
@@ -273,42 +1240,188 @@ fn cleanup_temp_files(save_backup_dir: &Path) {
 ///
 /// # Arguments
 /// * `save_backup_dir` - Directory containing backups for a specific save
-/// * `retention_count` - Maximum number of backups to retain
+/// * `retention` - Retention policy to apply
+/// * `max_age_days` - Also delete backups older than this (see `prune_backups_older_than`)
+/// * `use_local_time` - Zone the backup name timestamps are in (`Config::use_local_time`)
 ///
 /// # Returns
-/// `FileOpsResult<(usize, usize)>` - (retained_count, deleted_count)
+/// `FileOpsResult<(usize, usize, u64)>` - (retained_count, deleted_count, bytes_freed)
 ///
 /// # Behavior
-/// - Lists all backup tar.gz files sorted by creation time (newest first)
+/// - Lists all backup tar.gz files sorted by creation time (newest first), taken from the
+///   backup names where possible (see `backup_created_time`)
 /// - Keeps the newest `retention_count` backups
 /// - Deletes older backups
+/// - Deletes nothing by count when retention is `Unlimited`
+/// - Then deletes the remaining backups older than `max_age_days` (ignored if 0, so the
+///   backup just created is never removed)
+/// - Incremental backups whose parent is deleted are promoted to full backups first
 fn garbage_collection(
     save_backup_dir: &Path,
-    retention_count: usize,
-) -> FileOpsResult<(usize, usize)> {
-    let mut backups = list_backup_files(save_backup_dir)?;
+    retention: RetentionMode,
+    max_age_days: Option<u64>,
+    use_local_time: bool,
+) -> FileOpsResult<(usize, usize, u64)> {
+    let mut backups = list_backup_files(save_backup_dir, use_local_time)?;
+    let total_backups = backups.len();
+
+    // Pinned backups are kept and don't count against the retention limit
+    let pinned = read_pinned_backups(save_backup_dir);
+    backups.retain(|backup| !pinned.contains(&backup.name));
 
     // Sort by creation time (newest first)
     backups.sort_by(|a, b| b.created.cmp(&a.created));
 
-    let total_backups = backups.len();
-    let to_delete = if total_backups > retention_count {
-        backups.split_off(retention_count)
-    } else {
-        Vec::new()
+    let to_delete = match retention {
+        RetentionMode::Limited(count) if backups.len() > count => backups.split_off(count),
+        _ => Vec::new(),
     };
 
     // Delete old backups
+    let mut deleted = 0;
+    let mut bytes_freed = 0;
     for backup in &to_delete {
+        // Newer backups are deleted first, so dependents that are also being removed
+        // are already gone; keep the backup if the remaining ones can't be promoted
+        if promote_dependents(save_backup_dir, &backup.name, use_local_time).is_err() {
+            continue;
+        }
         let backup_path = save_backup_dir.join(&backup.name);
+        let size_bytes = fs::metadata(&backup_path).map(|m| m.len()).unwrap_or(0);
         // Silently ignore errors during GC - a failed deletion is not critical
-        let _ = delete_file(&backup_path);
+        if delete_backup_with_sidecars(&backup_path).is_ok() {
+            deleted += 1;
+            bytes_freed += size_bytes;
+        }
+    }
+
+    if let Some(days) = max_age_days.filter(|&days| days > 0) {
+        let (aged, aged_bytes) = prune_dir_older_than(save_backup_dir, days, use_local_time)?;
+        deleted += aged;
+        bytes_freed += aged_bytes;
+    }
+    let retained = total_backups.saturating_sub(deleted);
+
+    Ok((retained, deleted, bytes_freed))
+}
+
+/// Parses the creation time from a backup name (`YYYY-MM-DD_HH-mm-ss.<ext>`, as written
+/// by `generate_backup_name`, optionally after a `<save>_` prefix).
+///
+/// # Returns
+/// `None` if the name doesn't end in a timestamp followed by an archive extension
+pub(crate) fn parse_backup_timestamp(backup_name: &str) -> Option<chrono::NaiveDateTime> {
+    const TIMESTAMP_LEN: usize = "YYYY-MM-DD_HH-mm-ss".len();
+
+    let format = ArchiveFormat::from_path(Path::new(backup_name))?;
+    let stem = backup_name.strip_suffix(&format!(".{}", format.extension()))?;
+    let timestamp = stem.get(stem.len().checked_sub(TIMESTAMP_LEN)?..)?;
+    chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d_%H-%M-%S").ok()
+}
+
+/// Returns when a backup was created: the timestamp in its name if it has one, otherwise
+/// the file's creation (or modification) time.
+///
+/// # Behavior
+/// File times are unreliable for ordering backups: Linux often has no creation time,
+/// and copying backups to another drive resets both. The name timestamp is read as
+/// local time or UTC according to `use_local_time`.
+fn backup_created_time(
+    backup_name: &str,
+    metadata: &fs::Metadata,
+    use_local_time: bool,
+) -> SystemTime {
+    parse_backup_timestamp(backup_name)
+        .and_then(|timestamp| {
+            if use_local_time {
+                Local.from_local_datetime(&timestamp).earliest().map(SystemTime::from)
+            } else {
+                Some(Utc.from_utc_datetime(&timestamp).into())
+            }
+        })
+        .or_else(|| metadata.created().or_else(|_| metadata.modified()).ok())
+        .unwrap_or_else(SystemTime::now)
+}
+
+/// Deletes the backups in a save's backup folder whose name timestamp is more than
+/// `max_age_days` old.
+///
+/// # Returns
+/// `FileOpsResult<(usize, u64)>` - (deleted_count, bytes_freed)
+fn prune_dir_older_than(
+    save_backup_dir: &Path,
+    max_age_days: u64,
+    use_local_time: bool,
+) -> FileOpsResult<(usize, u64)> {
+    let Some(cutoff) = i64::try_from(max_age_days)
+        .ok()
+        .and_then(chrono::TimeDelta::try_days)
+        .and_then(|age| name_timestamp_now(use_local_time).checked_sub_signed(age))
+    else {
+        // Older than the earliest representable time: nothing to delete
+        return Ok((0, 0));
+    };
+
+    // Names without a timestamp (e.g. renamed by the user) and pinned backups are kept
+    let pinned = read_pinned_backups(save_backup_dir);
+    let mut expired: Vec<(chrono::NaiveDateTime, String)> =
+        list_backup_files(save_backup_dir, use_local_time)?
+        .into_iter()
+        .filter(|backup| !pinned.contains(&backup.name))
+        .filter_map(|backup| Some((parse_backup_timestamp(&backup.name)?, backup.name)))
+        .filter(|(created, _)| *created < cutoff)
+        .collect();
+    // Newest first, so incremental backups are gone before their parent (like GC)
+    expired.sort_by_key(|(created, _)| std::cmp::Reverse(*created));
+
+    let mut deleted = 0;
+    let mut bytes_freed = 0;
+    for (_, name) in expired {
+        if promote_dependents(save_backup_dir, &name, use_local_time).is_err() {
+            continue;
+        }
+        let backup_path = save_backup_dir.join(&name);
+        let size_bytes = fs::metadata(&backup_path).map(|m| m.len()).unwrap_or(0);
+        if delete_backup_with_sidecars(&backup_path).is_ok() {
+            deleted += 1;
+            bytes_freed += size_bytes;
+        }
     }
 
-    let retained = total_backups.saturating_sub(to_delete.len());
-    let deleted = to_delete.len();
+    Ok((deleted, bytes_freed))
+}
+
+/// Deletes a save's backups that are older than a given age, regardless of the
+/// retention count.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `max_age_days` - Backups created more than this many days ago are deleted
+///   (0 deletes every backup)
+///
+/// # Returns
+/// `BackupResultT<usize>` - Number of backups deleted
+///
+/// # Behavior
+/// - The age comes from the timestamp in the backup name, not the file's modification
+///   time, so copying or restoring backup files doesn't reset it
+/// - Backups whose name has no timestamp are kept, and so are pinned backups
+/// - Incremental backups of a deleted parent are promoted to full backups first
+/// - Deletions are recorded in the backup statistics like garbage collection
+pub fn prune_backups_older_than(save_name: &str, max_age_days: u64) -> BackupResultT<usize> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
 
-    Ok((retained, deleted))
+    let (deleted, bytes_freed) =
+        prune_dir_older_than(&save_backup_dir, max_age_days, config.use_local_time)?;
+    if deleted > 0 {
+        let _ = update_statistics(&backup_base_path, |stats| {
+            stats.record_deletions(save_name, deleted, bytes_freed)
+        });
+    }
+    Ok(deleted)
 }
 
 /// Internal struct for tracking backup files during GC.
@@ -329,7 +1442,10 @@ struct BackupFile {
 /// # Behavior
 /// - Only includes completed .tar.gz files (excludes .tmp temporary files)
 /// - This ensures that incomplete backups being created are not listed
-fn list_backup_files(save_backup_dir: &Path) -> FileOpsResult<Vec<BackupFile>> {
+fn list_backup_files(
+    save_backup_dir: &Path,
+    use_local_time: bool,
+) -> FileOpsResult<Vec<BackupFile>> {
     if !save_backup_dir.exists() {
         return Ok(Vec::new());
     }
@@ -340,17 +1456,14 @@ fn list_backup_files(save_backup_dir: &Path) -> FileOpsResult<Vec<BackupFile>> {
         let entry = entry?;
         let path = entry.path();
 
-        // Only process completed .tar.gz files (exclude .tmp temporary files)
+        // Only process completed .tar.gz / .zip files (exclude .tmp temporary files)
         if path.is_file() {
             if let Some(name) = path.file_name() {
                 if let Some(name_str) = name.to_str() {
-                    // Check if it's a completed backup file (ends with .tar.gz but not .tar.gz.tmp)
-                    if name_str.ends_with(".tar.gz") && !name_str.ends_with(".tar.gz.tmp") {
-                        let metadata = entry.metadata()?;
-                        let created = metadata
-                            .created()
-                            .or_else(|_| metadata.modified())
-                            .unwrap_or_else(|_| SystemTime::now());
+                    // Check if it's a completed backup file (a known archive extension, not .tmp)
+                    if is_backup_archive(name_str) {
+                        let created =
+                            backup_created_time(name_str, &entry.metadata()?, use_local_time);
 
                         backups.push(BackupFile {
                             name: name_str.to_string(),
@@ -365,6 +1478,450 @@ fn list_backup_files(save_backup_dir: &Path) -> FileOpsResult<Vec<BackupFile>> {
     Ok(backups)
 }
 
+/// Returns the name of the newest full tar.gz backup in a save's backup folder.
+fn latest_full_backup(
+    save_backup_dir: &Path,
+    use_local_time: bool,
+) -> FileOpsResult<Option<String>> {
+    let mut backups = list_backup_files(save_backup_dir, use_local_time)?;
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.created));
+
+    Ok(backups.into_iter().map(|backup| backup.name).find(|name| {
+        ArchiveFormat::from_path(Path::new(name)) == Some(ArchiveFormat::TarGz)
+            && read_backup_manifest(&save_backup_dir.join(name))
+                .is_none_or(|manifest| manifest.kind == BackupKind::Full)
+    }))
+}
+
+/// Files of a save compared against the full backup an incremental backup builds on.
+struct IncrementalPlan {
+    /// Name of the full backup the incremental backup is layered over
+    parent_name: String,
+    /// Files in the parent backup, by relative path
+    parent_files: HashSet<String>,
+    /// Every file of the save, mapped to the backup that will hold it
+    file_sources: BTreeMap<String, String>,
+    /// Files whose size or modification time differ from the parent (to be archived)
+    changed: HashSet<String>,
+}
+
+impl IncrementalPlan {
+    /// Returns the final file sources, given the files the archive had to skip.
+    ///
+    /// A skipped file falls back to the parent's copy if there is one, and is otherwise
+    /// left out of the backup.
+    fn into_file_sources(
+        mut self,
+        save_dir: &Path,
+        skipped: &[PathBuf],
+    ) -> BTreeMap<String, String> {
+        for path in skipped {
+            let relative = normalize_path_for_display(path.strip_prefix(save_dir).unwrap_or(path));
+            if self.parent_files.contains(&relative) {
+                self.file_sources.insert(relative, self.parent_name.clone());
+            } else {
+                self.file_sources.remove(&relative);
+            }
+        }
+        self.file_sources
+    }
+}
+
+/// Compares the files of `save_dir` against the backup `parent_name`.
+///
+/// A file is unchanged if its size and modification time (in whole seconds, the
+/// precision tar records) match the parent's copy.
+fn plan_incremental(
+    save_dir: &Path,
+    save_backup_dir: &Path,
+    parent_name: String,
+    backup_name: &str,
+    exclude_patterns: &[String],
+) -> FileOpsResult<IncrementalPlan> {
+    let unix_secs = |time: SystemTime| {
+        time.duration_since(SystemTime::UNIX_EPOCH).ok().map(|d| d.as_secs())
+    };
+    let parent_states: HashMap<String, (u64, Option<u64>)> =
+        list_tar_gz_files(&save_backup_dir.join(&parent_name))?
+            .into_iter()
+            .map(|file| (file.path, (file.size_bytes, file.modified.and_then(unix_secs))))
+            .collect();
+
+    let relative_path =
+        |path: &Path| normalize_path_for_display(path.strip_prefix(save_dir).unwrap_or(path));
+    let walker = walkdir::WalkDir::new(save_dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            !is_excluded(exclude_patterns, &name, &relative_path(entry.path()))
+        });
+
+    let mut file_sources = BTreeMap::new();
+    let mut changed = HashSet::new();
+    for entry in walker {
+        let entry = entry.map_err(std::io::Error::from)?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let metadata = entry.metadata().map_err(std::io::Error::from)?;
+        let state = (metadata.len(), metadata.modified().ok().and_then(unix_secs));
+        let relative = relative_path(entry.path());
+
+        if state.1.is_some() && parent_states.get(&relative) == Some(&state) {
+            file_sources.insert(relative, parent_name.clone());
+        } else {
+            file_sources.insert(relative.clone(), backup_name.to_string());
+            changed.insert(relative);
+        }
+    }
+
+    Ok(IncrementalPlan {
+        parent_files: parent_states.into_keys().collect(),
+        parent_name,
+        file_sources,
+        changed,
+    })
+}
+
+/// Returns the path of the full backup an incremental backup is layered over.
+///
+/// # Arguments
+/// * `backup_path` - Path to the backup archive
+///
+/// # Returns
+/// `BackupResultT<Option<PathBuf>>` - The parent's path, or None for a full backup;
+/// `BackupNotFound` if the parent no longer exists
+pub fn backup_parent_path(backup_path: &Path) -> BackupResultT<Option<PathBuf>> {
+    let missing = || BackupError::ManifestMissing(normalize_path_for_display(backup_path));
+    let Some(manifest) = read_backup_manifest(backup_path) else {
+        // Restoring the changed files alone would silently lose the rest of the save
+        if archived_incremental_parent(backup_path).is_some() {
+            return Err(missing());
+        }
+        return Ok(None);
+    };
+    let parent = match (manifest.kind, manifest.parent) {
+        (BackupKind::Full, _) => return Ok(None),
+        (BackupKind::Incremental, Some(parent)) => parent,
+        (BackupKind::Incremental, None) => return Err(missing()),
+    };
+
+    let parent_path = backup_path.with_file_name(&parent);
+    if !parent_path.exists() {
+        return Err(BackupError::BackupNotFound(normalize_path_for_display(&parent_path)));
+    }
+    Ok(Some(parent_path))
+}
+
+/// PAX header key recording a backup's kind inside its archive (see `ArchiveOptions`).
+const ARCHIVE_KIND_KEY: &str = "PZBACKUP.kind";
+
+/// Value of `ARCHIVE_KIND_KEY` for incremental backups; full backups don't set the key.
+const ARCHIVE_KIND_INCREMENTAL: &str = "incremental";
+
+/// PAX header key recording an incremental backup's parent inside its archive.
+const ARCHIVE_PARENT_KEY: &str = "PZBACKUP.parent";
+
+/// Reads the parent recorded inside an incremental backup's archive, for backups whose
+/// manifest was lost.
+///
+/// # Returns
+/// `Some(parent_name)` for incremental archives; `None` for full, zip and unreadable ones
+fn archived_incremental_parent(backup_path: &Path) -> Option<String> {
+    if ArchiveFormat::from_path(backup_path) != Some(ArchiveFormat::TarGz) {
+        return None;
+    }
+    let mut metadata = read_tar_gz_pax_metadata(backup_path).ok()?;
+    if metadata.get(ARCHIVE_KIND_KEY).map(String::as_str) != Some(ARCHIVE_KIND_INCREMENTAL) {
+        return None;
+    }
+    metadata.remove(ARCHIVE_PARENT_KEY)
+}
+
+/// Reads the `file_sources` of an incremental backup's manifest.
+///
+/// # Returns
+/// `BackupResultT<BTreeMap<String, String>>` - `ManifestMissing` if there are none, since
+/// every file of the save would otherwise look deleted
+pub(crate) fn incremental_file_sources(
+    backup_path: &Path,
+) -> BackupResultT<BTreeMap<String, String>> {
+    read_backup_manifest(backup_path)
+        .and_then(|manifest| manifest.file_sources)
+        .ok_or_else(|| BackupError::ManifestMissing(normalize_path_for_display(backup_path)))
+}
+
+/// Extracts a backup, rebuilding incremental backups from their parent.
+///
+/// # Arguments
+/// * `backup_path` - Path to the backup archive (.tar.gz or .zip)
+/// * `dst_dir` - Destination directory (must not exist)
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success
+///
+/// # Behavior
+/// - Full backups are extracted as they are (see `extract_archive`)
+/// - Incremental backups extract the parent first and then the changed files over it;
+///   files that were deleted from the save since the parent are removed again
+/// - Returns `BackupNotFound` if an incremental backup's parent is missing, and
+///   `ManifestMissing` if its manifest is (the archive itself records that it's
+///   incremental, so it is never restored as if it were full)
+pub fn extract_backup(backup_path: &Path, dst_dir: &Path) -> BackupResultT<()> {
+    extract_backup_with_options(backup_path, dst_dir, &ExtractOptions::default())
+}
+
+/// Extracts a backup with explicit options (see `extract_backup`).
+///
+/// # Arguments
+/// * `backup_path` - Path to the backup archive (.tar.gz or .zip)
+/// * `dst_dir` - Destination directory (must not exist)
+/// * `options` - Extraction options; `cancel` is also checked between the parent and
+///   the incremental layer
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success
+pub fn extract_backup_with_options(
+    backup_path: &Path,
+    dst_dir: &Path,
+    options: &ExtractOptions,
+) -> BackupResultT<()> {
+    let Some(parent_path) = backup_parent_path(backup_path)? else {
+        extract_archive_with_options(backup_path, dst_dir, options)?;
+        return Ok(());
+    };
+    let file_sources = incremental_file_sources(backup_path)?;
+
+    extract_archive_with_options(&parent_path, dst_dir, options)?;
+    check_cancelled(options.cancel.as_ref())?;
+    extract_tar_gz_over(backup_path, dst_dir)?;
+
+    let extracted: Vec<PathBuf> = walkdir::WalkDir::new(dst_dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .collect();
+    for path in extracted {
+        let relative = normalize_path_for_display(path.strip_prefix(dst_dir).unwrap_or(&path));
+        if !file_sources.contains_key(&relative) {
+            fs::remove_file(&path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts the incremental backups layered over `parent_name` into full backups, so
+/// the parent can be deleted without breaking them.
+///
+/// # Returns
+/// `BackupResultT<usize>` - Number of backups promoted
+fn promote_dependents(
+    save_backup_dir: &Path,
+    parent_name: &str,
+    use_local_time: bool,
+) -> BackupResultT<usize> {
+    let mut promoted = 0;
+    for backup in list_backup_files(save_backup_dir, use_local_time)? {
+        let backup_path = save_backup_dir.join(&backup.name);
+        let Some(manifest) = read_backup_manifest(&backup_path) else {
+            // A dependent that lost its manifest can't be promoted, so the parent stays
+            if archived_incremental_parent(&backup_path).as_deref() == Some(parent_name) {
+                return Err(BackupError::ManifestMissing(normalize_path_for_display(
+                    &backup_path,
+                )));
+            }
+            continue;
+        };
+        if manifest.kind == BackupKind::Incremental
+            && manifest.parent.as_deref() == Some(parent_name)
+        {
+            promote_to_full(&backup_path, manifest)?;
+            promoted += 1;
+        }
+    }
+    Ok(promoted)
+}
+
+/// Rewrites an incremental backup as a full backup of the save it restores to.
+fn promote_to_full(backup_path: &Path, mut manifest: BackupManifest) -> BackupResultT<()> {
+    let mut staging_name = backup_path.as_os_str().to_os_string();
+    staging_name.push(".promote.tmp");
+    let staging_dir = PathBuf::from(staging_name);
+    if staging_dir.exists() {
+        delete_dir_recursive(&staging_dir)?;
+    }
+
+    let result = rebuild_full_archive(backup_path, &staging_dir);
+    let _ = delete_dir_recursive(&staging_dir);
+    let report = result?;
+
+    let sha256 = calculate_checksum_sha256(backup_path)?;
+    write_backup_checksum(backup_path, &sha256)?;
+
+    manifest.kind = BackupKind::Full;
+    manifest.parent = None;
+    manifest.file_sources = None;
+    manifest.file_inventory = Some(report.file_inventory.into_iter().map(Into::into).collect());
+    manifest.file_count_truncated = report.file_inventory_truncated;
+    manifest.sha256 = Some(sha256);
+    manifest.archive_meta = read_archive_meta(backup_path).ok();
+    write_backup_manifest(backup_path, &manifest)?;
+    Ok(())
+}
+
+/// Rebuilds `backup_path` as a self-contained archive, using `staging_dir` for the
+/// extracted save and the new archive.
+fn rebuild_full_archive(
+    backup_path: &Path,
+    staging_dir: &Path,
+) -> BackupResultT<crate::file_ops::ArchiveReport> {
+    let save_dir = staging_dir.join("save");
+    let archive_path = staging_dir.join("full.tar.gz");
+    extract_backup(backup_path, &save_dir)?;
+    let report = create_tar_gz_with_options(&save_dir, &archive_path, &ArchiveOptions::default())?;
+
+    // The staging directory is next to the backup, so this rename replaces the original
+    // atomically and a crash leaves one complete archive. The name keeps the timestamp
+    // backups are ordered by; the modification time is carried over for older names.
+    let modified = fs::metadata(backup_path)?.modified()?;
+    fs::File::options().write(true).open(&archive_path)?.set_modified(modified)?;
+    fs::rename(&archive_path, backup_path)?;
+    Ok(report)
+}
+
+/// Free space required on top of a save's size before backing it up, in percent,
+/// since the compressed size isn't known in advance.
+pub const BACKUP_SPACE_MARGIN_PERCENT: u64 = 10;
+
+/// Free space on the backup volume compared to what a backup of a save needs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupSpaceCheck {
+    /// Uncompressed size of the save
+    pub save_size_bytes: u64,
+    /// Save size plus `BACKUP_SPACE_MARGIN_PERCENT`
+    pub needed_bytes: u64,
+    /// Free space on the backup volume
+    pub available_bytes: u64,
+    /// Whether `available_bytes` covers `needed_bytes`
+    pub sufficient: bool,
+}
+
+/// Compares the space a backup of `save_size_bytes` needs with the free space at
+/// `save_backup_dir` (which may not exist yet).
+fn estimate_backup_space(
+    save_size_bytes: u64,
+    save_backup_dir: &Path,
+) -> FileOpsResult<BackupSpaceCheck> {
+    let needed_bytes = save_size_bytes.saturating_mul(100 + BACKUP_SPACE_MARGIN_PERCENT) / 100;
+    let available_bytes = crate::file_ops::available_space(save_backup_dir)?;
+    Ok(BackupSpaceCheck {
+        save_size_bytes,
+        needed_bytes,
+        available_bytes,
+        sufficient: available_bytes >= needed_bytes,
+    })
+}
+
+/// Checks whether the backup volume has room for a backup of a save.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+///
+/// # Returns
+/// `BackupResultT<BackupSpaceCheck>` - Needed and available space
+///
+/// # Behavior
+/// - Uses the same estimate as `create_backup`, which fails with `InsufficientSpace`
+///   when `sufficient` is false: the uncompressed save size plus
+///   `BACKUP_SPACE_MARGIN_PERCENT`, since compression can't be predicted
+/// - Returns `SaveNotFound` if the save directory doesn't exist
+pub fn check_backup_space(save_name: &str) -> BackupResultT<BackupSpaceCheck> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    let save_dir = config.get_save_path()?.join(save_name);
+    if !save_dir.is_dir() {
+        return Err(BackupError::SaveNotFound(save_name.to_string()));
+    }
+
+    let save_size_bytes = crate::file_ops::get_dir_size(&save_dir)?;
+    let save_backup_dir = get_save_backup_dir(&config.get_backup_path()?, save_name);
+    Ok(estimate_backup_space(save_size_bytes, &save_backup_dir)?)
+}
+
+/// Disk usage of a save's backup directory, split by kind of file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupDirStats {
+    /// Bytes used by backup archives
+    pub archive_bytes: u64,
+    /// Bytes used by sidecar files next to an archive (checksum, manifest, signature)
+    pub sidecar_bytes: u64,
+    /// Bytes used by anything else (manually added files, leftover temporary files)
+    pub other_bytes: u64,
+}
+
+/// Checks whether `path` is a sidecar file (`<archive>.<ext>`) of a backup archive.
+fn is_backup_sidecar(path: &Path) -> bool {
+    let is_sidecar_extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| BACKUP_SIDECAR_EXTENSIONS.contains(&ext));
+    is_sidecar_extension
+        && path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(is_backup_archive)
+}
+
+/// Reports how much space a save's backup directory uses for archives,
+/// sidecar files and other files.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+///
+/// # Returns
+/// `BackupResultT<BackupDirStats>` - Sizes per category (all zero if there are no backups)
+///
+/// # Behavior
+/// - Unlike `get_dir_size`, archive sizes don't include sidecars or files the
+///   user placed in the backup directory
+/// - Subdirectories are not counted: they hold the backups of other saves (e.g. a
+///   two-level save below a flat one of the same name), which are reported on their own
+pub fn get_backup_dir_stats(save_name: &str) -> BackupResultT<BackupDirStats> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
+
+    let mut stats = BackupDirStats::default();
+    if !save_backup_dir.exists() {
+        return Ok(stats);
+    }
+
+    for entry in fs::read_dir(&save_backup_dir)? {
+        let path = entry?.path();
+        let metadata = fs::metadata(&path)?;
+        if metadata.is_dir() {
+            continue;
+        }
+
+        let size = metadata.len();
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+        if is_backup_archive(name) {
+            stats.archive_bytes += size;
+        } else if is_backup_sidecar(&path) {
+            stats.sidecar_bytes += size;
+        } else {
+            stats.other_bytes += size;
+        }
+    }
+
+    Ok(stats)
+}
+
 /// Lists all backups for a specific save.
 ///
 /// # Arguments
@@ -375,8 +1932,12 @@ fn list_backup_files(save_backup_dir: &Path) -> FileOpsResult<Vec<BackupFile>> {
 ///
 /// # Behavior
 /// - Only includes completed .tar.gz files (excludes .tmp temporary files)
+/// - The creation time comes from the timestamp in the backup name, falling back to the
+///   file's creation time for names without one
 /// - Populates tag information for each backup
+/// - Reports paths relative to the backup path when `use_relative_paths` is enabled
 pub fn list_backups(save_name: &str) -> BackupResultT<Vec<BackupInfo>> {
+    validate_save_name(save_name)?;
     let config = config_module::load_config()?;
     let backup_base_path = config.get_backup_path()?;
     let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
@@ -385,27 +1946,28 @@ pub fn list_backups(save_name: &str) -> BackupResultT<Vec<BackupInfo>> {
         return Ok(Vec::new());
     }
 
+    let pinned = read_pinned_backups(&save_backup_dir);
     let mut backups = Vec::new();
 
-    for entry in fs::read_dir(&save_backup_dir).map_err(FileOpsError::Io)? {
-        let entry = entry.map_err(FileOpsError::Io)?;
+    for entry in fs::read_dir(&save_backup_dir)? {
+        let entry = entry?;
         let path = entry.path();
 
-        // Only process completed .tar.gz files (exclude .tmp temporary files)
+        // Only process completed .tar.gz / .zip files (exclude .tmp temporary files)
         if path.is_file() {
             if let Some(name) = path.file_name() {
                 if let Some(name_str) = name.to_str() {
-                    // Check if it's a completed backup file (ends with .tar.gz but not .tar.gz.tmp)
-                    if name_str.ends_with(".tar.gz") && !name_str.ends_with(".tar.gz.tmp") {
-                        let size_bytes = get_file_size(&path)?;
-                        let size_formatted = crate::file_ops::format_size(size_bytes);
+                    // Check if it's a completed backup file (a known archive extension, not .tmp)
+                    if is_backup_archive(name_str) {
+                        let archive_format = ArchiveFormat::from_path(&path).unwrap_or_default();
+                        let (size_bytes, size_formatted) = get_file_size_human(&path)?;
 
                         // Get creation time
-                        let metadata = entry.metadata().map_err(FileOpsError::Io)?;
-                        let created = metadata
-                            .created()
-                            .or_else(|_| metadata.modified())
-                            .unwrap_or_else(|_| SystemTime::now());
+                        let created = backup_created_time(
+                            name_str,
+                            &entry.metadata()?,
+                            config.use_local_time,
+                        );
                         let created_dt: DateTime<Utc> = created.into();
                         let created_at = created_dt.to_rfc3339();
 
@@ -417,15 +1979,23 @@ pub fn list_backups(save_name: &str) -> BackupResultT<Vec<BackupInfo>> {
                         let thumb_data = crate::file_ops::read_file_from_tar_gz_base64(&path, "thumb.png")
                             .unwrap_or(None);
 
+                        let manifest = read_backup_manifest(&path).unwrap_or_default();
+
                         backups.push(BackupInfo {
                             name: name_str.to_string(),
-                            path: crate::file_ops::normalize_path_for_display(&path),
+                            path: relative_backup_path(&config, &backup_base_path, &path)
+                                .unwrap_or_else(|| crate::file_ops::normalize_path_for_display(&path)),
                             size_bytes,
                             size_formatted,
                             created_at,
                             save_name: save_name.to_string(),
                             tags,
                             thumb_data,
+                            label: manifest.label,
+                            archive_format,
+                            checksum_verified: listed_checksum_verified(&path),
+                            kind: manifest.kind,
+                            pinned: pinned.contains(name_str),
                         });
                     }
                 }
@@ -448,6 +2018,8 @@ pub fn list_backups(save_name: &str) -> BackupResultT<Vec<BackupInfo>> {
 /// # Returns
 /// `BackupResultT<BackupInfo>` - Detailed backup information
 pub fn get_backup_info(save_name: &str, backup_name: &str) -> BackupResultT<BackupInfo> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
     let config = config_module::load_config()?;
     let backup_base_path = config.get_backup_path()?;
     let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
@@ -460,14 +2032,10 @@ pub fn get_backup_info(save_name: &str, backup_name: &str) -> BackupResultT<Back
         )));
     }
 
-    let size_bytes = get_file_size(&backup_path)?;
-    let size_formatted = crate::file_ops::format_size(size_bytes);
+    let (size_bytes, size_formatted) = get_file_size_human(&backup_path)?;
 
-    let metadata = fs::metadata(&backup_path).map_err(FileOpsError::Io)?;
-    let created = metadata
-        .created()
-        .or_else(|_| metadata.modified())
-        .unwrap_or_else(|_| SystemTime::now());
+    let created =
+        backup_created_time(backup_name, &fs::metadata(&backup_path)?, config.use_local_time);
     let created_dt: DateTime<Utc> = created.into();
     let created_at = created_dt.to_rfc3339();
 
@@ -479,70 +2047,759 @@ pub fn get_backup_info(save_name: &str, backup_name: &str) -> BackupResultT<Back
     let thumb_data = crate::file_ops::read_file_from_tar_gz_base64(&backup_path, "thumb.png")
         .unwrap_or(None);
 
+    let manifest = read_backup_manifest(&backup_path).unwrap_or_default();
+
     Ok(BackupInfo {
         name: backup_name.to_string(),
-        path: crate::file_ops::normalize_path_for_display(&backup_path),
+        path: relative_backup_path(&config, &backup_base_path, &backup_path)
+            .unwrap_or_else(|| crate::file_ops::normalize_path_for_display(&backup_path)),
         size_bytes,
         size_formatted,
         created_at,
         save_name: save_name.to_string(),
         tags,
         thumb_data,
+        label: manifest.label,
+        archive_format: ArchiveFormat::detect_from_path(&backup_path).unwrap_or_default(),
+        checksum_verified: listed_checksum_verified(&backup_path),
+        kind: manifest.kind,
+        pinned: read_pinned_backups(&save_backup_dir).contains(backup_name),
     })
 }
 
-/// Lists all saves that have at least one backup.
+/// Verifies a backup archive against the checksum recorded when it was created.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `backup_name` - Name of the backup file (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
 ///
 /// # Returns
-/// `BackupResultT<Vec<String>>` - List of save names with backups
-pub fn list_saves_with_backups() -> BackupResultT<Vec<String>> {
+/// `BackupResultT<Option<bool>>` - Whether the archive's SHA-256 matches its
+/// `<backup>.sha256` sidecar, or None if the backup has no sidecar (e.g. older backups)
+///
+/// # Behavior
+/// Always hashes the whole archive; `list_backups` and `get_backup_info` reuse that hash
+/// until the archive's size or modification time changes.
+pub fn verify_backup(save_name: &str, backup_name: &str) -> BackupResultT<Option<bool>> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
     let config = config_module::load_config()?;
     let backup_base_path = config.get_backup_path()?;
+    let backup_path = get_save_backup_dir(&backup_base_path, save_name).join(backup_name);
 
-    if !backup_base_path.exists() {
-        return Ok(Vec::new());
-    }
-
-    let mut saves = Vec::new();
-
-    for entry in fs::read_dir(&backup_base_path).map_err(FileOpsError::Io)? {
-        let entry = entry.map_err(FileOpsError::Io)?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            if let Some(name) = path.file_name() {
-                if let Some(name_str) = name.to_str() {
-                    saves.push(name_str.to_string());
-                }
-            }
-        }
+    if !backup_path.is_file() {
+        return Err(BackupError::BackupNotFound(format!(
+            "{}/{}",
+            save_name, backup_name
+        )));
     }
 
-    saves.sort();
-
-    Ok(saves)
+    Ok(check_backup_checksum(&backup_path, false)?)
 }
 
-/// Counts the number of backups for a specific save.
+/// Gets the sidecar manifest of a specific backup.
 ///
 /// # Arguments
-/// * `save_name` - Name of the save
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `backup_name` - Name of the backup file (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
 ///
 /// # Returns
-/// `BackupResultT<usize>` - Number of backups
-pub fn count_backups(save_name: &str) -> BackupResultT<usize> {
-    let backups = list_backups(save_name)?;
-    Ok(backups.len())
+/// `BackupResultT<BackupManifest>` - The manifest, or an empty one if the backup has none
+///
+/// # Behavior
+/// The file inventory lets callers browse a backup without decompressing it.
+pub fn get_backup_manifest(save_name: &str, backup_name: &str) -> BackupResultT<BackupManifest> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
+    let backup_path = save_backup_dir.join(backup_name);
+
+    if !backup_path.exists() {
+        return Err(BackupError::BackupNotFound(format!(
+            "{}/{}",
+            save_name, backup_name
+        )));
+    }
+
+    Ok(read_backup_manifest(&backup_path).unwrap_or_default())
 }
 
-/// Deletes a specific backup (async version).
+/// An entry (file or directory) inside a backup archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    /// Path relative to the save directory (using `/` separators)
+    pub path: String,
+    /// Size in bytes (0 for directories)
+    pub size: u64,
+    /// Whether the entry is a directory
+    pub is_dir: bool,
+}
+
+/// Lists the files and directories inside a backup without extracting it.
 ///
 /// # Arguments
 /// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
-/// * `backup_name` - Name of the backup file to delete (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
+/// * `backup_name` - Name of the backup file (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
 ///
 /// # Returns
-/// `BackupResultT<()>` - Ok(()) on success
+/// `BackupResultT<Vec<BackupEntry>>` - Entries in archive order
+///
+/// # Behavior
+/// - Only entry headers are read; file contents are skipped, never written to disk
+/// - The archive root itself and entries escaping it (absolute paths or `..`) are left out
+/// - Incremental backups list only the files they store, not their parent's
+pub fn list_backup_contents(save_name: &str, backup_name: &str) -> BackupResultT<Vec<BackupEntry>> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let backup_path = get_save_backup_dir(&backup_base_path, save_name).join(backup_name);
+
+    if !backup_path.is_file() {
+        return Err(BackupError::BackupNotFound(format!(
+            "{}/{}",
+            save_name, backup_name
+        )));
+    }
+
+    read_archive_entries(&backup_path)
+}
+
+/// Reads the entry headers of an archive (see `list_backup_contents`).
+pub(crate) fn read_archive_entries(backup_path: &Path) -> BackupResultT<Vec<BackupEntry>> {
+    let mut entries = Vec::new();
+    match ArchiveFormat::detect_from_path(backup_path).unwrap_or_default() {
+        ArchiveFormat::TarGz => {
+            let decoder = flate2::read::MultiGzDecoder::new(fs::File::open(backup_path)?);
+            let mut archive = tar::Archive::new(decoder);
+            for entry in archive.entries()? {
+                let entry = entry?;
+                let Some(path) = archive_entry_path(&entry.path()?) else {
+                    continue;
+                };
+                let is_dir = entry.header().entry_type().is_dir();
+                entries.push(BackupEntry {
+                    path,
+                    size: if is_dir { 0 } else { entry.size() },
+                    is_dir,
+                });
+            }
+        }
+        ArchiveFormat::Zip => {
+            let mut archive = zip::ZipArchive::new(fs::File::open(backup_path)?)
+                .map_err(std::io::Error::from)?;
+            for index in 0..archive.len() {
+                let entry = archive.by_index(index).map_err(std::io::Error::from)?;
+                let Some(path) = archive_entry_path(Path::new(entry.name())) else {
+                    continue;
+                };
+                entries.push(BackupEntry {
+                    path,
+                    size: entry.size(),
+                    is_dir: entry.is_dir(),
+                });
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Returns the sizes of the files a backup restores, by relative path.
+///
+/// # Behavior
+/// Incremental backups are resolved against their parent, so the result describes the
+/// whole save (see `extract_backup`); only entry headers are read.
+pub(crate) fn backup_file_sizes(backup_path: &Path) -> BackupResultT<BTreeMap<String, u64>> {
+    let files_of = |path: &Path| -> BackupResultT<BTreeMap<String, u64>> {
+        Ok(read_archive_entries(path)?
+            .into_iter()
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| (entry.path, entry.size))
+            .collect())
+    };
+
+    let Some(parent_path) = backup_parent_path(backup_path)? else {
+        return files_of(backup_path);
+    };
+
+    let mut files = files_of(&parent_path)?;
+    files.extend(files_of(backup_path)?);
+    // Manifest paths use the platform separator; archive paths always use `/`
+    let restored: HashSet<String> = incremental_file_sources(backup_path)?
+        .keys()
+        .filter_map(|path| archive_entry_path(Path::new(path)))
+        .collect();
+    files.retain(|path, _| restored.contains(path));
+    Ok(files)
+}
+
+/// A file that differs between two backups.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupDiffFile {
+    /// Path relative to the save directory (using `/` separators)
+    pub path: String,
+    /// Size of the file in `backup_a` (None if only `backup_b` has it)
+    pub size_a: Option<u64>,
+    /// Size of the file in `backup_b` (None if only `backup_a` has it)
+    pub size_b: Option<u64>,
+}
+
+/// Differences between two backups of a save, computed from their entry headers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupDiff {
+    /// Files in `backup_b` that `backup_a` doesn't have
+    pub added: Vec<BackupDiffFile>,
+    /// Files in `backup_a` that `backup_b` doesn't have
+    pub removed: Vec<BackupDiffFile>,
+    /// Files in both backups with different sizes
+    pub changed: Vec<BackupDiffFile>,
+    /// Number of files in both backups with the same size
+    pub unchanged_count: usize,
+}
+
+/// Compares two backups of a save without extracting them.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `backup_a` - Name of the older (or reference) backup
+/// * `backup_b` - Name of the backup to compare against it
+///
+/// # Returns
+/// `BackupResultT<BackupDiff>` - Added, removed and size-changed files, each sorted by
+/// path; `BackupNotFound` if either backup doesn't exist
+///
+/// # Behavior
+/// - Only entry headers are read (see `list_backup_contents`), so this stays fast on
+///   large archives
+/// - Files are compared by size only: a file rewritten with the same size counts as
+///   unchanged
+/// - Incremental backups are compared as the full save they restore to
+pub fn diff_backups(save_name: &str, backup_a: &str, backup_b: &str) -> BackupResultT<BackupDiff> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_a)?;
+    validate_backup_name(backup_b)?;
+    let config = config_module::load_config()?;
+    let save_backup_dir = get_save_backup_dir(&config.get_backup_path()?, save_name);
+
+    let files_of = |backup_name: &str| {
+        let backup_path = save_backup_dir.join(backup_name);
+        if !backup_path.is_file() {
+            return Err(BackupError::BackupNotFound(format!("{}/{}", save_name, backup_name)));
+        }
+        backup_file_sizes(&backup_path)
+    };
+    let files_a = files_of(backup_a)?;
+    let files_b = files_of(backup_b)?;
+
+    let mut diff = BackupDiff::default();
+    for (path, &size_a) in &files_a {
+        let file = BackupDiffFile {
+            path: path.clone(),
+            size_a: Some(size_a),
+            size_b: files_b.get(path).copied(),
+        };
+        match file.size_b {
+            None => diff.removed.push(file),
+            Some(size_b) if size_b != size_a => diff.changed.push(file),
+            Some(_) => diff.unchanged_count += 1,
+        }
+    }
+    for (path, &size_b) in &files_b {
+        if !files_a.contains_key(path) {
+            diff.added.push(BackupDiffFile {
+                path: path.clone(),
+                size_a: None,
+                size_b: Some(size_b),
+            });
+        }
+    }
+
+    Ok(diff)
+}
+
+/// Returns an archive entry's path with `/` separators, or None for the archive root
+/// and paths that would escape it.
+pub(crate) fn archive_entry_path(path: &Path) -> Option<String> {
+    use std::path::Component;
+
+    let mut parts = Vec::new();
+    for comp in path.components() {
+        match comp {
+            Component::Normal(part) => parts.push(part.to_string_lossy()),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("/"))
+    }
+}
+
+/// Lists all saves that have at least one backup.
+///
+/// # Returns
+/// `BackupResultT<Vec<String>>` - List of save names with backups, sorted
+///
+/// # Behavior
+/// Looks two levels deep, like the saves directory: a backup directory holding archives
+/// is a save (e.g. "OldSave"), and so is each directory below it that holds archives
+/// (e.g. "Survival/MySave"). A game mode directory without archives of its own is not
+/// listed.
+pub fn list_saves_with_backups() -> BackupResultT<Vec<String>> {
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+
+    if !backup_base_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut saves = Vec::new();
+
+    for (top_name, top_path) in backup_subdirs(&backup_base_path)? {
+        if dir_has_backup_archive(&top_path)? {
+            saves.push(top_name.clone());
+        }
+        for (name, path) in backup_subdirs(&top_path)? {
+            if dir_has_backup_archive(&path)? {
+                saves.push(format!("{}/{}", top_name, name));
+            }
+        }
+    }
+
+    saves.sort();
+
+    Ok(saves)
+}
+
+/// Lists the subdirectories of a backup directory that may hold a save's backups.
+///
+/// # Returns
+/// `std::io::Result<Vec<(String, PathBuf)>>` - Name and path of each subdirectory, leaving
+/// out undo snapshot directories (`_undo` and legacy `<save>_undo`)
+fn backup_subdirs(dir: &Path) -> std::io::Result<Vec<(String, PathBuf)>> {
+    let mut subdirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            if !name.ends_with("_undo") {
+                subdirs.push((name.to_string(), path.clone()));
+            }
+        }
+    }
+    Ok(subdirs)
+}
+
+/// Checks whether a directory directly contains at least one backup archive.
+fn dir_has_backup_archive(dir: &Path) -> std::io::Result<bool> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() && is_backup_archive(&entry.file_name().to_string_lossy()) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Moves a save's backups to a new save name, e.g. after the save was renamed in-game.
+///
+/// # Arguments
+/// * `old_name` - Relative path the backups are stored under (e.g., "Survival/MySave")
+/// * `new_name` - Relative path to store them under (e.g., "Survival/Apocalypse")
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success; `BackupNotFound` if `old_name` has neither
+/// backups nor undo snapshots, `DestinationExists` if `new_name` already has either
+///
+/// # Behavior
+/// - Moves the backup directory and the undo snapshot directory, creating the new
+///   game mode directory if needed and removing the old one once it is empty
+/// - Saves nested below `old_name` move along with it (renaming the game mode
+///   `Survival` also moves `Survival/MySave`), including their undo snapshots
+/// - Backup tags follow the backups; failing to update them doesn't fail the move
+pub fn rename_save_backups(old_name: &str, new_name: &str) -> BackupResultT<()> {
+    validate_save_name(old_name)?;
+    validate_save_name(new_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+
+    let old_dir = get_save_backup_dir(&backup_base_path, old_name);
+    let new_dir = get_save_backup_dir(&backup_base_path, new_name);
+    if new_dir.starts_with(&old_dir) {
+        return Err(BackupError::InvalidSaveName(format!(
+            "{} is inside {}",
+            new_name, old_name
+        )));
+    }
+
+    // Undo directories mirror the save paths, so the save's own directory and the
+    // one holding its nested saves' undo directories move like the backups do
+    let undo_moves: Vec<(PathBuf, PathBuf)> = [
+        (
+            get_undo_snapshot_dir(&backup_base_path, old_name),
+            get_undo_snapshot_dir(&backup_base_path, new_name),
+        ),
+        (
+            get_nested_undo_snapshot_root(&backup_base_path, old_name),
+            get_nested_undo_snapshot_root(&backup_base_path, new_name),
+        ),
+    ]
+    .into_iter()
+    .filter(|(from, _)| from.is_dir())
+    .collect();
+
+    if !old_dir.is_dir() && undo_moves.is_empty() {
+        return Err(BackupError::BackupNotFound(old_name.to_string()));
+    }
+    let conflict = std::iter::once(&new_dir)
+        .filter(|_| old_dir.is_dir())
+        .chain(undo_moves.iter().map(|(_, to)| to))
+        .find(|to| to.exists());
+    if let Some(conflict) = conflict {
+        return Err(FileOpsError::DestinationExists(conflict.clone()).into());
+    }
+
+    if old_dir.is_dir() {
+        if let Some(parent) = new_dir.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(&old_dir, &new_dir)?;
+        // Drop the old game mode directory if this was its last save
+        if let Some(parent) = old_dir.parent() {
+            if parent != backup_base_path {
+                let _ = fs::remove_dir(parent);
+            }
+        }
+    }
+    let undo_root = backup_base_path.join(UNDO_SNAPSHOT_ROOT);
+    for (from, to) in &undo_moves {
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(from, to)?;
+        if let Some(parent) = from.parent() {
+            if parent != undo_root {
+                let _ = fs::remove_dir(parent);
+            }
+        }
+    }
+
+    let _ = crate::tags::rename_backup_tags_save(old_name, new_name);
+    Ok(())
+}
+
+/// Aggregate backup statistics for the dashboard.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupSummary {
+    /// Number of backups across all saves
+    pub total_backups: usize,
+    /// Number of saves that have at least one backup
+    pub total_saves_with_backups: usize,
+    /// Combined size of all backups in bytes
+    pub total_size_bytes: u64,
+    /// Combined size of backup sidecar files in bytes
+    pub total_sidecar_bytes: u64,
+    /// Combined size of other files in backup directories in bytes
+    pub total_other_bytes: u64,
+    /// Backup with the earliest creation time
+    pub oldest_backup: Option<BackupInfo>,
+    /// Backup with the latest creation time
+    pub newest_backup: Option<BackupInfo>,
+    /// Backup with the largest size
+    pub largest_backup: Option<BackupInfo>,
+    /// Average backup size in bytes (0 if there are no backups)
+    pub average_backup_size_bytes: u64,
+    /// ISO 8601 timestamp of the newest backup
+    pub last_backup_at: Option<String>,
+}
+
+/// Computes aggregate statistics over all backups.
+///
+/// # Returns
+/// `BackupResultT<BackupSummary>` - Totals plus the oldest, newest and largest backup
+///
+/// # Behavior
+/// Iterates every save returned by `list_saves_with_backups` (including two-level saves
+/// like "Survival/MySave") and its backups. Sidecar and other files are reported
+/// separately from `total_size_bytes` (see `get_backup_dir_stats`).
+pub fn get_backup_summary() -> BackupResultT<BackupSummary> {
+    let mut summary = BackupSummary::default();
+    let mut backups = Vec::new();
+
+    for save_name in list_saves_with_backups()? {
+        let save_backups = list_backups(&save_name)?;
+        if !save_backups.is_empty() {
+            summary.total_saves_with_backups += 1;
+        }
+        backups.extend(save_backups);
+
+        let dir_stats = get_backup_dir_stats(&save_name)?;
+        summary.total_sidecar_bytes += dir_stats.sidecar_bytes;
+        summary.total_other_bytes += dir_stats.other_bytes;
+    }
+
+    summary.total_backups = backups.len();
+    summary.total_size_bytes = backups.iter().map(|b| b.size_bytes).sum();
+    if !backups.is_empty() {
+        summary.average_backup_size_bytes = summary.total_size_bytes / backups.len() as u64;
+    }
+    summary.oldest_backup = backups.iter().min_by(|a, b| a.created_at.cmp(&b.created_at)).cloned();
+    summary.newest_backup = backups.iter().max_by(|a, b| a.created_at.cmp(&b.created_at)).cloned();
+    summary.largest_backup = backups.iter().max_by_key(|b| b.size_bytes).cloned();
+    summary.last_backup_at = summary.newest_backup.as_ref().map(|b| b.created_at.clone());
+
+    Ok(summary)
+}
+
+/// Disk space used by backups, in total and per save.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupStorageStats {
+    /// Combined size of all backup archives in bytes
+    pub total_bytes: u64,
+    /// Archive bytes per save (as returned by `list_saves_with_backups`)
+    pub per_save_bytes: HashMap<String, u64>,
+    /// Number of backup archives across all saves
+    pub total_archives: usize,
+    /// Size of all undo snapshot directories in bytes (not part of `total_bytes`)
+    pub undo_snapshot_bytes: u64,
+}
+
+/// Reports how much disk space backups use.
+///
+/// # Returns
+/// `BackupResultT<BackupStorageStats>` - Archive totals, per-save breakdown and undo
+/// snapshot usage; all zeros if the backup directory doesn't exist yet
+///
+/// # Behavior
+/// - Covers every save from `list_saves_with_backups`, including two-level saves
+/// - Only backup archives are counted per save; sidecar files are left out
+///   (see `get_backup_dir_stats` for those)
+/// - Sizes come from file metadata alone, so no archive is opened or hashed
+/// - Undo snapshots (`_undo`, and legacy `<save>_undo` directories next to a save's
+///   backups) are counted separately in `undo_snapshot_bytes`
+pub fn get_backup_storage_stats() -> BackupResultT<BackupStorageStats> {
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+
+    let mut stats = BackupStorageStats::default();
+    if !backup_base_path.exists() {
+        return Ok(stats);
+    }
+
+    for save_name in list_saves_with_backups()? {
+        let mut save_bytes = 0;
+        for entry in fs::read_dir(get_save_backup_dir(&backup_base_path, &save_name))? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_file() && is_backup_archive(&entry.file_name().to_string_lossy()) {
+                save_bytes += metadata.len();
+                stats.total_archives += 1;
+            }
+        }
+        stats.total_bytes += save_bytes;
+        stats.per_save_bytes.insert(save_name, save_bytes);
+    }
+
+    let mut undo_dirs = undo_dirs_in(&backup_base_path)?;
+    for (_, top_path) in backup_subdirs(&backup_base_path)? {
+        undo_dirs.extend(undo_dirs_in(&top_path)?);
+    }
+    for path in undo_dirs {
+        stats.undo_snapshot_bytes += crate::file_ops::get_dir_size(&path)?;
+    }
+
+    Ok(stats)
+}
+
+/// Lists the undo snapshot directories (names ending in `_undo`) directly inside `dir`.
+fn undo_dirs_in(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut undo_dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_undo_dir = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with("_undo"));
+        if is_undo_dir && path.is_dir() {
+            undo_dirs.push(path);
+        }
+    }
+    Ok(undo_dirs)
+}
+
+/// Counts the number of backups for a specific save.
+///
+/// # Arguments
+/// * `save_name` - Name of the save
+///
+/// # Returns
+/// `BackupResultT<usize>` - Number of backups
+pub fn count_backups(save_name: &str) -> BackupResultT<usize> {
+    let backups = list_backups(save_name)?;
+    Ok(backups.len())
+}
+
+/// Compact, one-line view of a backup for log and CSV export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupSummaryLine {
+    /// Name of the backup file
+    pub name: String,
+    /// Human-readable size string
+    pub size_formatted: String,
+    /// ISO 8601 timestamp when backup was created
+    pub created_at: String,
+    /// Whether the backup is pinned
+    pub pinned: bool,
+    /// Optional note (the label given when the backup was created)
+    pub note: Option<String>,
+}
+
+impl From<&BackupInfo> for BackupSummaryLine {
+    fn from(info: &BackupInfo) -> Self {
+        Self {
+            name: info.name.clone(),
+            size_formatted: info.size_formatted.clone(),
+            created_at: info.created_at.clone(),
+            pinned: info.pinned,
+            note: info.label.clone(),
+        }
+    }
+}
+
+/// Header row written by `export_backups_to_csv`.
+const BACKUP_CSV_HEADER: &str = "name,size_formatted,created_at,pinned,note";
+
+/// Quotes a CSV field if it contains a separator, quote or line break (RFC 4180).
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Exports the backups of a save as CSV.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
+/// * `dst_path` - CSV file to write (overwritten if it exists)
+///
+/// # Returns
+/// `BackupResultT<usize>` - Number of rows written, excluding the header
+///
+/// # Behavior
+/// Rows follow `list_backups` order (newest first), one `BackupSummaryLine` each.
+pub fn export_backups_to_csv(save_name: &str, dst_path: &Path) -> BackupResultT<usize> {
+    let lines: Vec<BackupSummaryLine> =
+        list_backups(save_name)?.iter().map(BackupSummaryLine::from).collect();
+
+    let mut csv = String::from(BACKUP_CSV_HEADER);
+    csv.push('\n');
+    for line in &lines {
+        let row = [
+            escape_csv_field(&line.name),
+            escape_csv_field(&line.size_formatted),
+            escape_csv_field(&line.created_at),
+            line.pinned.to_string(),
+            escape_csv_field(line.note.as_deref().unwrap_or("")),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    fs::write(dst_path, csv)?;
+    Ok(lines.len())
+}
+
+/// Pin file in each save's backup directory, holding a JSON array of pinned backup names.
+const PINNED_FILE_NAME: &str = ".pinned";
+
+/// Returns the names of the pinned backups in a save's backup directory.
+///
+/// A missing or unreadable pin file means no backup is pinned, so GC never fails on it.
+fn read_pinned_backups(save_backup_dir: &Path) -> HashSet<String> {
+    fs::read_to_string(save_backup_dir.join(PINNED_FILE_NAME))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Adds `backup_name` to or removes it from a save's pin file.
+///
+/// The file is rewritten with the names sorted, and removed once nothing is pinned.
+fn write_backup_pinned(
+    save_backup_dir: &Path,
+    backup_name: &str,
+    pinned: bool,
+) -> FileOpsResult<()> {
+    let mut names = read_pinned_backups(save_backup_dir);
+    let changed = if pinned {
+        names.insert(backup_name.to_string())
+    } else {
+        names.remove(backup_name)
+    };
+    if !changed {
+        return Ok(());
+    }
+
+    let pin_path = save_backup_dir.join(PINNED_FILE_NAME);
+    if names.is_empty() {
+        return delete_file(&pin_path);
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort();
+    let json = serde_json::to_string_pretty(&names).map_err(std::io::Error::from)?;
+    fs::write(pin_path, json)?;
+    Ok(())
+}
+
+/// Pins or unpins a backup; pinned backups are never deleted by retention or age-based GC.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `backup_name` - Name of the backup file (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
+/// * `pinned` - true to keep the backup forever, false to let GC manage it again
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success, `BackupNotFound` if the backup doesn't exist
+///
+/// # Behavior
+/// - Pinned names are stored in a `.pinned` JSON file in the save's backup directory
+/// - Pinned backups don't count against `retention_count`, so the newest unpinned
+///   backups are still kept in full
+/// - Deleting a backup with `delete_backup` works as usual and unpins it
+pub fn set_backup_pinned(save_name: &str, backup_name: &str, pinned: bool) -> BackupResultT<()> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+    let config = config_module::load_config()?;
+    let save_backup_dir = get_save_backup_dir(&config.get_backup_path()?, save_name);
+
+    if !save_backup_dir.join(backup_name).is_file() {
+        return Err(BackupError::BackupNotFound(format!(
+            "{}/{}",
+            save_name, backup_name
+        )));
+    }
+
+    write_backup_pinned(&save_backup_dir, backup_name, pinned)?;
+    Ok(())
+}
+
+/// Deletes a specific backup (async version).
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `backup_name` - Name of the backup file to delete (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success
 ///
 /// # Behavior
 /// Runs the synchronous delete operation in a blocking thread pool to avoid
@@ -555,10 +2812,10 @@ pub async fn delete_backup_async(save_name: &str, backup_name: &str) -> BackupRe
     let backup_name = backup_name.to_string();
     tokio::task::spawn_blocking(move || delete_backup(&save_name, &backup_name))
         .await
-        .map_err(|e| BackupError::FileOp(FileOpsError::Io(std::io::Error::new(
+        .map_err(|e| std::io::Error::new(
             std::io::ErrorKind::Other,
             format!("Task join error: {}", e),
-        ))))?
+        ))?
 }
 
 /// Deletes a specific backup.
@@ -570,9 +2827,14 @@ pub async fn delete_backup_async(save_name: &str, backup_name: &str) -> BackupRe
 /// # Returns
 /// `BackupResultT<()>` - Ok(()) on success
 ///
+/// # Behavior
+/// Incremental backups layered over the deleted backup are rewritten as full backups first.
+///
 /// # Safety
 /// This is a destructive operation. Frontend should confirm with user before calling.
 pub fn delete_backup(save_name: &str, backup_name: &str) -> BackupResultT<()> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
     let config = config_module::load_config()?;
     let backup_base_path = config.get_backup_path()?;
     let save_backup_dir = get_save_backup_dir(&backup_base_path, save_name);
@@ -585,7 +2847,12 @@ pub fn delete_backup(save_name: &str, backup_name: &str) -> BackupResultT<()> {
         )));
     }
 
-    delete_file(&backup_path)?;
+    // Incremental backups layered over this one become full backups first
+    promote_dependents(&save_backup_dir, backup_name, config.use_local_time)?;
+    delete_backup_with_sidecars(&backup_path)?;
+    // A later backup with the same name must not inherit the pin
+    let _ = write_backup_pinned(&save_backup_dir, backup_name, false);
+    let _ = update_statistics(&backup_base_path, |stats| stats.record_deletions(save_name, 1, 0));
     Ok(())
 }
 
@@ -607,9 +2874,10 @@ mod tests {
             .unwrap()
             .write_all(b"game state")
             .unwrap();
+        // 1 KB map chunk, so the save passes the default minimum save size
         File::create(save_dir.join("map/pchunk_0_0.dat"))
             .unwrap()
-            .write_all(b"map data")
+            .write_all(&[b'm'; 1024])
             .unwrap();
         File::create(save_dir.join("map/pchunk_0_1.dat"))
             .unwrap()
@@ -639,18 +2907,67 @@ mod tests {
     }
 
     #[test]
-    fn test_get_save_backup_dir() {
-        let base = Path::new("/backups");
-        let save_dir = get_save_backup_dir(base, "Survival");
-        assert_eq!(save_dir, Path::new("/backups/Survival"));
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
     }
 
     #[test]
-    fn test_list_backup_files_empty() {
-        let temp_dir = TempDir::new().unwrap();
-        let backups = list_backup_files(temp_dir.path()).unwrap();
-        assert_eq!(backups.len(), 0);
-    }
+    #[serial]
+    fn test_export_backups_to_csv() {
+        let save_temp = TempDir::new().unwrap();
+        let backup_temp = TempDir::new().unwrap();
+        let export_temp = TempDir::new().unwrap();
+        create_test_save(&save_temp.path().join("Survival"));
+        setup_test_config(save_temp.path(), backup_temp.path());
+
+        for label in [None, Some("Before horde night"), Some("Base, day 3")] {
+            let options = BackupOptions {
+                label: label.map(String::from),
+                ..BackupOptions::default()
+            };
+            create_backup_with_options("Survival", &options).unwrap();
+            // Backup names have second precision
+            std::thread::sleep(std::time::Duration::from_millis(1100));
+        }
+
+        let csv_path = export_temp.path().join("backups.csv");
+        let rows = export_backups_to_csv("Survival", &csv_path).unwrap();
+        assert_eq!(rows, 3);
+
+        let content = fs::read_to_string(&csv_path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], BACKUP_CSV_HEADER);
+
+        let backups = list_backups("Survival").unwrap();
+        for (line, backup) in lines[1..].iter().zip(&backups) {
+            let expected = format!(
+                "{},{},{},false,",
+                backup.name, backup.size_formatted, backup.created_at
+            );
+            assert!(line.starts_with(&expected), "{}", line);
+        }
+        // Newest first; the comma in the label forces quoting
+        assert!(lines[1].ends_with(",\"Base, day 3\""));
+        assert!(lines[2].ends_with(",Before horde night"));
+        assert!(lines[3].ends_with(",false,"));
+    }
+
+    #[test]
+    fn test_get_save_backup_dir() {
+        let base = Path::new("/backups");
+        let save_dir = get_save_backup_dir(base, "Survival");
+        assert_eq!(save_dir, Path::new("/backups/Survival"));
+    }
+
+    #[test]
+    fn test_list_backup_files_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let backups = list_backup_files(temp_dir.path(), false).unwrap();
+        assert_eq!(backups.len(), 0);
+    }
 
     #[test]
     fn test_list_backup_files_with_backups() {
@@ -661,7 +2978,7 @@ mod tests {
         File::create(&backup1).unwrap().write_all(b"data").unwrap();
         File::create(&backup2).unwrap().write_all(b"data").unwrap();
 
-        let backups = list_backup_files(temp_dir.path()).unwrap();
+        let backups = list_backup_files(temp_dir.path(), false).unwrap();
         assert_eq!(backups.len(), 2);
     }
 
@@ -684,6 +3001,143 @@ mod tests {
         assert_eq!(result.deleted_count, 0);
     }
 
+    #[test]
+    #[serial]
+    fn test_create_backup_with_progress() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let mut calls = Vec::new();
+        create_backup_with_progress("Survival", &BackupOptions::default(), &mut |processed, total| {
+            calls.push((processed, total))
+        })
+        .unwrap();
+
+        // save.bin (10) + pchunk_0_0.dat (1024) + pchunk_0_1.dat (8)
+        let total = 1042;
+        assert_eq!(calls.first(), Some(&(0, total)));
+        assert_eq!(calls.last(), Some(&(total, total)));
+        // Start, one call per file, completion
+        assert_eq!(calls.len(), 5);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_create_backup_exclusive_same_save() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        create_test_save(&save_base.path().join("Survival"));
+        create_test_save(&save_base.path().join("Builder"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let semaphores = GlobalBackupSemaphores::new();
+        let permit = semaphores.try_acquire("Survival").unwrap();
+
+        // A second backup of the same save is rejected while the first runs
+        let result =
+            create_backup_exclusive(&semaphores, "Survival", BackupOptions::default(), None).await;
+        assert!(matches!(result, Err(BackupError::AlreadyInProgress(name)) if name == "Survival"));
+
+        // Other saves are not blocked
+        create_backup_exclusive(&semaphores, "Builder", BackupOptions::default(), None)
+            .await
+            .unwrap();
+
+        drop(permit);
+        create_backup_exclusive(&semaphores, "Survival", BackupOptions::default(), None)
+            .await
+            .unwrap();
+
+        // The permit is released once the backup finishes
+        assert!(semaphores.try_acquire("Survival").is_ok());
+    }
+
+    #[test]
+    fn test_save_permit_removes_idle_semaphore() {
+        let semaphores = GlobalBackupSemaphores::new();
+
+        let permit = semaphores.try_acquire("Survival").unwrap();
+        assert_eq!(semaphores.len(), 1);
+
+        // A rejected attempt doesn't drop the holder's entry
+        assert!(semaphores.try_acquire("Survival").is_err());
+        assert_eq!(semaphores.len(), 1);
+
+        drop(permit);
+        assert_eq!(semaphores.len(), 0);
+
+        // The save can be locked again after its entry was removed
+        let _permit = semaphores.try_acquire("Survival").unwrap();
+        assert!(semaphores.try_acquire("Survival").is_err());
+    }
+
+    #[test]
+    fn test_try_acquire_pair() {
+        let semaphores = GlobalBackupSemaphores::new();
+
+        // The same save on both sides is locked once
+        let (_first, second) = semaphores.try_acquire_pair("Survival", "Survival").unwrap();
+        assert!(second.is_none());
+
+        // A busy second save releases the first as well
+        let busy = semaphores.try_acquire("Builder").unwrap();
+        assert!(semaphores.try_acquire_pair("Old", "Builder").is_err());
+        assert!(semaphores.try_acquire("Old").is_ok());
+        drop(busy);
+    }
+
+    #[test]
+    fn test_create_backup_rejects_escaping_save_name() {
+        // Rejected before the config (or any save directory) is read
+        for name in ["../../etc", "/absolute", "Survival/../../etc", "bad\0name", ""] {
+            let result = create_backup(name);
+            assert!(
+                matches!(result, Err(BackupError::InvalidSaveName(ref n)) if n == name),
+                "{:?} was not rejected",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_save_and_backup_names() {
+        assert!(validate_save_name("Survival").is_ok());
+        assert!(validate_save_name("Sandbox/My..Save").is_ok());
+        assert!(validate_save_name("..\\etc").is_err());
+
+        assert!(validate_backup_name("Survival_2024-12-28_14-30-45.tar.gz").is_ok());
+        assert!(matches!(
+            delete_backup("Survival", "../other/backup.tar.gz"),
+            Err(BackupError::InvalidBackupName(_))
+        ));
+        assert!(validate_backup_name("..").is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_skips_empty_save() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        fs::create_dir_all(save_base.path().join("Survival")).unwrap();
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let result = create_backup("Survival");
+        assert!(matches!(
+            result,
+            Err(BackupError::SaveTooSmall { ref save_name, size_bytes: 0 }) if save_name == "Survival"
+        ));
+        assert!(!backup_base.path().join("Survival").exists());
+
+        // A minimum of 0 disables the check
+        config_module::update_min_save_size(0).unwrap();
+        assert!(create_backup("Survival").is_ok());
+    }
+
     #[test]
     #[serial]
     fn test_create_backup_save_not_found() {
@@ -696,56 +3150,739 @@ mod tests {
         assert!(matches!(result, Err(BackupError::SaveNotFound(_))));
     }
 
+    #[test]
+    #[serial]
+    fn test_create_backup_creates_missing_backup_dir() {
+        let save_base = TempDir::new().unwrap();
+        let backup_root = TempDir::new().unwrap();
+        let backup_base = backup_root.path().join("not/yet/created");
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), &backup_base);
+
+        let result = create_backup("Survival").unwrap();
+        assert!(backup_base.is_dir());
+        assert!(backup_base.join("Survival").join(&result.backup_name).exists());
+    }
+
+    #[test]
+    fn test_get_or_create_backup_path_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let blocker = temp_dir.path().join("file.txt");
+        File::create(&blocker).unwrap().write_all(b"not a dir").unwrap();
+
+        let config = Config {
+            backup_path: Some(blocker.join("backups").to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let result = get_or_create_backup_path(&config);
+        assert!(matches!(result, Err(BackupError::CreateDirectoryFailed(_, _))));
+    }
+
+    #[test]
+    fn test_parse_backup_timestamp() {
+        let expected = chrono::NaiveDate::from_ymd_opt(2024, 12, 28)
+            .unwrap()
+            .and_hms_opt(14, 30, 45)
+            .unwrap();
+        assert_eq!(parse_backup_timestamp("2024-12-28_14-30-45.tar.gz"), Some(expected));
+        assert_eq!(parse_backup_timestamp("2024-12-28_14-30-45.zip"), Some(expected));
+        assert_eq!(parse_backup_timestamp("Survival_2024-12-28_14-30-45.tar.gz"), Some(expected));
+        assert_eq!(parse_backup_timestamp("before-horde.tar.gz"), None);
+        assert_eq!(parse_backup_timestamp("2024-12-28_14-30-45.tar.gz.tmp"), None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_prune_backups_older_than() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let save_backup_dir = backup_base.path().join("Survival");
+        fs::create_dir_all(&save_backup_dir).unwrap();
+        let days_ago = |days: i64| {
+            let created = Utc::now() - chrono::TimeDelta::days(days);
+            format!("{}.tar.gz", created.format("%Y-%m-%d_%H-%M-%S"))
+        };
+        for name in [days_ago(40), days_ago(35), days_ago(5), "renamed.tar.gz".to_string()] {
+            fs::write(save_backup_dir.join(name), b"data").unwrap();
+        }
+        fs::write(save_backup_dir.join(format!("{}.sha256", days_ago(40))), b"hash").unwrap();
+        // Modification times are all recent; only the names say how old the backups are
+        assert_eq!(prune_backups_older_than("Survival", 30).unwrap(), 2);
+        assert!(!save_backup_dir.join(format!("{}.sha256", days_ago(40))).exists());
+        let mut remaining: Vec<String> =
+            list_backups("Survival").unwrap().into_iter().map(|b| b.name).collect();
+        remaining.sort();
+        assert_eq!(remaining, vec![days_ago(5), "renamed.tar.gz".to_string()]);
+
+        // Garbage collection after a backup applies the configured age limit too
+        fs::write(save_backup_dir.join(days_ago(60)), b"data").unwrap();
+        let mut config = config_module::load_config().unwrap();
+        config.max_backup_age_days = Some(30);
+        config_module::save_config(&config).unwrap();
+        let result = create_backup("Survival").unwrap();
+        assert_eq!(result.deleted_count, 1);
+        assert_eq!(list_backups("Survival").unwrap().len(), 3);
+    }
+
+    #[test]
+    #[serial]
+    fn test_pinned_backups_survive_gc() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let save_backup_dir = backup_base.path().join("Survival");
+        fs::create_dir_all(&save_backup_dir).unwrap();
+        let names = [
+            "2024-01-01_10-00-00.tar.gz",
+            "2024-01-02_10-00-00.tar.gz",
+            "2024-01-03_10-00-00.tar.gz",
+        ];
+        for name in names {
+            fs::write(save_backup_dir.join(name), b"data").unwrap();
+        }
+        set_backup_pinned("Survival", names[0], true).unwrap();
+        assert!(get_backup_info("Survival", names[0]).unwrap().pinned);
+        assert!(!get_backup_info("Survival", names[1]).unwrap().pinned);
+        assert!(matches!(
+            set_backup_pinned("Survival", "missing.tar.gz", true),
+            Err(BackupError::BackupNotFound(_))
+        ));
+
+        // The pinned backup doesn't count against the limit of 1
+        let (retained, deleted, _) =
+            garbage_collection(&save_backup_dir, RetentionMode::Limited(1), None, false).unwrap();
+        assert_eq!((retained, deleted), (2, 1));
+        assert!(save_backup_dir.join(names[0]).exists());
+        assert!(save_backup_dir.join(names[2]).exists());
+
+        // Nor is it deleted by age
+        assert_eq!(prune_backups_older_than("Survival", 1).unwrap(), 1);
+        assert!(save_backup_dir.join(names[0]).exists());
+
+        // Unpinning removes the pin file once nothing is pinned
+        set_backup_pinned("Survival", names[0], false).unwrap();
+        assert!(!save_backup_dir.join(PINNED_FILE_NAME).exists());
+        assert_eq!(prune_backups_older_than("Survival", 1).unwrap(), 1);
+    }
+
     #[test]
     fn test_garbage_collection_with_retention_limit() {
         let temp_dir = TempDir::new().unwrap();
 
-        // Create 5 backup tar.gz files
-        for i in 0..5 {
-            let backup_path = temp_dir
-                .path()
-                .join(format!("Survival_2024-12-28_{:02}-00-00.tar.gz", i));
-            File::create(&backup_path)
-                .unwrap()
-                .write_all(b"data")
-                .unwrap();
+        // Create 5 backup tar.gz files
+        for i in 0..5 {
+            let backup_path = temp_dir
+                .path()
+                .join(format!("Survival_2024-12-28_{:02}-00-00.tar.gz", i));
+            File::create(&backup_path)
+                .unwrap()
+                .write_all(b"data")
+                .unwrap();
+        }
+
+        // Set retention to 3
+        let (retained, deleted, _) =
+            garbage_collection(temp_dir.path(), RetentionMode::Limited(3), None, false).unwrap();
+
+        assert_eq!(retained, 3);
+        assert_eq!(deleted, 2);
+
+        // Verify only 3 backups remain
+        let remaining = list_backup_files(temp_dir.path(), false).unwrap();
+        assert_eq!(remaining.len(), 3);
+    }
+
+    #[test]
+    #[serial]
+    fn test_backup_order_uses_name_timestamps() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        setup_test_config(save_base.path(), backup_base.path());
+        let save_backup_dir = backup_base.path().join("Survival");
+        fs::create_dir_all(&save_backup_dir).unwrap();
+
+        // Modification times run opposite to the names, as after copying backups around
+        let names = [
+            "2024-12-28_10-00-00.tar.gz",
+            "2024-12-28_11-00-00.tar.gz",
+            "2024-12-28_12-00-00.tar.gz",
+        ];
+        let now = SystemTime::now();
+        for (i, name) in names.iter().enumerate() {
+            let file = File::create(save_backup_dir.join(name)).unwrap();
+            file.set_modified(now - std::time::Duration::from_secs(3600 * i as u64)).unwrap();
+        }
+
+        let listed: Vec<String> =
+            list_backups("Survival").unwrap().into_iter().map(|b| b.name).collect();
+        assert_eq!(listed, vec![names[2], names[1], names[0]]);
+
+        garbage_collection(&save_backup_dir, RetentionMode::Limited(1), None, false).unwrap();
+        let remaining = list_backup_files(&save_backup_dir, false).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].name, names[2]);
+    }
+
+    #[test]
+    fn test_is_backup_archive() {
+        assert!(is_backup_archive("2024-12-28_14-30-45.tar.gz"));
+        assert!(is_backup_archive("2024-12-28_14-30-45.zip"));
+        assert!(!is_backup_archive("2024-12-28_14-30-45.tar.gz.sha256"));
+        assert!(!is_backup_archive("2024-12-28_14-30-45.tar.gz.json"));
+        assert!(!is_backup_archive("2024-12-28_14-30-45.tar.gz.sig"));
+        assert!(!is_backup_archive("2024-12-28_14-30-45.tar.gz.tmp"));
+        assert!(!is_backup_archive("2024-12-28_14-30-45.gz"));
+        assert!(!is_backup_archive("notes.txt"));
+    }
+
+    #[test]
+    fn test_garbage_collection_ignores_sidecar_files() {
+        let temp_dir = TempDir::new().unwrap();
+
+        for i in 0..3 {
+            let name = format!("Survival_2024-12-28_{:02}-00-00.tar.gz", i);
+            fs::write(temp_dir.path().join(&name), b"data").unwrap();
+            for extension in BACKUP_SIDECAR_EXTENSIONS {
+                fs::write(temp_dir.path().join(format!("{}.{}", name, extension)), b"x").unwrap();
+            }
+        }
+
+        let files = list_backup_files(temp_dir.path(), false).unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|file| is_backup_archive(&file.name)));
+
+        // Sidecars neither count towards retention nor survive their archive
+        let (retained, deleted, _) =
+            garbage_collection(temp_dir.path(), RetentionMode::Limited(2), None, false).unwrap();
+        assert_eq!((retained, deleted), (2, 1));
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 2 * 4);
+    }
+
+    #[test]
+    fn test_garbage_collection_no_deletion_needed() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // Create 2 backup tar.gz files
+        for i in 0..2 {
+            let backup_path = temp_dir
+                .path()
+                .join(format!("Survival_2024-12-28_{:02}-00-00.tar.gz", i));
+            File::create(&backup_path)
+                .unwrap()
+                .write_all(b"data")
+                .unwrap();
+        }
+
+        // Set retention to 5 (more than existing)
+        let (retained, deleted, _) =
+            garbage_collection(temp_dir.path(), RetentionMode::Limited(5), None, false).unwrap();
+
+        assert_eq!(retained, 2);
+        assert_eq!(deleted, 0);
+
+        // Verify all backups remain
+        let remaining = list_backup_files(temp_dir.path(), false).unwrap();
+        assert_eq!(remaining.len(), 2);
+    }
+
+    #[test]
+    #[serial]
+    fn test_unlimited_retention_keeps_all_backups() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let config = Config {
+            retention_count: RetentionMode::Unlimited,
+            ..Config::with_paths(
+                save_base.path().to_str().unwrap().to_string(),
+                backup_base.path().to_str().unwrap().to_string(),
+            )
+        };
+        config_module::save_config(&config).unwrap();
+
+        // Seed 19 older backups so the 20th real backup exceeds the default limit
+        let save_backup_dir = backup_base.path().join("Survival");
+        fs::create_dir_all(&save_backup_dir).unwrap();
+        for i in 0..19 {
+            let name = format!("2024-12-28_10-00-{:02}.tar.gz", i);
+            File::create(save_backup_dir.join(name)).unwrap().write_all(b"data").unwrap();
+        }
+
+        let result = create_backup("Survival").unwrap();
+        assert_eq!(result.retained_count, 20);
+        assert_eq!(result.deleted_count, 0);
+        assert_eq!(count_backups("Survival").unwrap(), 20);
+    }
+
+    /// Helper to list the file paths inside a backup archive
+    fn archive_entries(backup_path: &Path) -> Vec<String> {
+        let file = File::open(backup_path).unwrap();
+        let mut archive = tar::Archive::new(flate2::read::MultiGzDecoder::new(file));
+        archive
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_uses_config_excludes() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let config = Config {
+            exclude_patterns: vec!["*.dat".to_string()],
+            ..Config::with_paths(
+                save_base.path().to_str().unwrap().to_string(),
+                backup_base.path().to_str().unwrap().to_string(),
+            )
+        };
+        config_module::save_config(&config).unwrap();
+
+        let result = create_backup("Survival").unwrap();
+        let entries = archive_entries(Path::new(&result.backup_path));
+        assert!(entries.iter().any(|e| e.ends_with("save.bin")));
+        assert!(!entries.iter().any(|e| e.ends_with(".dat")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_with_options_overrides_config() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let config = Config {
+            exclude_patterns: vec!["*.dat".to_string()],
+            ..Config::with_paths(
+                save_base.path().to_str().unwrap().to_string(),
+                backup_base.path().to_str().unwrap().to_string(),
+            )
+        };
+        config_module::save_config(&config).unwrap();
+
+        // Explicit options replace the configured exclusions
+        let options = BackupOptions {
+            exclude_patterns: vec!["save.bin".to_string()],
+            compression_level: 0,
+            verify_after_create: true,
+            ..BackupOptions::from_config(&config)
+        };
+        let result = create_backup_with_options("Survival", &options).unwrap();
+
+        let entries = archive_entries(Path::new(&result.backup_path));
+        assert!(!entries.iter().any(|e| e.ends_with("save.bin")));
+        assert!(entries.iter().any(|e| e.ends_with("pchunk_0_0.dat")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_records_last_backup_time() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+        assert_eq!(config_module::get_last_backup_time("Survival").unwrap(), None);
+
+        create_backup("Survival").unwrap();
+        let first = config_module::get_last_backup_time("Survival").unwrap().unwrap();
+        let config = config_module::load_config().unwrap();
+        assert_eq!(config.last_backup_at.as_ref(), Some(&first));
+
+        // Backup names have second precision
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        create_backup("Survival").unwrap();
+        let second = config_module::get_last_backup_time("Survival").unwrap().unwrap();
+        assert!(second > first);
+        assert_eq!(config_module::load_config().unwrap().last_backup_at, Some(second));
+    }
+
+    #[test]
+    #[serial]
+    fn test_list_backups_reports_archive_format() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let tar_gz = create_backup("Survival").unwrap();
+        let options = BackupOptions {
+            compression: ArchiveFormat::Zip,
+            ..BackupOptions::default()
+        };
+        let zip = create_backup_with_options("Survival", &options).unwrap();
+
+        let backups = list_backups("Survival").unwrap();
+        let format_of = |name: &str| {
+            backups.iter().find(|b| b.name == name).unwrap().archive_format
+        };
+        assert_eq!(backups.len(), 2);
+        assert_eq!(format_of(&tar_gz.backup_name), ArchiveFormat::TarGz);
+        assert_eq!(format_of(&zip.backup_name), ArchiveFormat::Zip);
+        assert_eq!(
+            get_backup_info("Survival", &zip.backup_name).unwrap().archive_format,
+            ArchiveFormat::Zip
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_uses_configured_archive_format() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+        config_module::update_archive_format(ArchiveFormat::Zip).unwrap();
+
+        let result = create_backup("Survival").unwrap();
+        assert!(result.backup_name.ends_with(".zip"));
+        let extracted = TempDir::new().unwrap();
+        let restored = extracted.path().join("Survival");
+        extract_backup(Path::new(&result.backup_path), &restored).unwrap();
+        assert_eq!(fs::read(restored.join("save.bin")).unwrap().len(), 10);
+    }
+
+    #[test]
+    #[serial]
+    fn test_rename_save_backups() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Sandbox/Survival"));
+        create_test_save(&save_base.path().join("Sandbox/Builder"));
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Sandbox/Survival").unwrap().backup_name;
+        create_backup("Sandbox/Builder").unwrap();
+        let undo_dir = get_undo_snapshot_dir(backup_base.path(), "Sandbox/Survival");
+        fs::create_dir_all(&undo_dir).unwrap();
+        fs::write(undo_dir.join("undo_snapshot.tar.gz"), b"undo").unwrap();
+
+        rename_save_backups("Sandbox/Survival", "Apocalypse/Survival").unwrap();
+        let backups = list_backups("Apocalypse/Survival").unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].name, backup_name);
+        assert!(list_backups("Sandbox/Survival").unwrap().is_empty());
+        let new_undo_dir = get_undo_snapshot_dir(backup_base.path(), "Apocalypse/Survival");
+        assert!(new_undo_dir.join("undo_snapshot.tar.gz").exists());
+        assert!(!undo_dir.exists());
+
+        // The destination must not have backups yet
+        let result = rename_save_backups("Sandbox/Builder", "Apocalypse/Survival");
+        assert!(matches!(result, Err(BackupError::FileOp(FileOpsError::DestinationExists(_)))));
+        assert!(matches!(
+            rename_save_backups("Sandbox/Missing", "Sandbox/Other"),
+            Err(BackupError::BackupNotFound(_))
+        ));
+
+        // Renaming a game mode moves the saves inside it, undo snapshots included
+        rename_save_backups("Apocalypse", "Endgame").unwrap();
+        assert_eq!(list_backups("Endgame/Survival").unwrap().len(), 1);
+        assert!(get_undo_snapshot_dir(backup_base.path(), "Endgame/Survival").is_dir());
+        assert!(!backup_base.path().join("Apocalypse").exists());
+        assert!(!get_nested_undo_snapshot_root(backup_base.path(), "Apocalypse").exists());
+        // The emptied game mode directory under `_undo` is removed too
+        assert!(!get_nested_undo_snapshot_root(backup_base.path(), "Sandbox").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_name_time_zone() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        // UTC is the default, as before local time was configurable
+        let before = Utc::now().naive_utc() - chrono::TimeDelta::seconds(1);
+        let result = create_backup("Survival").unwrap();
+        let after = Utc::now().naive_utc();
+        let timestamp = parse_backup_timestamp(&result.backup_name).unwrap();
+        assert!(before <= timestamp && timestamp <= after);
+
+        // The name timestamp is read back as UTC
+        let info = get_backup_info("Survival", &result.backup_name).unwrap();
+        let created = DateTime::parse_from_rfc3339(&info.created_at).unwrap();
+        assert_eq!(created.naive_utc(), timestamp);
+
+        config_module::update_use_local_time(true).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        let before = Local::now().naive_local() - chrono::TimeDelta::seconds(1);
+        let result = create_backup("Survival").unwrap();
+        let after = Local::now().naive_local();
+        let timestamp = parse_backup_timestamp(&result.backup_name).unwrap();
+        assert!(before <= timestamp && timestamp <= after);
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_with_label() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let options = BackupOptions {
+            label: Some("Before horde night".to_string()),
+            ..Default::default()
+        };
+        let result = create_backup_with_options("Survival", &options).unwrap();
+
+        let backups = list_backups("Survival").unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].label.as_deref(), Some("Before horde night"));
+        let info = get_backup_info("Survival", &result.backup_name).unwrap();
+        assert_eq!(info.label.as_deref(), Some("Before horde night"));
+
+        // Deleting the backup also removes its manifest
+        let backup_path = PathBuf::from(&result.backup_path);
+        delete_backup("Survival", &result.backup_name).unwrap();
+        assert!(!backup_manifest_path(&backup_path).exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_delete_backup_removes_sidecars() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let result = create_backup("Survival").unwrap();
+        let backup_path = PathBuf::from(&result.backup_path);
+        assert!(backup_manifest_path(&backup_path).exists());
+        fs::write(format!("{}.sha256", result.backup_path), "checksum").unwrap();
+        fs::write(format!("{}.sig", result.backup_path), "signature").unwrap();
+
+        delete_backup("Survival", &result.backup_name).unwrap();
+
+        let remaining: Vec<_> = fs::read_dir(backup_path.parent().unwrap())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert!(remaining.is_empty(), "Leftover files: {:?}", remaining);
+    }
+
+    #[test]
+    fn test_delete_backup_with_sidecars_counts_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let backup_path = temp_dir.path().join("Survival_2024-12-28_14-30-45.tar.gz");
+        fs::write(&backup_path, "archive").unwrap();
+        fs::write(backup_manifest_path(&backup_path), "{}").unwrap();
+
+        assert_eq!(delete_backup_with_sidecars(&backup_path).unwrap(), 2);
+        assert!(!backup_manifest_path(&backup_path).exists());
+        assert!(delete_backup_with_sidecars(&backup_path).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_records_file_inventory() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let result = create_backup("Survival").unwrap();
+        let manifest = get_backup_manifest("Survival", &result.backup_name).unwrap();
+
+        let mut inventory = manifest.file_inventory.unwrap();
+        inventory.sort_by(|a, b| a.path.cmp(&b.path));
+        let files: Vec<(&str, u64)> = inventory
+            .iter()
+            .map(|entry| (entry.path.as_str(), entry.size_bytes))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                ("map/pchunk_0_0.dat", 1024),
+                ("map/pchunk_0_1.dat", 8),
+                ("save.bin", 10),
+            ]
+        );
+        assert!(inventory.iter().all(|entry| entry.modified_at.is_some()));
+        assert!(!manifest.file_count_truncated);
+        assert_eq!(
+            manifest.sha256.unwrap(),
+            calculate_checksum_sha256(&PathBuf::from(&result.backup_path)).unwrap()
+        );
+        assert_eq!(
+            manifest.archive_meta,
+            Some(ArchiveMeta { entry_count: 3, total_uncompressed_bytes: 1024 + 8 + 10 })
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_reports_backup_dir() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let result = create_backup("Survival").unwrap();
+        let json = serde_json::to_string(&result).unwrap();
+        let parsed: BackupResult = serde_json::from_str(&json).unwrap();
+
+        let backup_path = PathBuf::from(&parsed.backup_path);
+        assert!(backup_path.is_file());
+        assert_eq!(PathBuf::from(&parsed.backup_dir), backup_base.path().join("Survival"));
+        assert_eq!(backup_path.parent().unwrap(), Path::new(&parsed.backup_dir));
+        assert_eq!(backup_path.file_name().unwrap().to_str(), Some(parsed.backup_name.as_str()));
+    }
+
+    #[test]
+    #[serial]
+    fn test_create_backup_without_awaiting_gc() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let config = Config {
+            retention_count: RetentionMode::Limited(2),
+            ..Config::with_paths(
+                save_base.path().to_str().unwrap().to_string(),
+                backup_base.path().to_str().unwrap().to_string(),
+            )
+        };
+        config_module::save_config(&config).unwrap();
+
+        let save_backup_dir = backup_base.path().join("Survival");
+        fs::create_dir_all(&save_backup_dir).unwrap();
+        for i in 0..3 {
+            let name = format!("2024-12-28_10-00-{:02}.tar.gz", i);
+            File::create(save_backup_dir.join(name)).unwrap().write_all(b"data").unwrap();
+        }
+
+        let semaphores = GlobalBackupSemaphores::new();
+        let options = BackupOptions {
+            await_gc: false,
+            permit: Some(Arc::new(semaphores.try_acquire("Survival").unwrap())),
+            ..BackupOptions::from_config(&config)
+        };
+        let result = create_backup_with_options("Survival", &options).unwrap();
+        drop(options);
+
+        // GC has not been awaited, so nothing is reported as deleted yet
+        assert_eq!(result.retained_count, 4);
+        assert_eq!(result.deleted_count, 0);
+
+        // The background GC holds the save's permit until it has trimmed down to the
+        // retention limit
+        let mut permit = None;
+        for _ in 0..50 {
+            permit = semaphores.try_acquire("Survival").ok();
+            if permit.is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        assert!(permit.is_some());
+        assert_eq!(list_backup_files(&save_backup_dir, false).unwrap().len(), 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn test_create_backup_fails_on_unreadable_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        let locked = save_dir.join("locked.bin");
+        fs::write(&locked, b"held by the game").unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+
+        // Privileged users can still open the file, so nothing fails
+        if File::open(&locked).is_ok() {
+            return;
         }
 
-        // Set retention to 3
-        let (retained, deleted) = garbage_collection(temp_dir.path(), 3).unwrap();
-
-        assert_eq!(retained, 3);
-        assert_eq!(deleted, 2);
+        setup_test_config(save_base.path(), backup_base.path());
 
-        // Verify only 3 backups remain
-        let remaining = list_backup_files(temp_dir.path()).unwrap();
-        assert_eq!(remaining.len(), 3);
+        // A permission problem is not a file held by the game, so it isn't skipped
+        let result = create_backup("Survival");
+        assert!(matches!(
+            result,
+            Err(BackupError::FileOp(FileOpsError::PermissionDenied(path))) if path == locked
+        ));
+        assert!(list_backups("Survival").unwrap().is_empty());
     }
 
     #[test]
-    fn test_garbage_collection_no_deletion_needed() {
-        let temp_dir = TempDir::new().unwrap();
+    fn test_backup_options_from_config() {
+        let config = Config {
+            compression_level: 9,
+            exclude_patterns: vec!["*.log".to_string()],
+            verify_after_create: true,
+            ..Default::default()
+        };
 
-        // Create 2 backup tar.gz files
-        for i in 0..2 {
-            let backup_path = temp_dir
-                .path()
-                .join(format!("Survival_2024-12-28_{:02}-00-00.tar.gz", i));
-            File::create(&backup_path)
-                .unwrap()
-                .write_all(b"data")
-                .unwrap();
-        }
+        let options = BackupOptions::from_config(&config);
+        assert_eq!(options.compression, ArchiveFormat::TarGz);
+        assert_eq!(options.compression_level, 9);
+        assert_eq!(options.exclude_patterns, vec!["*.log"]);
+        assert!(options.verify_after_create);
+        assert!(options.await_gc);
+        assert!(!options.emit_progress);
+        assert!(options.label.is_none());
+    }
 
-        // Set retention to 5 (more than existing)
-        let (retained, deleted) = garbage_collection(temp_dir.path(), 5).unwrap();
+    #[test]
+    fn test_backup_options_patch_keeps_configured_values() {
+        let config = Config {
+            compression_level: 9,
+            exclude_patterns: vec!["*.log".to_string()],
+            verify_after_create: true,
+            ..Default::default()
+        };
 
-        assert_eq!(retained, 2);
-        assert_eq!(deleted, 0);
+        // The frontend only sends a label
+        let patch: BackupOptionsPatch =
+            serde_json::from_str(r#"{ "label": "Before horde night" }"#).unwrap();
+        let options = patch.apply(BackupOptions::from_config(&config));
 
-        // Verify all backups remain
-        let remaining = list_backup_files(temp_dir.path()).unwrap();
-        assert_eq!(remaining.len(), 2);
+        assert_eq!(options.label.as_deref(), Some("Before horde night"));
+        assert_eq!(options.compression_level, 9);
+        assert_eq!(options.exclude_patterns, vec!["*.log"]);
+        assert!(options.verify_after_create);
+
+        let patch: BackupOptionsPatch =
+            serde_json::from_str(r#"{ "compression_level": 1 }"#).unwrap();
+        assert_eq!(patch.apply(BackupOptions::from_config(&config)).compression_level, 1);
     }
 
     #[test]
@@ -771,8 +3908,14 @@ mod tests {
 
         setup_test_config(save_base.path(), backup_base.path());
 
-        // Create a backup
-        create_backup("Survival").unwrap();
+        // Create a backup; its manifest and any other sidecars are not listed
+        let result = create_backup("Survival").unwrap();
+        let backup_path = PathBuf::from(&result.backup_path);
+        for extension in ["sha256", "sig"] {
+            let mut sidecar = backup_path.as_os_str().to_os_string();
+            sidecar.push(format!(".{}", extension));
+            fs::write(sidecar, b"x").unwrap();
+        }
 
         let backups = list_backups("Survival").unwrap();
         assert_eq!(backups.len(), 1);
@@ -808,6 +3951,141 @@ mod tests {
         assert!(info.size_bytes > 0);
     }
 
+    #[test]
+    #[serial]
+    fn test_relative_paths_survive_backup_dir_move() {
+        let save_base = TempDir::new().unwrap();
+        let old_drive = TempDir::new().unwrap();
+        let new_drive = TempDir::new().unwrap();
+        let old_backup_base = old_drive.path().join("ZomboidBackups");
+        let new_backup_base = new_drive.path().join("ZomboidBackups");
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let mut config = Config::with_paths(
+            save_base.path().to_str().unwrap().to_string(),
+            old_backup_base.to_str().unwrap().to_string(),
+        );
+        config.use_relative_paths = true;
+        config_module::save_config(&config).unwrap();
+
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+
+        // Simulate the drive being mounted somewhere else
+        crate::file_ops::copy_dir_recursive(&old_backup_base, &new_backup_base).unwrap();
+        config.backup_path = Some(new_backup_base.to_str().unwrap().to_string());
+        config_module::save_config(&config).unwrap();
+
+        let info = get_backup_info("Survival", &backup_name).unwrap();
+        assert!(Path::new(&info.path).is_relative());
+
+        let resolved = info.resolve_path(&new_backup_base);
+        assert_eq!(resolved, new_backup_base.join("Survival").join(&backup_name));
+        assert!(resolved.exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_verify_backup_checksum_sidecar() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+        let backup_path = backup_base.path().join("Survival").join(&backup_name);
+
+        let sidecar = fs::read_to_string(backup_checksum_path(&backup_path)).unwrap();
+        assert_eq!(sidecar.split_whitespace().nth(1), Some(backup_name.as_str()));
+        assert_eq!(verify_backup("Survival", &backup_name).unwrap(), Some(true));
+        assert_eq!(list_backups("Survival").unwrap()[0].checksum_verified, Some(true));
+
+        // Corrupt the archive
+        let mut data = fs::read(&backup_path).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0xff;
+        fs::write(&backup_path, data).unwrap();
+        assert_eq!(verify_backup("Survival", &backup_name).unwrap(), Some(false));
+
+        // Backups without a sidecar are not verified, rather than failing
+        fs::remove_file(backup_checksum_path(&backup_path)).unwrap();
+        assert_eq!(verify_backup("Survival", &backup_name).unwrap(), None);
+        assert_eq!(list_backups("Survival").unwrap()[0].checksum_verified, None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_list_backups_unreadable_checksum_sidecar() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+        let backup_path = backup_base.path().join("Survival").join(&backup_name);
+
+        // A sidecar that can't be read leaves the backup unverified in listings
+        fs::remove_file(backup_checksum_path(&backup_path)).unwrap();
+        fs::create_dir(backup_checksum_path(&backup_path)).unwrap();
+
+        let backups = list_backups("Survival").unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].checksum_verified, None);
+        let info = get_backup_info("Survival", &backup_name).unwrap();
+        assert_eq!(info.checksum_verified, None);
+        assert!(verify_backup("Survival", &backup_name).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_checksum_cache_forgets_deleted_backups_and_is_bounded() {
+        let temp_dir = TempDir::new().unwrap();
+        let cached = |path: &Path| {
+            CHECKSUM_CACHE.lock().unwrap_or_else(|e| e.into_inner()).contains_key(path)
+        };
+
+        let backup_path = temp_dir.path().join("2024-12-28_10-00-00.tar.gz");
+        fs::write(&backup_path, "archive").unwrap();
+        write_backup_checksum(&backup_path, "abc").unwrap();
+        assert_eq!(cached_backup_checksum(&backup_path).as_deref(), Some("abc"));
+        assert_eq!(delete_backup_with_sidecars(&backup_path).unwrap(), 2);
+        assert!(!cached(&backup_path));
+
+        for i in 0..CHECKSUM_CACHE_MAX_ENTRIES + 10 {
+            let path = temp_dir.path().join(format!("{}.tar.gz", i));
+            fs::write(&path, "archive").unwrap();
+            cache_backup_checksum(&path, "abc");
+        }
+        let len = CHECKSUM_CACHE.lock().unwrap_or_else(|e| e.into_inner()).len();
+        assert!(len <= CHECKSUM_CACHE_MAX_ENTRIES);
+        assert!(cached(&temp_dir.path().join(format!("{}.tar.gz", CHECKSUM_CACHE_MAX_ENTRIES))));
+    }
+
+    #[test]
+    fn test_resolve_path_keeps_absolute_paths() {
+        let info = BackupInfo {
+            name: "2024-12-28_10-00-00.tar.gz".to_string(),
+            path: "/backups/Survival/2024-12-28_10-00-00.tar.gz".to_string(),
+            size_bytes: 0,
+            size_formatted: "0 B".to_string(),
+            created_at: "2024-12-28T10:00:00Z".to_string(),
+            save_name: "Survival".to_string(),
+            tags: Vec::new(),
+            thumb_data: None,
+            label: None,
+            archive_format: ArchiveFormat::TarGz,
+            checksum_verified: None,
+            kind: BackupKind::Full,
+            pinned: false,
+        };
+
+        assert_eq!(
+            info.resolve_path(Path::new("/elsewhere")),
+            Path::new("/backups/Survival/2024-12-28_10-00-00.tar.gz")
+        );
+    }
+
     #[test]
     #[serial]
     fn test_get_backup_info_not_found() {
@@ -842,6 +4120,164 @@ mod tests {
         assert_eq!(count_backups("Survival").unwrap(), 2);
     }
 
+    #[test]
+    #[serial]
+    fn test_get_backup_summary() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let empty = get_backup_summary().unwrap();
+        assert_eq!(empty.total_backups, 0);
+        assert!(empty.newest_backup.is_none());
+        assert_eq!(empty.average_backup_size_bytes, 0);
+
+        // Seed backup files with known sizes, oldest first
+        let seed = |save: &str, name: &str, size: usize| {
+            let dir = backup_base.path().join(save);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(name), vec![0u8; size]).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        };
+        seed("Survival", "2024-12-28_10-00-00.tar.gz", 100);
+        seed("Builder", "2024-12-28_11-00-00.tar.gz", 300);
+        seed("Survival", "2024-12-28_12-00-00.tar.gz", 200);
+
+        let summary = get_backup_summary().unwrap();
+        assert_eq!(summary.total_backups, 3);
+        assert_eq!(summary.total_saves_with_backups, 2);
+        assert_eq!(summary.total_size_bytes, 600);
+        assert_eq!(summary.average_backup_size_bytes, 200);
+
+        let oldest = summary.oldest_backup.unwrap();
+        assert_eq!((oldest.save_name.as_str(), oldest.size_bytes), ("Survival", 100));
+        let newest = summary.newest_backup.unwrap();
+        assert_eq!(newest.name, "2024-12-28_12-00-00.tar.gz");
+        let largest = summary.largest_backup.unwrap();
+        assert_eq!((largest.save_name.as_str(), largest.size_bytes), ("Builder", 300));
+        assert_eq!(summary.last_backup_at, Some(newest.created_at));
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_backup_summary_nested_saves() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        create_test_save(&save_base.path().join("Survival/MySave"));
+        create_test_save(&save_base.path().join("Survival/Other"));
+        let first = create_backup("Survival/MySave").unwrap();
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        create_backup("Survival/Other").unwrap();
+
+        let summary = get_backup_summary().unwrap();
+        assert_eq!(summary.total_backups, 2);
+        assert_eq!(summary.total_saves_with_backups, 2);
+        assert!(summary.total_size_bytes > 0);
+        // The game mode directory holding both saves isn't counted as other files
+        assert_eq!(summary.total_other_bytes, 0);
+        let oldest = summary.oldest_backup.unwrap();
+        assert_eq!(oldest.save_name, "Survival/MySave");
+        assert_eq!(oldest.name, first.backup_name);
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_backup_storage_stats() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        // Backup directory not created yet
+        let backup_path = backup_base.path().join("backups");
+        setup_test_config(save_base.path(), &backup_path);
+        assert_eq!(get_backup_storage_stats().unwrap(), BackupStorageStats::default());
+
+        let seed = |dir: &str, name: &str, size: usize| {
+            let dir = backup_path.join(dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(name), vec![0u8; size]).unwrap();
+        };
+        seed("Survival", "2024-12-28_10-00-00.tar.gz", 100);
+        seed("Survival", "2024-12-28_12-00-00.tar.gz", 200);
+        seed("Survival", "2024-12-28_12-00-00.tar.gz.sha256", 64);
+        seed("Builder", "2024-12-28_11-00-00.zip", 300);
+        seed("_undo/Survival_undo", "undo_2024-12-28_13-00-00.tar.gz", 50);
+        seed("Sandbox/MySave", "2024-12-28_14-00-00.tar.gz", 400);
+        seed("Sandbox/MySave_undo", "undo_2024-12-28_15-00-00.tar.gz", 25);
+        seed("_undo/Sandbox/Other_undo", "undo_2024-12-28_16-00-00.tar.gz", 10);
+
+        let stats = get_backup_storage_stats().unwrap();
+        assert_eq!(stats.total_bytes, 1000);
+        assert_eq!(stats.total_archives, 4);
+        assert_eq!(stats.per_save_bytes.len(), 3);
+        assert_eq!(stats.per_save_bytes["Survival"], 300);
+        assert_eq!(stats.per_save_bytes["Builder"], 300);
+        assert_eq!(stats.per_save_bytes["Sandbox/MySave"], 400);
+        assert_eq!(stats.undo_snapshot_bytes, 85);
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_backup_space() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let space = check_backup_space("Survival").unwrap();
+        assert_eq!(space.save_size_bytes, 10 + 1024 + 8);
+        assert_eq!(space.needed_bytes, (10 + 1024 + 8) * 110 / 100);
+        assert!(space.sufficient);
+        assert!(matches!(
+            check_backup_space("Missing"),
+            Err(BackupError::SaveNotFound(_))
+        ));
+
+        let err = BackupError::InsufficientSpace { needed: 2048, available: 1024 };
+        assert_eq!(
+            err.to_string(),
+            "Not enough space on the backup drive: 2.00 KB needed, 1.00 KB available"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_backup_dir_stats() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        assert_eq!(get_backup_dir_stats("Survival").unwrap(), BackupDirStats::default());
+
+        let result = create_backup("Survival").unwrap();
+        let backup_path = PathBuf::from(&result.backup_path);
+        let save_backup_dir = backup_path.parent().unwrap();
+        fs::write(save_backup_dir.join("notes.txt"), b"horde night").unwrap();
+        // Another save's backups below this one are not other files
+        fs::create_dir_all(save_backup_dir.join("Nested")).unwrap();
+        fs::write(save_backup_dir.join("Nested/2024-12-28_10-00-00.tar.gz"), [0u8; 100]).unwrap();
+
+        let stats = get_backup_dir_stats("Survival").unwrap();
+        assert_eq!(stats.archive_bytes, fs::metadata(&backup_path).unwrap().len());
+        assert_eq!(
+            stats.sidecar_bytes,
+            fs::metadata(backup_manifest_path(&backup_path)).unwrap().len()
+                + fs::metadata(backup_checksum_path(&backup_path)).unwrap().len()
+        );
+        assert_eq!(stats.other_bytes, 11);
+        assert!(stats.archive_bytes > 0 && stats.sidecar_bytes > 0);
+
+        // The summary counts the nested archive once, as its own save
+        let summary = get_backup_summary().unwrap();
+        assert_eq!(summary.total_size_bytes, stats.archive_bytes + 100);
+        assert_eq!(summary.total_sidecar_bytes, stats.sidecar_bytes);
+        assert_eq!(summary.total_other_bytes, 11);
+    }
+
     #[test]
     #[serial]
     fn test_list_saves_with_backups() {
@@ -865,21 +4301,31 @@ mod tests {
         assert_eq!(saves.len(), 2);
         assert!(saves.contains(&"Builder".to_string()));
         assert!(saves.contains(&"Survival".to_string()));
+
+        // Two-level saves are listed by their full name, and a game mode directory
+        // without archives of its own is not a save
+        create_test_save(&save_base.path().join("Sandbox/MySave"));
+        create_backup("Sandbox/MySave").unwrap();
+        let saves = list_saves_with_backups().unwrap();
+        assert_eq!(saves, vec!["Builder", "Sandbox/MySave", "Survival"]);
     }
 
     #[test]
     fn test_backup_result_serialization() {
         let result = BackupResult {
             backup_path: "/backups/Survival_2024-12-28_10-00-00".to_string(),
+            backup_dir: "/backups".to_string(),
             backup_name: "Survival_2024-12-28_10-00-00".to_string(),
             retained_count: 5,
             deleted_count: 2,
+            warning: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
         let parsed: BackupResult = serde_json::from_str(&json).unwrap();
 
         assert_eq!(parsed.backup_path, result.backup_path);
+        assert_eq!(parsed.backup_dir, result.backup_dir);
         assert_eq!(parsed.backup_name, result.backup_name);
         assert_eq!(parsed.retained_count, 5);
         assert_eq!(parsed.deleted_count, 2);
@@ -896,6 +4342,11 @@ mod tests {
             save_name: "Survival".to_string(),
             tags: Vec::new(),
             thumb_data: None,
+            label: None,
+            archive_format: ArchiveFormat::TarGz,
+            checksum_verified: None,
+            kind: BackupKind::Full,
+            pinned: false,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -915,6 +4366,17 @@ mod tests {
         assert_eq!(err2.to_string(), "Invalid backup name format: bad_name");
     }
 
+    #[test]
+    fn test_backup_error_from_io_error() {
+        let err = BackupError::from(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"));
+        match err {
+            BackupError::FileOp(FileOpsError::Io(io_err)) => {
+                assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("unexpected variant: {:?}", other),
+        }
+    }
+
     #[test]
     #[serial]
     fn test_multiple_backups_with_gc() {
@@ -930,7 +4392,7 @@ mod tests {
             backup_base.path().to_str().unwrap().to_string(),
         );
         let config_with_retention = Config {
-            retention_count: 3,
+            retention_count: RetentionMode::Limited(3),
             ..config
         };
         config_module::save_config(&config_with_retention).unwrap();
@@ -1021,4 +4483,249 @@ mod tests {
         assert!(backups.iter().any(|b| b.name == backup3.backup_name));
         assert!(!backups.iter().any(|b| b.name == backup2.backup_name));
     }
+
+    /// Changes the save so an incremental backup has something to store: one file
+    /// modified, one added and one deleted.
+    fn modify_test_save(save_dir: &Path) {
+        fs::write(save_dir.join("save.bin"), b"game state after day 2").unwrap();
+        fs::write(save_dir.join("map/pchunk_1_0.dat"), b"new chunk").unwrap();
+        fs::remove_file(save_dir.join("map/pchunk_0_1.dat")).unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn test_incremental_backup_stores_changed_files_and_restores() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let full = create_backup("Survival").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        modify_test_save(&save_dir);
+        let incremental = create_incremental_backup("Survival").unwrap();
+
+        let backups = list_backups("Survival").unwrap();
+        assert_eq!(backups[0].name, incremental.backup_name);
+        assert_eq!(backups[0].kind, BackupKind::Incremental);
+        assert_eq!(backups[1].kind, BackupKind::Full);
+
+        let incremental_path = PathBuf::from(&incremental.backup_path);
+        let manifest = read_backup_manifest(&incremental_path).unwrap();
+        assert_eq!(manifest.parent.as_deref(), Some(full.backup_name.as_str()));
+        let mut archived: Vec<String> = list_tar_gz_files(&incremental_path)
+            .unwrap()
+            .into_iter()
+            .map(|file| file.path)
+            .collect();
+        archived.sort();
+        let expected = [Path::new("map/pchunk_1_0.dat"), Path::new("save.bin")];
+        assert_eq!(archived, expected.map(normalize_path_for_display));
+
+        let restored = save_base.path().join("restored");
+        extract_backup(&incremental_path, &restored).unwrap();
+        assert_eq!(
+            fs::read(restored.join("save.bin")).unwrap(),
+            b"game state after day 2"
+        );
+        assert_eq!(fs::read(restored.join("map/pchunk_0_0.dat")).unwrap(), [b'm'; 1024]);
+        assert!(restored.join("map/pchunk_1_0.dat").exists());
+        assert!(!restored.join("map/pchunk_0_1.dat").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_incremental_backup_without_full_backup_is_full() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        create_incremental_backup("Survival").unwrap();
+
+        let backups = list_backups("Survival").unwrap();
+        assert_eq!(backups[0].kind, BackupKind::Full);
+    }
+
+    #[test]
+    #[serial]
+    fn test_deleting_parent_promotes_incremental_backup() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let full = create_backup("Survival").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        modify_test_save(&save_dir);
+        let incremental = create_incremental_backup("Survival").unwrap();
+        let incremental_path = PathBuf::from(&incremental.backup_path);
+        let modified = fs::metadata(&incremental_path).unwrap().modified().unwrap();
+
+        delete_backup("Survival", &full.backup_name).unwrap();
+
+        let info = get_backup_info("Survival", &incremental.backup_name).unwrap();
+        assert_eq!(info.kind, BackupKind::Full);
+        assert_eq!(info.checksum_verified, Some(true));
+        // Replaced in place, without leftovers from the staging directory
+        assert_eq!(fs::metadata(&incremental_path).unwrap().modified().unwrap(), modified);
+        let save_backup_dir = backup_base.path().join("Survival");
+        assert_eq!(list_backup_files(&save_backup_dir, false).unwrap().len(), 1);
+        assert_eq!(fs::read_dir(&save_backup_dir).unwrap().count(), 3);
+        let manifest = read_backup_manifest(&incremental_path).unwrap();
+        assert!(manifest.parent.is_none() && manifest.file_sources.is_none());
+
+        let restored = save_base.path().join("restored");
+        extract_backup(&incremental_path, &restored).unwrap();
+        assert_eq!(fs::read(restored.join("map/pchunk_0_0.dat")).unwrap(), [b'm'; 1024]);
+        assert!(restored.join("map/pchunk_1_0.dat").exists());
+        assert!(!restored.join("map/pchunk_0_1.dat").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_garbage_collection_promotes_incremental_backup() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        let mut config = Config::with_paths(
+            save_base.path().to_str().unwrap().to_string(),
+            backup_base.path().to_str().unwrap().to_string(),
+        );
+        config.retention_count = RetentionMode::Limited(1);
+        config_module::save_config(&config).unwrap();
+
+        create_backup("Survival").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        modify_test_save(&save_dir);
+        let incremental = create_incremental_backup("Survival").unwrap();
+        assert_eq!(incremental.deleted_count, 1);
+
+        let backups = list_backups("Survival").unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].kind, BackupKind::Full);
+        let restored = save_base.path().join("restored");
+        extract_backup(&PathBuf::from(&incremental.backup_path), &restored).unwrap();
+        assert!(restored.join("map/pchunk_0_0.dat").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_incremental_backup_without_manifest_is_not_restored_as_full() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let full = create_backup("Survival").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        modify_test_save(&save_dir);
+        let incremental = create_incremental_backup("Survival").unwrap();
+        let incremental_path = PathBuf::from(&incremental.backup_path);
+        assert_eq!(
+            archived_incremental_parent(&incremental_path).as_deref(),
+            Some(full.backup_name.as_str())
+        );
+        assert!(archived_incremental_parent(&PathBuf::from(&full.backup_path)).is_none());
+
+        // A manifest without file sources would delete every file after extraction
+        let mut manifest = read_backup_manifest(&incremental_path).unwrap();
+        manifest.file_sources = None;
+        write_backup_manifest(&incremental_path, &manifest).unwrap();
+        let restored = save_base.path().join("restored");
+        let result = extract_backup(&incremental_path, &restored);
+        assert!(matches!(result, Err(BackupError::ManifestMissing(_))));
+        assert!(!restored.exists());
+
+        // Without a manifest the archive still says it's incremental
+        fs::remove_file(backup_manifest_path(&incremental_path)).unwrap();
+        let result = extract_backup(&incremental_path, &restored);
+        assert!(matches!(result, Err(BackupError::ManifestMissing(_))));
+
+        // Its parent can't be deleted, since the dependent couldn't be promoted
+        let result = delete_backup("Survival", &full.backup_name);
+        assert!(matches!(result, Err(BackupError::ManifestMissing(_))));
+        assert!(PathBuf::from(&full.backup_path).exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_garbage_collection_counts_only_deleted_backups() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let full = create_backup("Survival").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        modify_test_save(&save_dir);
+        let incremental = create_incremental_backup("Survival").unwrap();
+
+        // The incremental backup can't be promoted, so its parent has to stay
+        fs::write(&incremental.backup_path, b"not an archive").unwrap();
+        let save_backup_dir = backup_base.path().join("Survival");
+        let (retained, deleted, bytes_freed) =
+            garbage_collection(&save_backup_dir, RetentionMode::Limited(1), None, false).unwrap();
+        assert_eq!((retained, deleted, bytes_freed), (2, 0, 0));
+        assert!(PathBuf::from(&full.backup_path).exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_list_backup_contents() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup = create_backup("Survival").unwrap();
+        let entries = list_backup_contents("Survival", &backup.backup_name).unwrap();
+
+        let entry = |path: &str| entries.iter().find(|entry| entry.path == path).unwrap();
+        assert_eq!(entries.len(), 4);
+        assert!(entry("map").is_dir);
+        assert_eq!(entry("map/pchunk_0_0.dat").size, 1024);
+        assert_eq!(entry("save.bin").size, 10);
+        assert!(!entry("save.bin").is_dir);
+
+        let result = list_backup_contents("Survival", "missing.tar.gz");
+        assert!(matches!(result, Err(BackupError::BackupNotFound(_))));
+    }
+
+    #[test]
+    #[serial]
+    fn test_diff_backups() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup_a = create_backup("Survival").unwrap().backup_name;
+        fs::write(save_dir.join("save.bin"), b"game state, day 2").unwrap();
+        fs::remove_file(save_dir.join("map/pchunk_0_1.dat")).unwrap();
+        fs::write(save_dir.join("players.db"), b"players").unwrap();
+        // Backup names have second precision
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        let backup_b = create_backup("Survival").unwrap().backup_name;
+
+        let diff = diff_backups("Survival", &backup_a, &backup_b).unwrap();
+        let paths = |files: &[BackupDiffFile]| -> Vec<String> {
+            files.iter().map(|file| file.path.clone()).collect()
+        };
+        assert_eq!(paths(&diff.added), vec!["players.db"]);
+        assert_eq!(paths(&diff.removed), vec!["map/pchunk_0_1.dat"]);
+        assert_eq!(paths(&diff.changed), vec!["save.bin"]);
+        assert_eq!(diff.changed[0].size_a, Some(10));
+        assert_eq!(diff.changed[0].size_b, Some(17));
+        assert_eq!(diff.unchanged_count, 1);
+
+        let result = diff_backups("Survival", &backup_a, "missing.tar.gz");
+        assert!(matches!(result, Err(BackupError::BackupNotFound(_))));
+    }
 }
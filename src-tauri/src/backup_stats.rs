@@ -0,0 +1,255 @@
+//! Historical backup statistics for Project Zomboid save backup/restore.
+//!
+//! This module provides:
+//! - Lifetime counters for created/deleted backups, bytes and restores
+//! - Per-save statistics
+//! - Persistence to `<backup_base_path>/statistics.json`
+
+use crate::config as config_module;
+use crate::config::ConfigError;
+use crate::file_ops::{FileOpsError, FileOpsResult};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Statistics file name, stored in the backup base directory.
+const STATISTICS_FILE_NAME: &str = "statistics.json";
+
+/// Serializes read-modify-write cycles on the statistics file, since garbage
+/// collection may update it from a background thread.
+static STATISTICS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Statistics for a single save.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveStats {
+    /// Number of backups created for this save
+    pub backups_created: u64,
+    /// Number of backups of this save deleted (manually or by GC)
+    pub backups_deleted: u64,
+    /// Total archive bytes written for this save
+    pub bytes_backed_up: u64,
+    /// Number of restores into this save
+    pub restore_operations: u64,
+    /// Time of the latest backup of this save (RFC 3339)
+    pub last_backup_at: Option<String>,
+}
+
+/// Lifetime backup statistics across all saves.
+///
+/// Missing fields deserialize to their defaults, so counters added later start at zero.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupStatistics {
+    /// Number of backups created
+    pub total_backups_created: u64,
+    /// Number of backups deleted (manually or by GC)
+    pub total_backups_deleted: u64,
+    /// Total archive bytes written
+    pub total_bytes_backed_up: u64,
+    /// Total archive bytes removed by garbage collection
+    pub total_bytes_freed_by_gc: u64,
+    /// Number of restores (from backups and undo snapshots)
+    pub total_restore_operations: u64,
+    /// Mean time to create a backup, in milliseconds
+    pub average_backup_duration_ms: f64,
+    /// Time of the first recorded backup (RFC 3339)
+    pub first_backup_at: Option<String>,
+    /// Time of the latest backup (RFC 3339)
+    pub last_backup_at: Option<String>,
+    /// Statistics per save, keyed by relative save path
+    pub per_save_stats: HashMap<String, SaveStats>,
+}
+
+impl BackupStatistics {
+    /// Records a newly created backup.
+    pub fn record_backup(&mut self, save_name: &str, size_bytes: u64, duration: Duration) {
+        let now = Utc::now().to_rfc3339();
+        let duration_ms = duration.as_secs_f64() * 1000.0;
+
+        // Running mean over all backups, including this one
+        self.total_backups_created += 1;
+        self.average_backup_duration_ms += (duration_ms - self.average_backup_duration_ms)
+            / self.total_backups_created as f64;
+        self.total_bytes_backed_up += size_bytes;
+        if self.first_backup_at.is_none() {
+            self.first_backup_at = Some(now.clone());
+        }
+        self.last_backup_at = Some(now.clone());
+
+        let save = self.per_save_stats.entry(save_name.to_string()).or_default();
+        save.backups_created += 1;
+        save.bytes_backed_up += size_bytes;
+        save.last_backup_at = Some(now);
+    }
+
+    /// Records deleted backups; `bytes_freed_by_gc` is 0 for manual deletions.
+    pub fn record_deletions(&mut self, save_name: &str, count: usize, bytes_freed_by_gc: u64) {
+        self.total_backups_deleted += count as u64;
+        self.total_bytes_freed_by_gc += bytes_freed_by_gc;
+        self.per_save_stats
+            .entry(save_name.to_string())
+            .or_default()
+            .backups_deleted += count as u64;
+    }
+
+    /// Records a restore into a save.
+    pub fn record_restore(&mut self, save_name: &str) {
+        self.total_restore_operations += 1;
+        self.per_save_stats
+            .entry(save_name.to_string())
+            .or_default()
+            .restore_operations += 1;
+    }
+}
+
+/// Returns the path of the statistics file for a backup base directory.
+fn statistics_path(backup_base_path: &Path) -> PathBuf {
+    backup_base_path.join(STATISTICS_FILE_NAME)
+}
+
+/// Loads statistics, returning defaults if the file doesn't exist yet.
+fn read_statistics(backup_base_path: &Path) -> FileOpsResult<BackupStatistics> {
+    let path = statistics_path(backup_base_path);
+    if !path.exists() {
+        return Ok(BackupStatistics::default());
+    }
+
+    let content = fs::read_to_string(&path)?;
+    Ok(serde_json::from_str(&content).map_err(std::io::Error::from)?)
+}
+
+/// Writes statistics, creating the backup base directory if needed.
+fn write_statistics(backup_base_path: &Path, stats: &BackupStatistics) -> FileOpsResult<()> {
+    fs::create_dir_all(backup_base_path)?;
+    let json = serde_json::to_string_pretty(stats).map_err(std::io::Error::from)?;
+    fs::write(statistics_path(backup_base_path), json)?;
+    Ok(())
+}
+
+/// Applies `update` to the statistics stored under `backup_base_path` and saves them.
+///
+/// # Arguments
+/// * `backup_base_path` - Backup base directory holding `statistics.json`
+/// * `update` - Changes to apply (e.g. `|s| s.record_restore("Survival")`)
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success
+///
+/// # Behavior
+/// Callers treat failures as non-critical: statistics never make a backup or
+/// restore fail.
+pub fn update_statistics<F>(backup_base_path: &Path, update: F) -> FileOpsResult<()>
+where
+    F: FnOnce(&mut BackupStatistics),
+{
+    let _guard = STATISTICS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut stats = read_statistics(backup_base_path)?;
+    update(&mut stats);
+    write_statistics(backup_base_path, &stats)
+}
+
+/// Returns the statistics for the configured backup path.
+///
+/// # Returns
+/// `Result<BackupStatistics, ConfigError>` - Stored statistics, or all zeros if none exist yet
+pub fn get_backup_statistics() -> Result<BackupStatistics, ConfigError> {
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let _guard = STATISTICS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(read_statistics(&backup_base_path)?)
+}
+
+/// Resets all statistics for the configured backup path to zero.
+///
+/// # Returns
+/// `Result<(), ConfigError>` - Ok(()) on success
+pub fn reset_statistics() -> Result<(), ConfigError> {
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let _guard = STATISTICS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let path = statistics_path(&backup_base_path);
+    if path.exists() {
+        fs::remove_file(&path).map_err(FileOpsError::Io)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::{create_backup, delete_backup, list_backups};
+    use crate::config::{Config, RetentionMode};
+    use crate::restore::restore_backup;
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    fn create_test_save(save_dir: &Path) {
+        fs::create_dir_all(save_dir.join("map")).unwrap();
+        fs::write(save_dir.join("save.bin"), b"game state").unwrap();
+        fs::write(save_dir.join("map/pchunk_0_0.dat"), [b'm'; 1024]).unwrap();
+    }
+
+    #[test]
+    fn test_record_backup_running_average() {
+        let mut stats = BackupStatistics::default();
+        stats.record_backup("Survival", 100, Duration::from_millis(100));
+        stats.record_backup("Survival", 300, Duration::from_millis(300));
+
+        assert_eq!(stats.total_backups_created, 2);
+        assert_eq!(stats.total_bytes_backed_up, 400);
+        assert!((stats.average_backup_duration_ms - 200.0).abs() < 1e-9);
+        assert!(stats.first_backup_at.is_some());
+        assert_eq!(stats.per_save_stats["Survival"].backups_created, 2);
+    }
+
+    #[test]
+    #[serial]
+    fn test_statistics_track_backups_gc_and_restores() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+        create_test_save(&save_base.path().join("Builder"));
+
+        let mut config = Config::with_paths(
+            save_base.path().to_str().unwrap().to_string(),
+            backup_base.path().to_str().unwrap().to_string(),
+        );
+        config.retention_count = RetentionMode::Limited(2);
+        config_module::save_config(&config).unwrap();
+
+        for _ in 0..3 {
+            create_backup("Survival").unwrap();
+            // Backup names have second precision
+            std::thread::sleep(Duration::from_millis(1100));
+        }
+        let builder = create_backup("Builder").unwrap();
+        delete_backup("Builder", &builder.backup_name).unwrap();
+        let survival = list_backups("Survival").unwrap();
+        restore_backup("Survival", &survival[0].name).unwrap();
+
+        let stats = get_backup_statistics().unwrap();
+        assert_eq!(stats.total_backups_created, 4);
+        // One Survival backup removed by GC, one Builder backup deleted manually
+        assert_eq!(stats.total_backups_deleted, 2);
+        assert!(stats.total_bytes_freed_by_gc > 0);
+        assert!(stats.total_bytes_backed_up >= stats.total_bytes_freed_by_gc);
+        assert_eq!(stats.total_restore_operations, 1);
+        assert!(stats.first_backup_at.is_some());
+        assert!(stats.first_backup_at <= stats.last_backup_at);
+
+        let survival_stats = &stats.per_save_stats["Survival"];
+        assert_eq!(survival_stats.backups_created, 3);
+        assert_eq!(survival_stats.backups_deleted, 1);
+        assert_eq!(survival_stats.restore_operations, 1);
+        assert_eq!(stats.per_save_stats["Builder"].backups_deleted, 1);
+
+        reset_statistics().unwrap();
+        assert_eq!(get_backup_statistics().unwrap(), BackupStatistics::default());
+    }
+}
@@ -5,18 +5,106 @@
 //! - Configuration file persistence (JSON format)
 //! - User preference management (paths, backup retention settings)
 
-use crate::file_ops::{FileOpsError, FileOpsResult};
+use crate::file_ops::{ArchiveFormat, FileOpsError, FileOpsResult};
 use crate::tags::Tag;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
+use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
 /// Default backup retention count.
 pub const DEFAULT_RETENTION_COUNT: usize = 10;
 
+/// Largest retention count `update_retention_count` accepts (0 still keeps all backups).
+pub const MAX_RETENTION_COUNT: usize = 1000;
+
+/// Default minimum save size for a backup to be created.
+pub const DEFAULT_MIN_SAVE_SIZE_BYTES: u64 = 1024;
+
+/// Default number of undo snapshots to keep per save.
+pub const DEFAULT_UNDO_SNAPSHOT_RETENTION: usize = 10;
+
+/// Default gzip compression level for backups (matches flate2's default).
+pub const DEFAULT_COMPRESSION_LEVEL: u8 = 6;
+
+/// Default number of threads compressing a backup.
+pub const DEFAULT_BACKUP_THREADS: usize = 1;
+
+/// UI themes accepted by `update_ui_theme`.
+pub const UI_THEMES: [&str; 4] = ["dark", "light", "system", "custom"];
+
+/// Version of the config file format written by this build (see `migrate_config`).
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
 /// Default configuration file name.
 const CONFIG_FILE_NAME: &str = "zomboid_backup_config.json";
 
+/// How long a cached save list is served before the saves directory is rescanned.
+pub const CACHE_TTL_SECS: u64 = 5;
+
+/// Bounds for the config file read buffer, which is sized from the file length.
+const MIN_CONFIG_BUFFER_SIZE: usize = 1024;
+const MAX_CONFIG_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Backup retention policy.
+///
+/// Serialized as a plain count for backward compatibility with existing config
+/// files, where `0` means `Unlimited`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetentionMode {
+    /// Keep at most this many backups per save
+    Limited(usize),
+    /// Keep all backups forever (garbage collection never deletes anything)
+    Unlimited,
+}
+
+impl RetentionMode {
+    /// Converts a retention count to a mode, treating `0` as unlimited.
+    pub fn from_count(count: usize) -> Self {
+        if count == 0 {
+            RetentionMode::Unlimited
+        } else {
+            RetentionMode::Limited(count)
+        }
+    }
+
+    /// Returns the retention count, or `0` for unlimited.
+    pub fn count(&self) -> usize {
+        match self {
+            RetentionMode::Limited(count) => *count,
+            RetentionMode::Unlimited => 0,
+        }
+    }
+}
+
+impl Default for RetentionMode {
+    fn default() -> Self {
+        RetentionMode::Limited(DEFAULT_RETENTION_COUNT)
+    }
+}
+
+impl Serialize for RetentionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.count() as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for RetentionMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let count = usize::deserialize(deserializer)?;
+        Ok(RetentionMode::from_count(count))
+    }
+}
+
 /// Application configuration.
 ///
 /// Stores user preferences including save paths, backup location, and retention policy.
@@ -32,7 +120,8 @@ pub struct Config {
 
     /// Maximum number of backups to retain per save.
     /// Old backups exceeding this count will be garbage collected.
-    pub retention_count: usize,
+    /// Stored as a count in the config file, where 0 means keep all backups.
+    pub retention_count: RetentionMode,
 
     /// Whether to automatically check for updates on startup.
     #[serde(default = "default_auto_check_updates")]
@@ -46,6 +135,113 @@ pub struct Config {
     /// Used to restore the user's previous selection on app startup.
     #[serde(default)]
     pub last_selected_save: Option<String>,
+
+    /// Maximum backup I/O throughput in bytes per second.
+    /// If None, backups run at full disk speed.
+    #[serde(default)]
+    pub max_io_bytes_per_sec: Option<u64>,
+
+    /// Backups older than this many days are deleted after each backup, on top of the
+    /// retention count. If None, backups are only pruned by count.
+    #[serde(default)]
+    pub max_backup_age_days: Option<u64>,
+
+    /// Whether backup and undo snapshot paths are reported relative to the backup path.
+    /// Useful when backups live on a removable drive whose mount point or drive letter changes.
+    #[serde(default)]
+    pub use_relative_paths: bool,
+
+    /// Additional process names treated as "game is running" (e.g. launchers or wrapper scripts).
+    #[serde(default)]
+    pub custom_game_process_names: Vec<String>,
+
+    /// Gzip compression level for new backups (0 = fastest, 9 = smallest).
+    #[serde(default = "default_compression_level")]
+    pub compression_level: u8,
+
+    /// Archive format for new backups; existing backups restore in either format.
+    #[serde(default)]
+    pub archive_format: ArchiveFormat,
+
+    /// Number of threads compressing tar.gz backups (1 = single-threaded).
+    #[serde(default = "default_backup_threads")]
+    pub backup_threads: usize,
+
+    /// Whether backup and undo snapshot names use local time (false = UTC).
+    #[serde(default = "default_use_local_time")]
+    pub use_local_time: bool,
+
+    /// Wildcard patterns for files and directories to leave out of backups.
+    #[serde(default)]
+    pub exclude_patterns: Vec<String>,
+
+    /// Whether to verify each backup archive right after creating it.
+    #[serde(default)]
+    pub verify_after_create: bool,
+
+    /// Undo snapshot retention policy per save (serialized as a count, 0 = keep all).
+    #[serde(default = "default_undo_snapshot_retention")]
+    pub undo_snapshot_retention: RetentionMode,
+
+    /// Proxy URL for update checks (e.g. "http://proxy.example.com:3128").
+    /// Takes precedence over the `HTTPS_PROXY` / `ALL_PROXY` environment variables.
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+
+    /// Saves smaller than this are treated as empty and not backed up (0 = no minimum).
+    #[serde(default = "default_min_save_size_bytes")]
+    pub min_save_size_bytes: u64,
+
+    /// Whether initial setup has not been completed yet (fresh install).
+    /// Config files written before this field existed belong to returning users,
+    /// so a missing value deserializes as `false`.
+    #[serde(default)]
+    pub first_run: bool,
+
+    /// Time of the latest backup per save (relative save path -> ISO 8601 timestamp).
+    #[serde(default)]
+    pub last_backup_per_save: HashMap<String, String>,
+
+    /// Time of the latest backup of any save (ISO 8601 format).
+    #[serde(default)]
+    pub last_backup_at: Option<String>,
+
+    /// UI theme, one of `UI_THEMES` (None = frontend default).
+    #[serde(default)]
+    pub ui_theme: Option<String>,
+
+    /// UI language as a BCP 47 tag, e.g. "en" or "zh-CN" (None = system language).
+    #[serde(default)]
+    pub ui_language: Option<String>,
+
+    /// Release version the user chose to skip; no update is reported for it.
+    #[serde(default)]
+    pub skipped_version: Option<String>,
+
+    /// Config file format version. Files written before versioning have none and
+    /// load as version 0; `save_config` always writes `CURRENT_CONFIG_VERSION`.
+    #[serde(default)]
+    pub config_version: u32,
+}
+
+/// Picks `overrides` unless it still has the default value (see `Config::merge`).
+fn merge_value<T: Clone + PartialEq>(base: &T, overrides: &T, default: &T) -> T {
+    if overrides != default {
+        overrides.clone()
+    } else {
+        base.clone()
+    }
+}
+
+/// Concatenates two lists, skipping entries of `overrides` already in `base`.
+fn merge_list(base: &[String], overrides: &[String]) -> Vec<String> {
+    let mut merged = base.to_vec();
+    for item in overrides {
+        if !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    merged
 }
 
 /// Default value for auto_check_updates field.
@@ -53,15 +249,60 @@ fn default_auto_check_updates() -> bool {
     true
 }
 
+/// Default value for min_save_size_bytes field.
+fn default_min_save_size_bytes() -> u64 {
+    DEFAULT_MIN_SAVE_SIZE_BYTES
+}
+
+/// Default value for undo_snapshot_retention field.
+fn default_undo_snapshot_retention() -> RetentionMode {
+    RetentionMode::Limited(DEFAULT_UNDO_SNAPSHOT_RETENTION)
+}
+
+/// Default value for backup_threads field.
+fn default_backup_threads() -> usize {
+    DEFAULT_BACKUP_THREADS
+}
+
+/// Default value for use_local_time field.
+fn default_use_local_time() -> bool {
+    false
+}
+
+/// Default value for compression_level field.
+fn default_compression_level() -> u8 {
+    DEFAULT_COMPRESSION_LEVEL
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config {
             save_path: None,
             backup_path: None,
-            retention_count: DEFAULT_RETENTION_COUNT,
+            retention_count: RetentionMode::default(),
             auto_check_updates: default_auto_check_updates(),
             last_update_check: None,
             last_selected_save: None,
+            max_io_bytes_per_sec: None,
+            max_backup_age_days: None,
+            use_relative_paths: false,
+            custom_game_process_names: Vec::new(),
+            compression_level: default_compression_level(),
+            archive_format: ArchiveFormat::default(),
+            backup_threads: default_backup_threads(),
+            use_local_time: default_use_local_time(),
+            exclude_patterns: Vec::new(),
+            verify_after_create: false,
+            undo_snapshot_retention: default_undo_snapshot_retention(),
+            proxy_url: None,
+            min_save_size_bytes: default_min_save_size_bytes(),
+            first_run: true,
+            last_backup_per_save: HashMap::new(),
+            last_backup_at: None,
+            ui_theme: None,
+            ui_language: None,
+            skipped_version: None,
+            config_version: CURRENT_CONFIG_VERSION,
         }
     }
 }
@@ -89,6 +330,144 @@ impl Config {
         }
     }
 
+    /// Creates a default configuration with the platform's save and backup paths filled in.
+    ///
+    /// Paths that cannot be detected (no home directory) stay `None`, as in `default()`.
+    pub fn with_defaults_for_platform() -> Self {
+        let mut config = Config::default();
+        config.apply_detected_paths();
+        config
+    }
+
+    /// Fills in unset paths with the detected platform defaults.
+    ///
+    /// # Returns
+    /// `Vec<String>` - Names of the fields that were filled in (`"save_path"`, `"backup_path"`)
+    ///
+    /// # Behavior
+    /// Paths the user has set are never overwritten.
+    pub fn apply_detected_paths(&mut self) -> Vec<String> {
+        let mut filled = Vec::new();
+
+        if self.save_path.is_none() {
+            if let Ok(path) = detect_zomboid_save_path() {
+                self.save_path = Some(path.to_string_lossy().to_string());
+                filled.push("save_path".to_string());
+            }
+        }
+        if self.backup_path.is_none() {
+            if let Ok(path) = get_default_backup_path() {
+                self.backup_path = Some(path.to_string_lossy().to_string());
+                filled.push("backup_path".to_string());
+            }
+        }
+
+        filled
+    }
+
+    /// Layers `overrides` on top of `base` and returns the combined configuration.
+    ///
+    /// # Behavior
+    /// - `Option` fields: a `Some` value in `overrides` replaces the base value
+    /// - `Vec` fields: concatenated (base entries first) without duplicates
+    /// - Map fields: merged, with `overrides` winning on collision
+    /// - Other fields: the `overrides` value wins if it differs from the default, so an
+    ///   override built from `Config::default()` only changes the fields it sets
+    pub fn merge(base: &Config, overrides: &Config) -> Config {
+        let defaults = Config::default();
+        Config {
+            save_path: overrides.save_path.clone().or_else(|| base.save_path.clone()),
+            backup_path: overrides.backup_path.clone().or_else(|| base.backup_path.clone()),
+            retention_count: merge_value(
+                &base.retention_count,
+                &overrides.retention_count,
+                &defaults.retention_count,
+            ),
+            auto_check_updates: merge_value(
+                &base.auto_check_updates,
+                &overrides.auto_check_updates,
+                &defaults.auto_check_updates,
+            ),
+            last_update_check: overrides
+                .last_update_check
+                .clone()
+                .or_else(|| base.last_update_check.clone()),
+            last_selected_save: overrides
+                .last_selected_save
+                .clone()
+                .or_else(|| base.last_selected_save.clone()),
+            max_io_bytes_per_sec: overrides.max_io_bytes_per_sec.or(base.max_io_bytes_per_sec),
+            max_backup_age_days: overrides.max_backup_age_days.or(base.max_backup_age_days),
+            use_relative_paths: merge_value(
+                &base.use_relative_paths,
+                &overrides.use_relative_paths,
+                &defaults.use_relative_paths,
+            ),
+            custom_game_process_names: merge_list(
+                &base.custom_game_process_names,
+                &overrides.custom_game_process_names,
+            ),
+            compression_level: merge_value(
+                &base.compression_level,
+                &overrides.compression_level,
+                &defaults.compression_level,
+            ),
+            archive_format: merge_value(
+                &base.archive_format,
+                &overrides.archive_format,
+                &defaults.archive_format,
+            ),
+            backup_threads: merge_value(
+                &base.backup_threads,
+                &overrides.backup_threads,
+                &defaults.backup_threads,
+            ),
+            use_local_time: merge_value(
+                &base.use_local_time,
+                &overrides.use_local_time,
+                &defaults.use_local_time,
+            ),
+            exclude_patterns: merge_list(&base.exclude_patterns, &overrides.exclude_patterns),
+            verify_after_create: merge_value(
+                &base.verify_after_create,
+                &overrides.verify_after_create,
+                &defaults.verify_after_create,
+            ),
+            undo_snapshot_retention: merge_value(
+                &base.undo_snapshot_retention,
+                &overrides.undo_snapshot_retention,
+                &defaults.undo_snapshot_retention,
+            ),
+            proxy_url: overrides.proxy_url.clone().or_else(|| base.proxy_url.clone()),
+            min_save_size_bytes: merge_value(
+                &base.min_save_size_bytes,
+                &overrides.min_save_size_bytes,
+                &defaults.min_save_size_bytes,
+            ),
+            first_run: merge_value(&base.first_run, &overrides.first_run, &defaults.first_run),
+            last_backup_per_save: base
+                .last_backup_per_save
+                .iter()
+                .chain(&overrides.last_backup_per_save)
+                .map(|(save, time)| (save.clone(), time.clone()))
+                .collect(),
+            last_backup_at: overrides
+                .last_backup_at
+                .clone()
+                .or_else(|| base.last_backup_at.clone()),
+            ui_theme: overrides.ui_theme.clone().or_else(|| base.ui_theme.clone()),
+            ui_language: overrides
+                .ui_language
+                .clone()
+                .or_else(|| base.ui_language.clone()),
+            skipped_version: overrides
+                .skipped_version
+                .clone()
+                .or_else(|| base.skipped_version.clone()),
+            config_version: base.config_version.max(overrides.config_version),
+        }
+    }
+
     /// Returns the effective save path, using auto-detection if not set.
     pub fn get_save_path(&self) -> FileOpsResult<PathBuf> {
         match &self.save_path {
@@ -105,6 +484,17 @@ impl Config {
         }
     }
 
+    /// Resolves a path reported by this tool against the effective backup path.
+    ///
+    /// Relative paths (see `use_relative_paths`) are joined onto the backup path,
+    /// absolute paths are returned unchanged.
+    pub fn resolve_backup_relative_path(&self, path: &Path) -> FileOpsResult<PathBuf> {
+        if path.is_absolute() {
+            return Ok(path.to_path_buf());
+        }
+        Ok(self.get_backup_path()?.join(path))
+    }
+
     /// Validates that all configured paths exist and are directories.
     pub fn validate(&self) -> FileOpsResult<()> {
         let save_path = self.get_save_path()?;
@@ -241,9 +631,24 @@ pub fn get_default_backup_path() -> FileOpsResult<PathBuf> {
 /// # Platform Behavior
 /// - **Windows**: `%APPDATA%\ZomboidBackupTool`
 /// - **macOS**: `~/Library/Application Support/ZomboidBackupTool`
-/// - **Linux**: `~/.config/ZomboidBackupTool`
+/// - **Linux**: `$XDG_CONFIG_HOME/ZomboidBackupTool` if `XDG_CONFIG_HOME` is set to an
+///   absolute path, otherwise `~/.config/ZomboidBackupTool`
+///
+/// On Linux the canonical config file is therefore
+/// `$XDG_CONFIG_HOME/ZomboidBackupTool/zomboid_backup_config.json`.
 pub fn get_config_dir() -> ConfigResult<PathBuf> {
-    // dirs::config_dir() already handles platform differences correctly
+    #[cfg(target_os = "linux")]
+    {
+        // The XDG spec says relative values must be ignored
+        if let Some(xdg_config_home) = std::env::var_os("XDG_CONFIG_HOME") {
+            let base = PathBuf::from(xdg_config_home);
+            if base.is_absolute() {
+                return Ok(base.join("ZomboidBackupTool"));
+            }
+        }
+    }
+
+    // dirs::config_dir() handles the remaining platform differences
     let config_dir = dirs::config_dir().map(|p| p.join("ZomboidBackupTool"));
     config_dir.ok_or(ConfigError::ConfigDirNotFound)
 }
@@ -260,21 +665,104 @@ pub fn get_config_file_path() -> ConfigResult<PathBuf> {
 /// `ConfigResult<Config>` - Loaded configuration, or default if file doesn't exist
 ///
 /// # Behavior
-/// - If config file exists, loads and parses it
-/// - If config file doesn't exist, returns default config
+/// - If config file exists, streams and parses it (without reading it into a string first)
+///   and upgrades it from older format versions (see `migrate_config`)
+/// - If config file doesn't exist (first run), returns `Config::with_defaults_for_platform()`
 /// - If config file is corrupted, returns error
 pub fn load_config() -> ConfigResult<Config> {
     let config_path = get_config_file_path()?;
 
     if !config_path.exists() {
-        // Config file doesn't exist yet, return default
-        return Ok(Config::default());
+        // First run: start from the detected platform paths
+        return Ok(Config::with_defaults_for_platform());
     }
 
-    let content = fs::read_to_string(&config_path)
+    let file = fs::File::open(&config_path)
         .map_err(FileOpsError::Io)?;
 
-    let config: Config = serde_json::from_str(&content)?;
+    // Size the buffer from the file length so small configs don't over-allocate
+    let file_len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let capacity = usize::try_from(file_len)
+        .unwrap_or(MAX_CONFIG_BUFFER_SIZE)
+        .clamp(MIN_CONFIG_BUFFER_SIZE, MAX_CONFIG_BUFFER_SIZE);
+
+    let raw: serde_json::Value =
+        serde_json::from_reader(BufReader::with_capacity(capacity, file))?;
+
+    migrate_config(raw)
+}
+
+/// Upgrades a config file's JSON from an older format version and parses it.
+///
+/// # Arguments
+/// * `raw` - Parsed contents of a config file
+///
+/// # Returns
+/// `ConfigResult<Config>` - The configuration at `CURRENT_CONFIG_VERSION`;
+/// `InvalidValue` if `raw` is not an object or comes from a newer version
+///
+/// # Behavior
+/// Each step upgrades one version. Fields added without changing the meaning of
+/// existing ones only need serde defaults, not a new version.
+/// - v0 (no `config_version`) -> v1: unchanged, missing fields take their defaults
+pub fn migrate_config(mut raw: serde_json::Value) -> ConfigResult<Config> {
+    let Some(fields) = raw.as_object_mut() else {
+        return Err(ConfigError::InvalidValue(
+            "config file must contain a JSON object".to_string(),
+        ));
+    };
+
+    let version = fields
+        .get("config_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0);
+    if version > u64::from(CURRENT_CONFIG_VERSION) {
+        return Err(ConfigError::InvalidValue(format!(
+            "config version {} is newer than the supported version {}",
+            version, CURRENT_CONFIG_VERSION
+        )));
+    }
+
+    fields.insert("config_version".to_string(), CURRENT_CONFIG_VERSION.into());
+    Ok(serde_json::from_value(raw)?)
+}
+
+/// Environment variable overriding the save path.
+pub const ENV_SAVE_PATH: &str = "ZOMBOID_BACKUP_SAVE_PATH";
+
+/// Environment variable overriding the backup path.
+pub const ENV_BACKUP_PATH: &str = "ZOMBOID_BACKUP_PATH";
+
+/// Environment variable overriding the retention count (0 = keep all backups).
+pub const ENV_RETENTION: &str = "ZOMBOID_BACKUP_RETENTION";
+
+/// Loads configuration and applies overrides from environment variables.
+///
+/// # Returns
+/// `ConfigResult<Config>` - Configuration from `load_config` with overrides applied;
+/// `InvalidValue` if `ZOMBOID_BACKUP_RETENTION` is not a number
+///
+/// # Behavior
+/// - `ZOMBOID_BACKUP_SAVE_PATH` replaces `save_path`
+/// - `ZOMBOID_BACKUP_PATH` replaces `backup_path`
+/// - `ZOMBOID_BACKUP_RETENTION` replaces `retention_count`
+/// - Empty variables are ignored; the overrides are never written to the config file
+pub fn load_config_with_env_overrides() -> ConfigResult<Config> {
+    let mut config = load_config()?;
+    let env_value = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    if let Some(save_path) = env_value(ENV_SAVE_PATH) {
+        config.save_path = Some(save_path);
+    }
+    if let Some(backup_path) = env_value(ENV_BACKUP_PATH) {
+        config.backup_path = Some(backup_path);
+    }
+    if let Some(retention) = env_value(ENV_RETENTION) {
+        let count = retention.trim().parse::<usize>().map_err(|_| {
+            ConfigError::InvalidValue(format!("{} must be a number: {}", ENV_RETENTION, retention))
+        })?;
+        config.retention_count = RetentionMode::from_count(count);
+    }
 
     Ok(config)
 }
@@ -290,7 +778,8 @@ pub fn load_config() -> ConfigResult<Config> {
 /// # Behavior
 /// - Creates config directory if it doesn't exist
 /// - Overwrites existing config file
-/// - Writes formatted JSON for readability
+/// - Streams formatted JSON for readability (no intermediate string)
+/// - Always records `CURRENT_CONFIG_VERSION` as the file's version
 pub fn save_config(config: &Config) -> ConfigResult<()> {
     let config_path = get_config_file_path()?;
 
@@ -300,41 +789,199 @@ pub fn save_config(config: &Config) -> ConfigResult<()> {
             .map_err(FileOpsError::Io)?;
     }
 
-    // Serialize to formatted JSON
-    let json = serde_json::to_string_pretty(config)?;
+    // Serialize formatted JSON straight to the file
+    let file = fs::File::create(&config_path)
+        .map_err(FileOpsError::Io)?;
+    let mut writer = BufWriter::new(file);
+    let config = Config {
+        config_version: CURRENT_CONFIG_VERSION,
+        ..config.clone()
+    };
+    serde_json::to_writer_pretty(&mut writer, &config)?;
 
-    // Write to file
-    fs::write(&config_path, json)
+    // Flush explicitly so write errors are reported instead of dropped
+    writer.flush()
         .map_err(FileOpsError::Io)?;
 
     Ok(())
 }
 
+/// Writes the current configuration to an arbitrary file, e.g. to move it to another machine.
+///
+/// # Arguments
+/// * `dest_path` - File to write (overwritten if it exists)
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Behavior
+/// Creates parent directories if needed; the file has the same format as the config file.
+pub fn export_config(dest_path: &Path) -> ConfigResult<()> {
+    let config = load_config()?;
+
+    if let Some(parent) = dest_path.parent() {
+        fs::create_dir_all(parent).map_err(FileOpsError::Io)?;
+    }
+    let json = serde_json::to_string_pretty(&config)?;
+    fs::write(dest_path, json).map_err(FileOpsError::Io)?;
+    Ok(())
+}
+
+/// Reads a configuration written by `export_config`, validates it and makes it current.
+///
+/// # Arguments
+/// * `src_path` - File to import
+///
+/// # Returns
+/// `ConfigResult<Config>` - The imported configuration
+///
+/// # Behavior
+/// - Returns `InvalidValue` if the file is not a JSON object, has fields this version
+///   doesn't know (e.g. it was exported by a newer version) or has invalid values
+/// - Files exported by older versions are upgraded like in `load_config`
+/// - Returns `InvalidValue` if `Config::validate` rejects the imported paths
+/// - The current config file is only replaced once the import is valid
+pub fn import_config(src_path: &Path) -> ConfigResult<Config> {
+    let content = fs::read_to_string(src_path).map_err(FileOpsError::Io)?;
+    let invalid =
+        |msg: String| ConfigError::InvalidValue(format!("{}: {}", src_path.display(), msg));
+
+    let value: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| invalid(format!("not a valid config file ({})", e)))?;
+    let Some(fields) = value.as_object() else {
+        return Err(invalid("not a valid config file (expected a JSON object)".to_string()));
+    };
+
+    // Every field is serialized, so the default config lists all known fields
+    let known = serde_json::to_value(Config::default())?;
+    let unknown: Vec<&str> = fields
+        .keys()
+        .filter(|key| known.get(key.as_str()).is_none())
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        return Err(invalid(format!(
+            "unknown fields {} (exported by a newer version?)",
+            unknown.join(", ")
+        )));
+    }
+
+    let config = migrate_config(value).map_err(|e| match e {
+        ConfigError::InvalidValue(msg) => invalid(msg),
+        e => invalid(format!("invalid config value ({})", e)),
+    })?;
+    config
+        .validate()
+        .map_err(|e| invalid(format!("invalid paths ({})", e)))?;
+
+    save_config(&config)?;
+    Ok(config)
+}
+
+/// Serializes read-modify-write cycles on the config file, since backups record their
+/// time from background threads while settings may be changed at the same moment.
+static CONFIG_UPDATE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Loads the configuration, applies `update` and persists it, holding
+/// `CONFIG_UPDATE_LOCK` so concurrent updates don't overwrite each other.
+fn modify_config(update: impl FnOnce(&mut Config)) -> ConfigResult<()> {
+    let _guard = CONFIG_UPDATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut config = load_config()?;
+    update(&mut config);
+    save_config(&config)
+}
+
 /// Updates the save path in the configuration and persists it.
 pub fn update_save_path(save_path: String) -> ConfigResult<()> {
+    modify_config(|config| {
+        config.save_path = Some(save_path);
+    })
+}
+
+/// Marks initial setup as done so the setup wizard is not shown again.
+///
+/// Called once the user has set a save path, or dismissed the wizard.
+pub fn mark_first_run_complete() -> ConfigResult<()> {
+    let _guard = CONFIG_UPDATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     let mut config = load_config()?;
-    config.save_path = Some(save_path);
+    if !config.first_run {
+        return Ok(());
+    }
+    config.first_run = false;
     save_config(&config)
 }
 
 /// Updates the backup path in the configuration and persists it.
 pub fn update_backup_path(backup_path: String) -> ConfigResult<()> {
-    let mut config = load_config()?;
-    config.backup_path = Some(backup_path);
-    save_config(&config)
+    modify_config(|config| {
+        config.backup_path = Some(backup_path);
+    })
 }
 
 /// Updates the retention count in the configuration and persists it.
+///
+/// # Arguments
+/// * `count` - Maximum number of backups to keep per save, or `0` to keep all backups
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue above `MAX_RETENTION_COUNT`
 pub fn update_retention_count(count: usize) -> ConfigResult<()> {
-    if count == 0 {
-        return Err(ConfigError::InvalidValue(
-            format!("Retention count must be at least 1, got {}", count)
-        ));
+    if count > MAX_RETENTION_COUNT {
+        return Err(ConfigError::InvalidValue(format!(
+            "Retention count {} is above the maximum of {}",
+            count, MAX_RETENTION_COUNT
+        )));
     }
+    modify_config(|config| {
+        config.retention_count = RetentionMode::from_count(count);
+    })
+}
 
-    let mut config = load_config()?;
-    config.retention_count = count;
-    save_config(&config)
+/// Estimates the disk space a save's backups would take with a given retention count.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
+/// * `count` - Proposed retention count, or `0` to keep all backups
+///
+/// # Returns
+/// `ConfigResult<u64>` - Average size of the save's existing backups times `count`
+/// (0 if it has no backups yet); for `count` 0 the current total, since keeping
+/// all backups has no bound
+pub fn estimate_retention_disk_usage(save_name: &str, count: usize) -> ConfigResult<u64> {
+    crate::backup::validate_save_name(save_name)
+        .map_err(|e| ConfigError::InvalidValue(e.to_string()))?;
+    let save_backup_dir = load_config()?.get_backup_path()?.join(save_name);
+    if !save_backup_dir.is_dir() {
+        return Ok(0);
+    }
+
+    let mut total_bytes: u64 = 0;
+    let mut backups: u64 = 0;
+    for entry in fs::read_dir(&save_backup_dir).map_err(FileOpsError::Io)? {
+        let path = entry.map_err(FileOpsError::Io)?.path();
+        if path.is_file() && ArchiveFormat::from_path(&path).is_some() {
+            total_bytes += fs::metadata(&path).map_err(FileOpsError::Io)?.len();
+            backups += 1;
+        }
+    }
+
+    if count == 0 || backups == 0 {
+        return Ok(total_bytes);
+    }
+    Ok((total_bytes / backups).saturating_mul(count as u64))
+}
+
+/// Updates the undo snapshot retention count in the configuration and persists it.
+///
+/// # Arguments
+/// * `count` - Maximum number of undo snapshots to keep per save, or `0` to keep all
+///
+/// # Behavior
+/// Only saves the setting; `restore::prune_all_undo_snapshots` applies it to existing snapshots.
+pub fn update_undo_snapshot_retention(count: usize) -> ConfigResult<()> {
+    modify_config(|config| {
+        config.undo_snapshot_retention = RetentionMode::from_count(count);
+    })
 }
 
 /// Updates the last selected save in the configuration and persists it.
@@ -352,9 +999,211 @@ pub fn update_retention_count(count: usize) -> ConfigResult<()> {
 /// update_last_selected_save("Survival/MySave".to_string()).unwrap();
 /// ```
 pub fn update_last_selected_save(relative_path: String) -> ConfigResult<()> {
-    let mut config = load_config()?;
-    config.last_selected_save = Some(relative_path);
-    save_config(&config)
+    modify_config(|config| {
+        config.last_selected_save = Some(relative_path);
+    })
+}
+
+/// Records the time of a new backup in the configuration and persists it.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the backed up save (e.g., "Survival/MySave")
+/// * `timestamp` - ISO 8601 time of the backup
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+pub fn record_last_backup_time(save_name: &str, timestamp: &str) -> ConfigResult<()> {
+    modify_config(|config| {
+        config
+            .last_backup_per_save
+            .insert(save_name.to_string(), timestamp.to_string());
+        config.last_backup_at = Some(timestamp.to_string());
+    })
+}
+
+/// Gets the time of the latest backup of a save, without listing its backups.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
+///
+/// # Returns
+/// `ConfigResult<Option<String>>` - ISO 8601 timestamp, or None if no backup was recorded
+pub fn get_last_backup_time(save_name: &str) -> ConfigResult<Option<String>> {
+    Ok(load_config()?.last_backup_per_save.get(save_name).cloned())
+}
+
+/// Updates the backup I/O rate limit in the configuration and persists it.
+///
+/// # Arguments
+/// * `bytes_per_sec` - Maximum throughput in bytes per second, or None for unlimited
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue if the limit is zero
+pub fn update_io_rate_limit(bytes_per_sec: Option<u64>) -> ConfigResult<()> {
+    if bytes_per_sec == Some(0) {
+        return Err(ConfigError::InvalidValue(
+            "I/O rate limit must be greater than 0".to_string()
+        ));
+    }
+
+    modify_config(|config| {
+        config.max_io_bytes_per_sec = bytes_per_sec;
+    })
+}
+
+/// Updates the maximum backup age in the configuration and persists it.
+///
+/// # Arguments
+/// * `days` - Delete backups older than this many days after each backup, or None to
+///   only prune by count
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue if the age is zero
+pub fn update_max_backup_age(days: Option<u64>) -> ConfigResult<()> {
+    if days == Some(0) {
+        return Err(ConfigError::InvalidValue(
+            "Maximum backup age must be at least 1 day".to_string()
+        ));
+    }
+
+    modify_config(|config| {
+        config.max_backup_age_days = days;
+    })
+}
+
+/// Updates the minimum save size for backups in the configuration and persists it.
+///
+/// # Arguments
+/// * `bytes` - Saves smaller than this are skipped, or `0` to back up any save
+pub fn update_min_save_size(bytes: u64) -> ConfigResult<()> {
+    modify_config(|config| {
+        config.min_save_size_bytes = bytes;
+    })
+}
+
+/// Updates the archive format for new backups in the configuration and persists it.
+///
+/// # Arguments
+/// * `format` - Format used by `create_backup` from now on
+pub fn update_archive_format(format: ArchiveFormat) -> ConfigResult<()> {
+    modify_config(|config| {
+        config.archive_format = format;
+    })
+}
+
+/// Updates whether backup names use local time or UTC and persists it.
+///
+/// # Arguments
+/// * `use_local_time` - true for local time, false for UTC
+///
+/// # Behavior
+/// Only names generated from now on change; existing backups keep their names and
+/// are still aged and ordered by the timestamp in them.
+pub fn update_use_local_time(use_local_time: bool) -> ConfigResult<()> {
+    modify_config(|config| {
+        config.use_local_time = use_local_time;
+    })
+}
+
+/// Updates the UI theme in the configuration and persists it.
+///
+/// # Arguments
+/// * `theme` - One of `UI_THEMES`, or None to use the frontend default
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue for an unknown theme
+pub fn update_ui_theme(theme: Option<String>) -> ConfigResult<()> {
+    if let Some(theme) = &theme {
+        if !UI_THEMES.contains(&theme.as_str()) {
+            return Err(ConfigError::InvalidValue(format!(
+                "Unknown UI theme '{}', expected one of: {}",
+                theme,
+                UI_THEMES.join(", ")
+            )));
+        }
+    }
+
+    modify_config(|config| {
+        config.ui_theme = theme;
+    })
+}
+
+/// Returns true if `tag` is a well-formed BCP 47 language tag.
+///
+/// Only the shape is checked: a 2-8 letter primary subtag followed by
+/// alphanumeric subtags of 1-8 characters, separated by '-'.
+fn is_valid_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    let primary_ok = subtags.next().is_some_and(|s| {
+        (2..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphabetic())
+    });
+
+    primary_ok
+        && subtags.all(|s| {
+            (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+/// Updates the UI language in the configuration and persists it.
+///
+/// # Arguments
+/// * `language` - BCP 47 language tag (e.g., "en", "zh-CN"), or None to follow the system
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue for a malformed tag
+pub fn update_ui_language(language: Option<String>) -> ConfigResult<()> {
+    if let Some(language) = &language {
+        if !is_valid_language_tag(language) {
+            return Err(ConfigError::InvalidValue(format!(
+                "Invalid UI language tag '{}'",
+                language
+            )));
+        }
+    }
+
+    modify_config(|config| {
+        config.ui_language = language;
+    })
+}
+
+/// Adds a custom game process name to the configuration and persists it.
+///
+/// # Arguments
+/// * `name` - Process name to treat as the running game (e.g., "PZLauncher")
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue if the name is empty
+///
+/// # Behavior
+/// - Surrounding whitespace is trimmed
+/// - Adding a name that is already present is a no-op
+pub fn add_custom_process_name(name: String) -> ConfigResult<()> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(ConfigError::InvalidValue(
+            "Process name must not be empty".to_string()
+        ));
+    }
+
+    modify_config(|config| {
+        if !config.custom_game_process_names.contains(&name) {
+            config.custom_game_process_names.push(name);
+        }
+    })
+}
+
+/// Removes a custom game process name from the configuration and persists it.
+///
+/// # Arguments
+/// * `name` - Process name to remove
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success (also if the name was not present)
+pub fn remove_custom_process_name(name: String) -> ConfigResult<()> {
+    let name = name.trim();
+    modify_config(|config| {
+        config.custom_game_process_names.retain(|existing| existing != name);
+    })
 }
 
 /// Lists all save directories in the Zomboid saves folder.
@@ -410,6 +1259,17 @@ pub struct SaveEntry {
     /// Tags associated with this save
     #[serde(default)]
     pub tags: Vec<Tag>,
+    /// When the save was last played (RFC 3339): the newest mtime among the save's
+    /// top-level entries, or of every file in it when listed with
+    /// `list_save_entries_detailed(true)`; None if it couldn't be determined
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// Size of the save in bytes (0 unless listed with `list_save_entries_detailed(true)`)
+    #[serde(default)]
+    pub size_bytes: u64,
+    /// Human-readable `size_bytes` (empty unless sizes were requested)
+    #[serde(default)]
+    pub size_formatted: String,
 }
 
 impl SaveEntry {
@@ -425,6 +1285,18 @@ impl SaveEntry {
             save_name,
             relative_path,
             tags: Vec::new(),
+            last_modified: None,
+            size_bytes: 0,
+            size_formatted: String::new(),
+        }
+    }
+
+    /// Fills in `size_bytes` and `size_formatted` from the save directory, leaving them
+    /// at 0 if it can't be read.
+    fn set_size(&mut self, save_dir: &Path) {
+        if let Ok((bytes, formatted)) = crate::file_ops::get_dir_size_human(save_dir) {
+            self.size_bytes = bytes;
+            self.size_formatted = formatted;
         }
     }
 
@@ -440,6 +1312,44 @@ impl SaveEntry {
     pub fn full_path(&self, base_path: &Path) -> PathBuf {
         base_path.join(&self.relative_path)
     }
+
+    /// Checks that this save's directory still exists.
+    ///
+    /// # Arguments
+    /// * `base_path` - The Saves base path
+    ///
+    /// # Returns
+    /// `Result<(), FileOpsError>` - `SourceNotFound` if the save was moved or deleted,
+    /// `NotADirectory` if its path is now a file
+    pub fn validate(&self, base_path: &Path) -> Result<(), FileOpsError> {
+        let path = self.full_path(base_path);
+        if !path.exists() {
+            return Err(FileOpsError::SourceNotFound(path));
+        }
+        if !path.is_dir() {
+            return Err(FileOpsError::NotADirectory(path));
+        }
+        Ok(())
+    }
+}
+
+/// A scan result paired with the non-fatal errors encountered while producing it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanResult<T> {
+    /// The scanned value
+    pub value: T,
+    /// Errors for entries that were skipped (e.g. unreadable directories)
+    pub scan_errors: Vec<String>,
+}
+
+impl<T> ScanResult<T> {
+    /// Creates a ScanResult without errors.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            scan_errors: Vec::new(),
+        }
+    }
 }
 
 /// Lists all save entries with game mode information.
@@ -448,7 +1358,8 @@ impl SaveEntry {
 /// Also supports legacy flat structure for backwards compatibility.
 ///
 /// # Returns
-/// `ConfigResult<Vec<SaveEntry>>` - List of save entries with game mode info
+/// `ConfigResult<ScanResult<Vec<SaveEntry>>>` - List of save entries with game mode info,
+/// plus errors for directories that could not be read
 ///
 /// # Behavior
 /// 1. Scans all subdirectories in the Saves folder
@@ -458,30 +1369,64 @@ impl SaveEntry {
 ///    treats it as a flat save (legacy structure)
 /// 4. Returns sorted list (by game mode, then save name)
 ///
+/// A subdirectory that cannot be read (e.g. restricted permissions) is skipped
+/// and reported in `scan_errors`; only an unreadable Saves folder fails the scan.
+///
 /// # Example
 /// ```no_run
 /// use tauri_app_lib::config::list_save_entries;
 ///
-/// let entries = list_save_entries().unwrap();
-/// for entry in entries {
+/// let scan = list_save_entries().unwrap();
+/// for entry in scan.value {
 ///     println!("{}: {}", entry.game_mode, entry.save_name);
 /// }
+/// for error in scan.scan_errors {
+///     eprintln!("Skipped: {}", error);
+/// }
 /// ```
-pub fn list_save_entries() -> ConfigResult<Vec<SaveEntry>> {
+pub fn list_save_entries() -> ConfigResult<ScanResult<Vec<SaveEntry>>> {
+    list_save_entries_detailed(false)
+}
+
+/// Lists all save entries, optionally with the size of each save.
+///
+/// # Arguments
+/// * `include_sizes` - Fill in `size_bytes` and `size_formatted` for every save
+///
+/// # Returns
+/// `ConfigResult<ScanResult<Vec<SaveEntry>>>` - Same entries as `list_save_entries`
+///
+/// # Behavior
+/// Computing sizes walks every save directory, which is slow for large saves, so
+/// `list_save_entries` leaves them out. A save whose size can't be read is listed
+/// with size 0. The same walk gives the exact `last_modified`; without it only the
+/// save's top-level entries are looked at.
+pub fn list_save_entries_detailed(
+    include_sizes: bool,
+) -> ConfigResult<ScanResult<Vec<SaveEntry>>> {
     let config = load_config()?;
     let save_path = config.get_save_path()?;
 
-    if !save_path.exists() {
-        return Ok(Vec::new());
+    // A broken symlink (e.g. ~/Zomboid pointing at an unmounted partition) is
+    // reported by `check_save_path_health`, not as an I/O error here
+    if !save_path.exists() || is_broken_symlink(&save_path) {
+        return Ok(ScanResult::new(Vec::new()));
     }
 
     let mut entries = Vec::new();
+    let mut scan_errors = Vec::new();
 
     // Read all entries in the Saves directory
     for game_mode_entry in fs::read_dir(&save_path)
         .map_err(FileOpsError::Io)?
     {
-        let game_mode_entry = game_mode_entry.map_err(FileOpsError::Io)?;
+        let game_mode_entry = match game_mode_entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                scan_errors.push(format!("{}: {}", save_path.display(), e));
+                continue;
+            }
+        };
         let game_mode_path = game_mode_entry.path();
 
         // Only process directories
@@ -498,44 +1443,61 @@ pub fn list_save_entries() -> ConfigResult<Vec<SaveEntry>> {
         let mut has_save_subdirs = false;
         let mut has_save_files = false;
 
-        if let Ok(sub_entries) = fs::read_dir(&game_mode_path) {
-            for sub_entry in sub_entries {
-                let sub_entry = match sub_entry {
-                    Ok(e) => e,
-                    Err(_) => continue,
-                };
-                let sub_path = sub_entry.path();
-
-                if sub_path.is_dir() {
-                    // Check if this subdirectory looks like a save
-                    if looks_like_save_directory(&sub_path) {
-                        has_save_subdirs = true;
-                        let save_name = sub_path.file_name()
-                            .and_then(|n| n.to_str())
-                            .unwrap_or("")
-                            .to_string();
-                        let relative_path = if game_mode_name.is_empty() {
-                            save_name.clone()
-                        } else {
-                            format!("{}/{}", game_mode_name, save_name)
-                        };
-
-                        // Get tags for this save
-                        let tags = crate::tags::get_save_tags(&relative_path)
-                            .unwrap_or_default();
-
-                        entries.push(SaveEntry {
-                            game_mode: game_mode_name.clone(),
-                            save_name,
-                            relative_path,
-                            tags,
-                        });
-                    }
-                } else {
-                    // Check if this is a save file (map/*.bin or save.bin at root)
-                    if looks_like_save_file(&sub_path) {
-                        has_save_files = true;
+        let sub_entries = match fs::read_dir(&game_mode_path) {
+            Ok(sub_entries) => sub_entries,
+            Err(e) => {
+                // Keep scanning the other game modes
+                scan_errors.push(format!("{}: {}", game_mode_path.display(), e));
+                continue;
+            }
+        };
+
+        for sub_entry in sub_entries {
+            let sub_entry = match sub_entry {
+                Ok(e) => e,
+                Err(e) => {
+                    scan_errors.push(format!("{}: {}", game_mode_path.display(), e));
+                    continue;
+                }
+            };
+            let sub_path = sub_entry.path();
+
+            if sub_path.is_dir() {
+                // Check if this subdirectory looks like a save
+                if looks_like_save_directory(&sub_path) {
+                    has_save_subdirs = true;
+                    let save_name = sub_path.file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let relative_path = if game_mode_name.is_empty() {
+                        save_name.clone()
+                    } else {
+                        format!("{}/{}", game_mode_name, save_name)
+                    };
+
+                    // Get tags for this save
+                    let tags = crate::tags::get_save_tags(&relative_path)
+                        .unwrap_or_default();
+
+                    let mut entry = SaveEntry {
+                        game_mode: game_mode_name.clone(),
+                        save_name,
+                        relative_path,
+                        tags,
+                        last_modified: save_last_modified(&sub_path, include_sizes),
+                        size_bytes: 0,
+                        size_formatted: String::new(),
+                    };
+                    if include_sizes {
+                        entry.set_size(&sub_path);
                     }
+                    entries.push(entry);
+                }
+            } else {
+                // Check if this is a save file (map/*.bin or save.bin at root)
+                if looks_like_save_file(&sub_path) {
+                    has_save_files = true;
                 }
             }
         }
@@ -548,12 +1510,19 @@ pub fn list_save_entries() -> ConfigResult<Vec<SaveEntry>> {
                 let tags = crate::tags::get_save_tags(&game_mode_name)
                     .unwrap_or_default();
 
-                entries.push(SaveEntry {
+                let mut entry = SaveEntry {
                     game_mode: String::new(),
                     save_name: game_mode_name.clone(),
                     relative_path: game_mode_name.clone(),
                     tags,
-                });
+                    last_modified: save_last_modified(&game_mode_path, include_sizes),
+                    size_bytes: 0,
+                    size_formatted: String::new(),
+                };
+                if include_sizes {
+                    entry.set_size(&game_mode_path);
+                }
+                entries.push(entry);
             }
         }
     }
@@ -561,7 +1530,26 @@ pub fn list_save_entries() -> ConfigResult<Vec<SaveEntry>> {
     // Sort by game mode, then by save name
     entries.sort();
 
-    Ok(entries)
+    Ok(ScanResult { value: entries, scan_errors })
+}
+
+/// Returns when a save directory was last written as RFC 3339, or None if it's empty
+/// or can't be read.
+///
+/// With `walk` the newest mtime of any file in the save is used. Otherwise only the
+/// top-level entries are read: the game rewrites `map_p.bin` and the `.db` files on
+/// every save, and adding chunk files bumps the mtime of `map`, so that is close
+/// enough for a listing without walking every chunk.
+fn save_last_modified(save_dir: &Path, walk: bool) -> Option<String> {
+    let modified = if walk {
+        crate::file_ops::get_dir_last_modified(save_dir).ok()??
+    } else {
+        fs::read_dir(save_dir)
+            .ok()?
+            .filter_map(|entry| entry.ok()?.metadata().ok()?.modified().ok())
+            .max()?
+    };
+    Some(chrono::DateTime::<chrono::Utc>::from(modified).to_rfc3339())
 }
 
 /// Checks if a directory looks like a Project Zomboid save directory.
@@ -570,7 +1558,7 @@ pub fn list_save_entries() -> ConfigResult<Vec<SaveEntry>> {
 /// - A `map` subdirectory with `.bin` or `.dat` files
 /// - Or `save.bin` / `map_p.bin` files at the root
 fn looks_like_save_directory(path: &Path) -> bool {
-    if !path.is_dir() {
+    if is_broken_symlink(path) || !path.is_dir() {
         return false;
     }
 
@@ -613,6 +1601,129 @@ fn looks_like_save_directory(path: &Path) -> bool {
     false
 }
 
+/// Checks whether `path` is a symbolic link whose target does not exist.
+///
+/// `Path::exists` and `Path::is_dir` follow links and cannot tell a broken link
+/// from a missing path, so the link itself is inspected with `symlink_metadata`.
+fn is_broken_symlink(path: &Path) -> bool {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata.file_type().is_symlink() && fs::metadata(path).is_err(),
+        Err(_) => false,
+    }
+}
+
+/// A problem with the configured paths that the frontend should warn about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "path", rename_all = "snake_case")]
+pub enum HealthIssue {
+    /// The save path is a symbolic link whose target does not exist
+    BrokenSavePathSymlink(String),
+}
+
+/// Checks the configured save path for problems that make saves disappear silently.
+///
+/// # Returns
+/// `ConfigResult<Vec<HealthIssue>>` - Issues found (empty if everything looks fine)
+pub fn check_save_path_health() -> ConfigResult<Vec<HealthIssue>> {
+    let config = load_config()?;
+    let save_path = config.get_save_path()?;
+
+    let mut issues = Vec::new();
+    if is_broken_symlink(&save_path) {
+        issues.push(HealthIssue::BrokenSavePathSymlink(
+            save_path.to_string_lossy().to_string(),
+        ));
+    }
+
+    Ok(issues)
+}
+
+/// Whether the configured save and backup paths exist, for detecting stale configs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigPathsValidation {
+    /// Whether the effective save path is an existing directory
+    pub save_path_exists: bool,
+    /// Effective save path (configured or detected)
+    pub save_path_value: Option<String>,
+    /// Whether the effective backup path is an existing directory
+    pub backup_path_exists: bool,
+    /// Effective backup path (configured or default)
+    pub backup_path_value: Option<String>,
+    /// Existing Zomboid save directories to offer when the save path is missing
+    pub suggestions: Vec<String>,
+}
+
+impl ConfigPathsValidation {
+    /// Returns true if both paths exist.
+    pub fn is_valid(&self) -> bool {
+        self.save_path_exists && self.backup_path_exists
+    }
+}
+
+/// Returns the existing Zomboid save directories found in the usual install locations.
+///
+/// # Returns
+/// `Vec<PathBuf>` - Candidates that exist, without duplicates
+///
+/// # Platform Behavior
+/// - **All platforms**: `~/Zomboid/Saves`
+/// - **Linux**: also the Flatpak Steam home, `~/.var/app/com.valvesoftware.Steam/Zomboid/Saves`
+pub fn detect_all_zomboid_save_candidates() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Ok(path) = detect_zomboid_save_path() {
+        candidates.push(path);
+    }
+
+    #[cfg(target_os = "linux")]
+    if let Some(home) = dirs::home_dir() {
+        candidates.push(
+            home.join(".var/app/com.valvesoftware.Steam")
+                .join("Zomboid")
+                .join("Saves"),
+        );
+    }
+
+    let mut existing: Vec<PathBuf> = Vec::new();
+    for candidate in candidates {
+        if candidate.is_dir() && !existing.contains(&candidate) {
+            existing.push(candidate);
+        }
+    }
+    existing
+}
+
+/// Checks whether the configured save and backup paths still exist.
+///
+/// # Returns
+/// `ConfigResult<ConfigPathsValidation>` - Existence of both paths, plus suggested
+/// save paths if the save path is missing (e.g. after moving the Zomboid installation)
+pub fn validate_config_paths() -> ConfigResult<ConfigPathsValidation> {
+    let config = load_config()?;
+    let save_path = config.get_save_path().ok();
+    let backup_path = config.get_backup_path().ok();
+
+    let save_path_exists = save_path.as_ref().is_some_and(|path| path.is_dir());
+    let backup_path_exists = backup_path.as_ref().is_some_and(|path| path.is_dir());
+
+    let suggestions = if save_path_exists {
+        Vec::new()
+    } else {
+        detect_all_zomboid_save_candidates()
+            .into_iter()
+            .filter(|candidate| Some(candidate) != save_path.as_ref())
+            .map(|candidate| candidate.to_string_lossy().to_string())
+            .collect()
+    };
+
+    Ok(ConfigPathsValidation {
+        save_path_exists,
+        save_path_value: save_path.map(|path| path.to_string_lossy().to_string()),
+        backup_path_exists,
+        backup_path_value: backup_path.map(|path| path.to_string_lossy().to_string()),
+        suggestions,
+    })
+}
+
 /// Checks if a file looks like a Project Zomboid save file.
 fn looks_like_save_file(path: &Path) -> bool {
     if !path.is_file() {
@@ -624,14 +1735,126 @@ fn looks_like_save_file(path: &Path) -> bool {
         None => return false,
     };
 
-    // Check for common save file patterns
-    name.ends_with(".bin") || name == "map_p.bin" || name == "save.bin"
+    // Check for common save file patterns
+    name.ends_with(".bin") || name == "map_p.bin" || name == "save.bin"
+}
+
+/// In-memory cache of the latest `list_save_entries` result.
+///
+/// Managed as Tauri state so that frontend polling doesn't rescan the saves
+/// directory on every call. Entries expire after `CACHE_TTL_SECS` and the cache
+/// must be invalidated whenever the save path or save tags change.
+#[derive(Debug, Clone, Default)]
+pub struct SaveListCache(Arc<RwLock<CachedSaveList>>);
+
+/// Save entries together with the time they were loaded.
+type CachedSaveList = Option<(Instant, Vec<SaveEntry>)>;
+
+impl SaveListCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached save list, calling `load` if it is missing or expired.
+    ///
+    /// # Arguments
+    /// * `load` - Function that scans the filesystem (normally `list_save_entries`)
+    ///
+    /// # Returns
+    /// `ConfigResult<Vec<SaveEntry>>` - Cached or freshly loaded entries; errors are not cached
+    pub fn get_or_load<F>(&self, load: F) -> ConfigResult<Vec<SaveEntry>>
+    where
+        F: FnOnce() -> ConfigResult<Vec<SaveEntry>>,
+    {
+        self.get_or_load_within(Duration::from_secs(CACHE_TTL_SECS), load)
+    }
+
+    fn get_or_load_within<F>(&self, ttl: Duration, load: F) -> ConfigResult<Vec<SaveEntry>>
+    where
+        F: FnOnce() -> ConfigResult<Vec<SaveEntry>>,
+    {
+        if let Ok(guard) = self.0.read() {
+            if let Some((loaded_at, entries)) = guard.as_ref() {
+                if loaded_at.elapsed() < ttl {
+                    return Ok(entries.clone());
+                }
+            }
+        }
+
+        let entries = load()?;
+        if let Ok(mut guard) = self.0.write() {
+            *guard = Some((Instant::now(), entries.clone()));
+        }
+        Ok(entries)
+    }
+
+    /// Drops the cached save list so the next call rescans the filesystem.
+    pub fn invalidate(&self) {
+        if let Ok(mut guard) = self.0.write() {
+            *guard = None;
+        }
+    }
+}
+
+/// Lists the save names accepted by backup and restore functions.
+///
+/// # Returns
+/// `ConfigResult<Vec<String>>` - The `relative_path` of every save entry, e.g.
+/// `"Survival/MySave"` for two-level saves and `"OldSave"` for flat saves
+///
+/// # Example
+/// ```no_run
+/// use tauri_app_lib::config::get_all_save_names_for_backup;
+///
+/// for save_name in get_all_save_names_for_backup().unwrap() {
+///     println!("{}", save_name);
+/// }
+/// ```
+pub fn get_all_save_names_for_backup() -> ConfigResult<Vec<String>> {
+    Ok(list_save_entries()?
+        .value
+        .into_iter()
+        .map(|entry| entry.relative_path)
+        .collect())
+}
+
+/// Lists save entries whose directories still exist.
+///
+/// # Returns
+/// `ConfigResult<Vec<SaveEntry>>` - Entries from `list_save_entries` that pass `SaveEntry::validate`
+pub fn list_valid_save_entries() -> ConfigResult<Vec<SaveEntry>> {
+    let save_path = load_config()?.get_save_path()?;
+    Ok(list_save_entries()?
+        .value
+        .into_iter()
+        .filter(|entry| entry.validate(&save_path).is_ok())
+        .collect())
+}
+
+/// Checks whether a save still exists in the configured saves directory.
+///
+/// # Arguments
+/// * `game_mode` - Game mode of the save (empty for legacy flat saves)
+/// * `save_name` - Save folder name
+///
+/// # Returns
+/// `ConfigResult<bool>` - true if the save directory exists, false if it was moved or deleted
+pub fn validate_save_entry(game_mode: &str, save_name: &str) -> ConfigResult<bool> {
+    let save_path = load_config()?.get_save_path()?;
+    let entry = SaveEntry::new(game_mode.to_string(), save_name.to_string());
+    match entry.validate(&save_path) {
+        Ok(()) => Ok(true),
+        Err(FileOpsError::SourceNotFound(_)) | Err(FileOpsError::NotADirectory(_)) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
 }
 
 /// Gets save entries grouped by game mode.
 ///
 /// # Returns
-/// `ConfigResult<std::collections::HashMap<String, Vec<SaveEntry>>>` - Map of game mode to save entries
+/// `ConfigResult<std::collections::BTreeMap<String, Vec<SaveEntry>>>` - Map of game mode to save entries,
+/// ordered by game mode name so the UI renders groups consistently
 ///
 /// # Example
 /// ```no_run
@@ -642,9 +1865,17 @@ fn looks_like_save_file(path: &Path) -> bool {
 ///     println!("{}: {} saves", game_mode, saves.len());
 /// }
 /// ```
-pub fn list_save_entries_by_game_mode() -> ConfigResult<std::collections::HashMap<String, Vec<SaveEntry>>> {
-    let entries = list_save_entries()?;
-    let mut grouped: std::collections::HashMap<String, Vec<SaveEntry>> = std::collections::HashMap::new();
+pub fn list_save_entries_by_game_mode() -> ConfigResult<std::collections::BTreeMap<String, Vec<SaveEntry>>> {
+    Ok(group_save_entries_by_game_mode(list_save_entries()?.value))
+}
+
+/// Groups save entries by game mode.
+///
+/// Saves without a game mode (legacy flat structure) are grouped under "(Other)".
+pub fn group_save_entries_by_game_mode(
+    entries: Vec<SaveEntry>,
+) -> std::collections::BTreeMap<String, Vec<SaveEntry>> {
+    let mut grouped: std::collections::BTreeMap<String, Vec<SaveEntry>> = std::collections::BTreeMap::new();
 
     for entry in entries {
         // Use "(Other)" with parentheses to avoid collision with actual game mode named "Other"
@@ -657,7 +1888,7 @@ pub fn list_save_entries_by_game_mode() -> ConfigResult<std::collections::HashMa
         grouped.entry(game_mode).or_insert_with(Vec::new).push(entry);
     }
 
-    Ok(grouped)
+    grouped
 }
 
 #[cfg(test)]
@@ -674,7 +1905,139 @@ mod tests {
 
         assert!(config.save_path.is_none());
         assert!(config.backup_path.is_none());
-        assert_eq!(config.retention_count, DEFAULT_RETENTION_COUNT);
+        assert_eq!(config.retention_count, RetentionMode::Limited(DEFAULT_RETENTION_COUNT));
+    }
+
+    #[test]
+    fn test_with_defaults_for_platform_detects_paths() {
+        if dirs::home_dir().is_none() {
+            return;
+        }
+
+        let config = Config::with_defaults_for_platform();
+        assert_eq!(
+            config.save_path.map(PathBuf::from),
+            Some(detect_zomboid_save_path().unwrap())
+        );
+        assert_eq!(
+            config.backup_path.map(PathBuf::from),
+            Some(get_default_backup_path().unwrap())
+        );
+        assert!(config.first_run);
+    }
+
+    #[test]
+    fn test_apply_detected_paths_keeps_user_values() {
+        if dirs::home_dir().is_none() {
+            return;
+        }
+
+        let mut config = Config::with_save_path("/custom/saves".to_string());
+        let filled = config.apply_detected_paths();
+
+        assert_eq!(filled, vec!["backup_path".to_string()]);
+        assert_eq!(config.save_path.as_deref(), Some("/custom/saves"));
+        assert!(config.backup_path.is_some());
+        assert!(config.apply_detected_paths().is_empty());
+    }
+
+    #[test]
+    fn test_config_default_is_first_run() {
+        assert!(Config::default().first_run);
+
+        // Existing config files without the field belong to returning users
+        let parsed: Config = serde_json::from_value(serde_json::json!({
+            "save_path": null,
+            "backup_path": null,
+            "retention_count": 10
+        }))
+        .unwrap();
+        assert!(!parsed.first_run);
+    }
+
+    #[test]
+    #[serial]
+    fn test_mark_first_run_complete() {
+        save_config(&Config::default()).unwrap();
+        assert!(load_config().unwrap().first_run);
+
+        mark_first_run_complete().unwrap();
+        assert!(!load_config().unwrap().first_run);
+    }
+
+    #[test]
+    #[serial]
+    fn test_record_last_backup_time_concurrently() {
+        save_config(&Config::default()).unwrap();
+
+        // Backups of different saves finishing together must not drop each other's time
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    let timestamp = format!("2024-12-28T10:00:0{}+00:00", i);
+                    record_last_backup_time(&format!("Save{}", i), &timestamp).unwrap();
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let config = load_config().unwrap();
+        assert_eq!(config.last_backup_per_save.len(), 8);
+        assert!(config.last_backup_at.is_some());
+    }
+
+    #[test]
+    fn test_config_merge() {
+        let base = Config {
+            retention_count: RetentionMode::Limited(5),
+            exclude_patterns: vec!["*.log".to_string()],
+            proxy_url: Some("http://proxy:3128".to_string()),
+            ..Config::with_paths("/saves".to_string(), "/backups".to_string())
+        };
+        let overrides = Config {
+            backup_path: Some("/ci/backups".to_string()),
+            compression_level: 9,
+            exclude_patterns: vec!["*.log".to_string(), "*.tmp".to_string()],
+            ..Config::default()
+        };
+
+        let merged = Config::merge(&base, &overrides);
+        assert_eq!(merged.save_path.as_deref(), Some("/saves"));
+        assert_eq!(merged.backup_path.as_deref(), Some("/ci/backups"));
+        assert_eq!(merged.proxy_url.as_deref(), Some("http://proxy:3128"));
+        // Fields left at their default in the overrides keep the base value
+        assert_eq!(merged.retention_count, RetentionMode::Limited(5));
+        assert_eq!(merged.compression_level, 9);
+        assert_eq!(merged.exclude_patterns, vec!["*.log", "*.tmp"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_config_with_env_overrides() {
+        let config = Config {
+            retention_count: RetentionMode::Limited(5),
+            ..Config::with_paths("/saves".to_string(), "/backups".to_string())
+        };
+        save_config(&config).unwrap();
+
+        std::env::set_var(ENV_BACKUP_PATH, "/ci/backups");
+        std::env::set_var(ENV_RETENTION, "0");
+        let loaded = load_config_with_env_overrides();
+        std::env::set_var(ENV_RETENTION, "many");
+        let invalid = load_config_with_env_overrides();
+        std::env::remove_var(ENV_BACKUP_PATH);
+        std::env::remove_var(ENV_RETENTION);
+
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.save_path.as_deref(), Some("/saves"));
+        assert_eq!(loaded.backup_path.as_deref(), Some("/ci/backups"));
+        assert_eq!(loaded.retention_count, RetentionMode::Unlimited);
+        assert!(matches!(invalid, Err(ConfigError::InvalidValue(_))));
+
+        // The file itself is unchanged
+        assert_eq!(load_config().unwrap().backup_path.as_deref(), Some("/backups"));
     }
 
     #[test]
@@ -684,7 +2047,7 @@ mod tests {
 
         assert_eq!(config.save_path, Some(save_path));
         assert!(config.backup_path.is_none());
-        assert_eq!(config.retention_count, DEFAULT_RETENTION_COUNT);
+        assert_eq!(config.retention_count, RetentionMode::Limited(DEFAULT_RETENTION_COUNT));
     }
 
     #[test]
@@ -695,7 +2058,7 @@ mod tests {
 
         assert_eq!(config.save_path, Some(save_path));
         assert_eq!(config.backup_path, Some(backup_path));
-        assert_eq!(config.retention_count, DEFAULT_RETENTION_COUNT);
+        assert_eq!(config.retention_count, RetentionMode::Limited(DEFAULT_RETENTION_COUNT));
     }
 
     #[test]
@@ -731,6 +2094,82 @@ mod tests {
         assert!(path.ends_with("ZomboidBackups"));
     }
 
+    #[test]
+    #[serial]
+    fn test_export_and_import_config() {
+        let save_dir = TempDir::new().unwrap();
+        let export_dir = TempDir::new().unwrap();
+        let exported = export_dir.path().join("nested/config.json");
+
+        let original = Config {
+            retention_count: RetentionMode::Limited(7),
+            ..Config::with_paths(save_dir.path().to_str().unwrap().to_string(), "/b".to_string())
+        };
+        save_config(&original).unwrap();
+        export_config(&exported).unwrap();
+
+        save_config(&Config::default()).unwrap();
+        let imported = import_config(&exported).unwrap();
+        assert_eq!(imported.retention_count, RetentionMode::Limited(7));
+        assert_eq!(load_config().unwrap().save_path, original.save_path);
+
+        // Unknown fields and malformed files are rejected without touching the config
+        let mut value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&exported).unwrap()).unwrap();
+        value["cloud_sync"] = serde_json::Value::Bool(true);
+        let newer = export_dir.path().join("newer.json");
+        fs::write(&newer, value.to_string()).unwrap();
+        let garbage = export_dir.path().join("garbage.json");
+        fs::write(&garbage, "[1, 2]").unwrap();
+
+        save_config(&Config::default()).unwrap();
+        let result = import_config(&newer);
+        assert!(
+            matches!(&result, Err(ConfigError::InvalidValue(msg)) if msg.contains("cloud_sync"))
+        );
+        assert!(matches!(import_config(&garbage), Err(ConfigError::InvalidValue(_))));
+        assert_eq!(load_config().unwrap().retention_count, Config::default().retention_count);
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_config_migrates_v0_file() {
+        let _ = load_config();
+        let config_path = get_config_file_path().unwrap();
+        fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        // A config written before `config_version` (and most other fields) existed
+        fs::write(
+            &config_path,
+            r#"{"save_path": "/saves", "backup_path": "/backups", "retention_count": 3}"#,
+        )
+        .unwrap();
+
+        let config = load_config().unwrap();
+        assert_eq!(config.config_version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.save_path.as_deref(), Some("/saves"));
+        assert_eq!(config.retention_count, RetentionMode::Limited(3));
+        assert!(config.auto_check_updates);
+        assert_eq!(config.compression_level, DEFAULT_COMPRESSION_LEVEL);
+        assert!(!config.first_run);
+
+        // Saving writes the current version back
+        save_config(&Config { config_version: 0, ..config }).unwrap();
+        let raw: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(raw["config_version"], CURRENT_CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_config_rejects_newer_version() {
+        let raw = serde_json::json!({
+            "save_path": null,
+            "backup_path": null,
+            "retention_count": 10,
+            "config_version": CURRENT_CONFIG_VERSION + 1,
+        });
+        assert!(matches!(migrate_config(raw), Err(ConfigError::InvalidValue(_))));
+    }
+
     #[test]
     fn test_save_and_load_config() {
         let _temp_dir = TempDir::new().unwrap();
@@ -739,10 +2178,11 @@ mod tests {
         let original = Config {
             save_path: Some("/test/saves".to_string()),
             backup_path: Some("/test/backups".to_string()),
-            retention_count: 15,
+            retention_count: RetentionMode::Limited(15),
             auto_check_updates: true,
             last_update_check: None,
             last_selected_save: None,
+            ..Default::default()
         };
 
         // Serialize to JSON
@@ -772,9 +2212,178 @@ mod tests {
     }
 
     #[test]
-    fn test_update_retention_count_zero_fails() {
-        let result = update_retention_count(0);
-        assert!(result.is_err());
+    #[serial]
+    fn test_update_retention_count_zero_is_unlimited() {
+        save_config(&Config::default()).unwrap();
+
+        update_retention_count(0).unwrap();
+        assert_eq!(load_config().unwrap().retention_count, RetentionMode::Unlimited);
+
+        update_retention_count(7).unwrap();
+        assert_eq!(load_config().unwrap().retention_count, RetentionMode::Limited(7));
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_retention_count_rejects_above_max() {
+        save_config(&Config::default()).unwrap();
+
+        update_retention_count(MAX_RETENTION_COUNT).unwrap();
+        let result = update_retention_count(MAX_RETENTION_COUNT + 1);
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+        assert_eq!(
+            load_config().unwrap().retention_count,
+            RetentionMode::Limited(MAX_RETENTION_COUNT)
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_estimate_retention_disk_usage() {
+        let backup_base = TempDir::new().unwrap();
+        save_config(&Config::with_paths(
+            "/saves".to_string(),
+            backup_base.path().to_str().unwrap().to_string(),
+        ))
+        .unwrap();
+        assert_eq!(estimate_retention_disk_usage("Survival", 10).unwrap(), 0);
+
+        let save_backup_dir = backup_base.path().join("Survival");
+        fs::create_dir_all(&save_backup_dir).unwrap();
+        fs::write(save_backup_dir.join("2024-12-28_14-30-45.tar.gz"), [0u8; 100]).unwrap();
+        fs::write(save_backup_dir.join("2024-12-29_14-30-45.zip"), [0u8; 300]).unwrap();
+        // Sidecar files don't count as backups
+        fs::write(save_backup_dir.join("2024-12-29_14-30-45.zip.sha256"), [0u8; 64]).unwrap();
+
+        assert_eq!(estimate_retention_disk_usage("Survival", 10).unwrap(), 2000);
+        assert_eq!(estimate_retention_disk_usage("Survival", 0).unwrap(), 400);
+    }
+
+    #[test]
+    fn test_retention_mode_serializes_as_count() {
+        let config = Config {
+            retention_count: RetentionMode::Unlimited,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["retention_count"], 0);
+
+        let parsed: Config = serde_json::from_value(serde_json::json!({
+            "save_path": null,
+            "backup_path": null,
+            "retention_count": 0
+        }))
+        .unwrap();
+        assert_eq!(parsed.retention_count, RetentionMode::Unlimited);
+    }
+
+    #[test]
+    fn test_update_io_rate_limit_zero_fails() {
+        let result = update_io_rate_limit(Some(0));
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_io_rate_limit() {
+        save_config(&Config::default()).unwrap();
+
+        update_io_rate_limit(Some(2 * 1024 * 1024)).unwrap();
+        assert_eq!(load_config().unwrap().max_io_bytes_per_sec, Some(2 * 1024 * 1024));
+
+        update_io_rate_limit(None).unwrap();
+        assert_eq!(load_config().unwrap().max_io_bytes_per_sec, None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_ui_theme() {
+        save_config(&Config::default()).unwrap();
+
+        let result = update_ui_theme(Some("neon".to_string()));
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+        assert_eq!(load_config().unwrap().ui_theme, None);
+
+        update_ui_theme(Some("dark".to_string())).unwrap();
+        assert_eq!(load_config().unwrap().ui_theme.as_deref(), Some("dark"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_ui_language() {
+        save_config(&Config::default()).unwrap();
+
+        for invalid in ["", "e", "en_US", "en-", "toolonglanguage"] {
+            let result = update_ui_language(Some(invalid.to_string()));
+            assert!(matches!(result, Err(ConfigError::InvalidValue(_))), "{}", invalid);
+        }
+
+        update_ui_language(Some("zh-CN".to_string())).unwrap();
+        assert_eq!(load_config().unwrap().ui_language.as_deref(), Some("zh-CN"));
+
+        update_ui_language(None).unwrap();
+        assert_eq!(load_config().unwrap().ui_language, None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[serial]
+    fn test_get_config_dir_respects_xdg_config_home() {
+        let original = std::env::var_os("XDG_CONFIG_HOME");
+        let temp_dir = TempDir::new().unwrap();
+
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+        let custom = get_config_dir();
+        std::env::set_var("XDG_CONFIG_HOME", "relative/path");
+        let relative = get_config_dir();
+
+        match original {
+            Some(value) => std::env::set_var("XDG_CONFIG_HOME", value),
+            None => std::env::remove_var("XDG_CONFIG_HOME"),
+        }
+
+        assert_eq!(custom.unwrap(), temp_dir.path().join("ZomboidBackupTool"));
+        assert_ne!(relative.unwrap(), Path::new("relative/path/ZomboidBackupTool"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_and_load_large_config_round_trip() {
+        let config = Config {
+            exclude_patterns: (0..100).map(|i| format!("Survival/Save{}/*.log", i)).collect(),
+            custom_game_process_names: (0..100).map(|i| format!("launcher_{}", i)).collect(),
+            last_selected_save: Some("Survival/Save42".to_string()),
+            ..Default::default()
+        };
+
+        save_config(&config).unwrap();
+        let loaded = load_config().unwrap();
+
+        assert_eq!(loaded.exclude_patterns, config.exclude_patterns);
+        assert_eq!(loaded.custom_game_process_names, config.custom_game_process_names);
+        assert_eq!(loaded.last_selected_save, config.last_selected_save);
+
+        // Still written as formatted JSON
+        let content = fs::read_to_string(get_config_file_path().unwrap()).unwrap();
+        assert!(content.contains("\n  \"exclude_patterns\": ["));
+    }
+
+    #[test]
+    #[serial]
+    fn test_add_and_remove_custom_process_name() {
+        save_config(&Config::default()).unwrap();
+
+        add_custom_process_name(" PZLauncher ".to_string()).unwrap();
+        add_custom_process_name("PZLauncher".to_string()).unwrap();
+        assert_eq!(load_config().unwrap().custom_game_process_names, vec!["PZLauncher"]);
+
+        remove_custom_process_name("PZLauncher".to_string()).unwrap();
+        assert!(load_config().unwrap().custom_game_process_names.is_empty());
+
+        assert!(matches!(
+            add_custom_process_name("   ".to_string()),
+            Err(ConfigError::InvalidValue(_))
+        ));
     }
 
     #[test]
@@ -877,10 +2486,11 @@ mod tests {
         let config = Config {
             save_path: Some(saves_dir.to_str().unwrap().to_string()),
             backup_path: Some(backup_file.to_str().unwrap().to_string()),
-            retention_count: 10,
+            retention_count: RetentionMode::Limited(10),
             auto_check_updates: true,
             last_update_check: None,
             last_selected_save: None,
+            ..Default::default()
         };
 
         let result = config.validate();
@@ -922,6 +2532,44 @@ mod tests {
         assert_eq!(full, Path::new("/home/user/Zomboid/Saves/Survival/MySave"));
     }
 
+    #[test]
+    fn test_save_entry_validate() {
+        let temp_dir = TempDir::new().unwrap();
+        let entry = SaveEntry::new("Survival".to_string(), "MySave".to_string());
+        let save_dir = entry.full_path(temp_dir.path());
+        create_test_save_structure(&save_dir);
+        assert!(entry.validate(temp_dir.path()).is_ok());
+
+        fs::remove_dir_all(&save_dir).unwrap();
+        assert!(matches!(
+            entry.validate(temp_dir.path()),
+            Err(FileOpsError::SourceNotFound(path)) if path == save_dir
+        ));
+
+        fs::write(&save_dir, b"not a directory").unwrap();
+        assert!(matches!(
+            entry.validate(temp_dir.path()),
+            Err(FileOpsError::NotADirectory(_))
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_validate_save_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        create_test_save_structure(&saves_dir.join("Survival").join("Save1"));
+        let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        assert!(validate_save_entry("Survival", "Save1").unwrap());
+        assert_eq!(list_valid_save_entries().unwrap().len(), 1);
+
+        fs::remove_dir_all(saves_dir.join("Survival").join("Save1")).unwrap();
+        assert!(!validate_save_entry("Survival", "Save1").unwrap());
+        assert!(list_valid_save_entries().unwrap().is_empty());
+    }
+
     #[test]
     fn test_save_entry_serialization() {
         let entry = SaveEntry::new("Survival".to_string(), "MySave".to_string());
@@ -967,7 +2615,7 @@ mod tests {
         let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
         save_config(&config).unwrap();
 
-        let entries = list_save_entries().unwrap();
+        let entries = list_save_entries().unwrap().value;
 
         assert_eq!(entries.len(), 2);
 
@@ -975,6 +2623,14 @@ mod tests {
         let survival_entry = entries.iter().find(|e| e.game_mode == "Survival").unwrap();
         assert_eq!(survival_entry.save_name, "MySurvival");
         assert_eq!(survival_entry.relative_path, "Survival/MySurvival");
+        let last_modified = survival_entry.last_modified.as_deref().unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(last_modified).is_ok());
+        // Sizes are only computed when asked for
+        assert_eq!(survival_entry.size_bytes, 0);
+        let detailed = list_save_entries_detailed(true).unwrap().value;
+        let survival_entry = detailed.iter().find(|e| e.game_mode == "Survival").unwrap();
+        assert!(survival_entry.size_bytes > 0);
+        assert!(!survival_entry.size_formatted.is_empty());
 
         // Check Builder save
         let builder_entry = entries.iter().find(|e| e.game_mode == "Builder").unwrap();
@@ -997,7 +2653,7 @@ mod tests {
         let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
         save_config(&config).unwrap();
 
-        let entries = list_save_entries().unwrap();
+        let entries = list_save_entries().unwrap().value;
 
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].game_mode, "");
@@ -1005,6 +2661,32 @@ mod tests {
         assert_eq!(entries[0].relative_path, "OldSave");
     }
 
+    #[test]
+    #[serial]
+    fn test_list_save_entries_last_modified_walks_only_when_detailed() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        let save_dir = saves_dir.join("Survival").join("MySave");
+        create_test_save_structure(&save_dir);
+
+        // Only the chunk inside `map` is newer than the top-level entries
+        let now = std::time::SystemTime::now();
+        let top_level = now - std::time::Duration::from_secs(7200);
+        let chunk = now - std::time::Duration::from_secs(3600);
+        File::open(save_dir.join("save.bin")).unwrap().set_modified(top_level).unwrap();
+        File::open(save_dir.join("map/pchunk_0_0.dat")).unwrap().set_modified(chunk).unwrap();
+        File::open(save_dir.join("map")).unwrap().set_modified(top_level).unwrap();
+
+        let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        let rfc3339 = |time| chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339();
+        let listed = list_save_entries().unwrap().value;
+        assert_eq!(listed[0].last_modified, Some(rfc3339(top_level)));
+        let detailed = list_save_entries_detailed(true).unwrap().value;
+        assert_eq!(detailed[0].last_modified, Some(rfc3339(chunk)));
+    }
+
     #[test]
     #[serial]
     fn test_list_save_entries_mixed_structure() {
@@ -1024,7 +2706,7 @@ mod tests {
         let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
         save_config(&config).unwrap();
 
-        let entries = list_save_entries().unwrap();
+        let entries = list_save_entries().unwrap().value;
 
         assert_eq!(entries.len(), 2);
 
@@ -1037,6 +2719,25 @@ mod tests {
         assert_eq!(nested_entry.game_mode, "Survival");
     }
 
+    #[test]
+    #[serial]
+    fn test_get_all_save_names_for_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        fs::create_dir(&saves_dir).unwrap();
+
+        create_test_save_structure(&saves_dir.join("OldSave"));
+        create_test_save_structure(&saves_dir.join("Survival").join("MySave"));
+
+        let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        let names = get_all_save_names_for_backup().unwrap();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"OldSave".to_string()));
+        assert!(names.contains(&"Survival/MySave".to_string()));
+    }
+
     #[test]
     #[serial]
     fn test_list_save_entries_nonexistent_path() {
@@ -1046,10 +2747,91 @@ mod tests {
         let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
         save_config(&config).unwrap();
 
-        let entries = list_save_entries().unwrap();
+        let entries = list_save_entries().unwrap().value;
         assert_eq!(entries.len(), 0);
     }
 
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn test_list_save_entries_broken_symlink() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_link = temp_dir.path().join("Saves");
+        std::os::unix::fs::symlink(temp_dir.path().join("missing"), &saves_link).unwrap();
+
+        let config = Config::with_save_path(saves_link.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        let entries = list_save_entries().unwrap().value;
+        assert!(entries.is_empty());
+
+        let issues = check_save_path_health().unwrap();
+        assert_eq!(
+            issues,
+            vec![HealthIssue::BrokenSavePathSymlink(
+                saves_link.to_string_lossy().to_string()
+            )]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn test_list_save_entries_skips_unreadable_directories() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        create_test_save_structure(&saves_dir.join("Survival").join("Save1"));
+        let locked = saves_dir.join("Locked");
+        fs::create_dir_all(locked.join("Save2")).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+
+        // Permission bits don't apply to root, so there is nothing to test
+        if fs::read_dir(&locked).is_ok() {
+            fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+            return;
+        }
+
+        let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        let scan = list_save_entries().unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert_eq!(scan.value.len(), 1);
+        assert_eq!(scan.value[0].relative_path, "Survival/Save1");
+        assert_eq!(scan.scan_errors.len(), 1);
+        assert!(scan.scan_errors[0].contains("Locked"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_validate_config_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        let backups_dir = temp_dir.path().join("Backups");
+        fs::create_dir(&backups_dir).unwrap();
+
+        let config = Config::with_paths(
+            saves_dir.to_str().unwrap().to_string(),
+            backups_dir.to_str().unwrap().to_string(),
+        );
+        save_config(&config).unwrap();
+
+        let validation = validate_config_paths().unwrap();
+        assert!(!validation.save_path_exists);
+        assert_eq!(validation.save_path_value, Some(saves_dir.to_string_lossy().to_string()));
+        assert!(validation.backup_path_exists);
+        assert!(!validation.is_valid());
+        assert!(!validation.suggestions.contains(&saves_dir.to_string_lossy().to_string()));
+
+        fs::create_dir(&saves_dir).unwrap();
+        let validation = validate_config_paths().unwrap();
+        assert!(validation.is_valid());
+        assert!(validation.suggestions.is_empty());
+    }
+
     #[test]
     #[serial]
     fn test_list_save_entries_by_game_mode() {
@@ -1076,6 +2858,62 @@ mod tests {
         assert_eq!(grouped.get("Builder").unwrap().len(), 1);
     }
 
+    #[test]
+    #[serial]
+    fn test_list_save_entries_by_game_mode_sorted_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        fs::create_dir(&saves_dir).unwrap();
+
+        for mode in ["Survival", "Apocalypse", "Sandbox", "Builder"] {
+            create_test_save_structure(&saves_dir.join(mode).join("Save1"));
+        }
+
+        let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        let grouped = list_save_entries_by_game_mode().unwrap();
+        let keys: Vec<_> = grouped.keys().collect();
+        assert_eq!(keys, vec!["Apocalypse", "Builder", "Sandbox", "Survival"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_list_cache_serves_cached_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let saves_dir = temp_dir.path().join("Saves");
+        create_test_save_structure(&saves_dir.join("Survival").join("Save1"));
+
+        let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
+        save_config(&config).unwrap();
+
+        let cache = SaveListCache::new();
+        let first = cache.get_or_load(|| Ok(list_save_entries()?.value)).unwrap();
+        assert_eq!(first.len(), 1);
+
+        // Removing the saves makes a rescan return nothing, so a hit must come from the cache
+        fs::remove_dir_all(&saves_dir).unwrap();
+        let second = cache
+            .get_or_load(|| panic!("cache hit should not rescan the filesystem"))
+            .unwrap();
+        assert_eq!(second, first);
+
+        cache.invalidate();
+        assert!(cache.get_or_load(|| Ok(list_save_entries()?.value)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_save_list_cache_expires() {
+        let cache = SaveListCache::new();
+        let entry = SaveEntry::new("Survival".to_string(), "Save1".to_string());
+
+        cache.get_or_load(|| Ok(vec![entry.clone()])).unwrap();
+        let reloaded = cache
+            .get_or_load_within(Duration::ZERO, || Ok(Vec::new()))
+            .unwrap();
+        assert!(reloaded.is_empty());
+    }
+
     #[test]
     fn test_looks_like_save_directory_with_map_files() {
         let temp_dir = TempDir::new().unwrap();
@@ -1145,7 +2983,7 @@ mod tests {
         let config = Config::with_save_path(saves_dir.to_str().unwrap().to_string());
         save_config(&config).unwrap();
 
-        let entries = list_save_entries().unwrap();
+        let entries = list_save_entries().unwrap().value;
 
         // Should only find the valid save, not the empty folders
         assert_eq!(entries.len(), 1);
@@ -5,12 +5,17 @@
 //! - Recursive directory deletion
 //! - Directory size calculation
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs;
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
-use flate2::{write::GzEncoder, Compression, read::GzDecoder};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use sha2::{Digest, Sha256};
+use flate2::{write::GzEncoder, Compression, read::MultiGzDecoder};
 use tar::Builder;
 
 /// Error type for file operations.
@@ -20,6 +25,10 @@ pub enum FileOpsError {
     SourceNotFound(PathBuf),
     DestinationExists(PathBuf),
     NotADirectory(PathBuf),
+    EmptyPath(PathBuf),
+    DirectoryNotEmpty(PathBuf),
+    PermissionDenied(PathBuf),
+    Cancelled,
 }
 
 impl fmt::Display for FileOpsError {
@@ -35,6 +44,16 @@ impl fmt::Display for FileOpsError {
             FileOpsError::NotADirectory(path) => {
                 write!(f, "Path is not a directory: {}", path.display())
             }
+            FileOpsError::EmptyPath(path) => {
+                write!(f, "Stripping components leaves an empty path: {}", path.display())
+            }
+            FileOpsError::DirectoryNotEmpty(path) => {
+                write!(f, "Directory is not empty: {}", path.display())
+            }
+            FileOpsError::PermissionDenied(path) => {
+                write!(f, "Permission denied: {}", path.display())
+            }
+            FileOpsError::Cancelled => write!(f, "Operation was cancelled"),
         }
     }
 }
@@ -66,6 +85,518 @@ impl Serialize for FileOpsError {
 /// Result type for file operations.
 pub type FileOpsResult<T> = Result<T, FileOpsError>;
 
+/// Shared flag asking a running archive operation to stop; set it to `true` to cancel.
+pub type CancelFlag = Arc<AtomicBool>;
+
+/// Returns `Cancelled` if `cancel` has been set.
+pub(crate) fn check_cancelled(cancel: Option<&CancelFlag>) -> FileOpsResult<()> {
+    match cancel {
+        Some(flag) if flag.load(Ordering::Relaxed) => Err(FileOpsError::Cancelled),
+        _ => Ok(()),
+    }
+}
+
+/// Kind of operation registered in a `CancellationRegistry`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationKind {
+    Backup,
+    Restore,
+}
+
+/// Cancel flags of in-flight backups and restores, keyed by save name.
+///
+/// Managed as Tauri state: the command running an operation registers it, and
+/// `cancel_backup` / `cancel_restore` set its flag from another command.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationRegistry(Arc<Mutex<HashMap<(OperationKind, String), CancelFlag>>>);
+
+impl CancellationRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an operation on a save.
+    ///
+    /// # Returns
+    /// `Option<CancellationGuard>` - Holds the operation's flag and unregisters it on drop;
+    /// None if the same kind of operation is still registered on the save, whose flag is
+    /// left in place so `cancel` keeps reaching the running operation
+    pub fn register(&self, kind: OperationKind, save_name: &str) -> Option<CancellationGuard> {
+        let key = (kind, save_name.to_string());
+        let mut operations = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if operations.contains_key(&key) {
+            return None;
+        }
+        let flag = CancelFlag::default();
+        operations.insert(key.clone(), flag.clone());
+        Some(CancellationGuard {
+            registry: self.clone(),
+            key,
+            flag,
+        })
+    }
+
+    /// Asks the operation running on a save to stop.
+    ///
+    /// # Returns
+    /// `bool` - true if such an operation was running
+    pub fn cancel(&self, kind: OperationKind, save_name: &str) -> bool {
+        let operations = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        match operations.get(&(kind, save_name.to_string())) {
+            Some(flag) => {
+                flag.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Registration of an in-flight operation (see `CancellationRegistry::register`).
+#[derive(Debug)]
+pub struct CancellationGuard {
+    registry: CancellationRegistry,
+    key: (OperationKind, String),
+    flag: CancelFlag,
+}
+
+impl CancellationGuard {
+    /// Flag to pass to the operation (e.g. `ArchiveOptions::cancel`).
+    pub fn flag(&self) -> CancelFlag {
+        self.flag.clone()
+    }
+}
+
+impl Drop for CancellationGuard {
+    fn drop(&mut self) {
+        let mut operations = self.registry.0.lock().unwrap_or_else(|e| e.into_inner());
+        // Leave a newer registration of the same save alone
+        if operations
+            .get(&self.key)
+            .is_some_and(|flag| Arc::ptr_eq(flag, &self.flag))
+        {
+            operations.remove(&self.key);
+        }
+    }
+}
+
+/// How symbolic links are handled when copying or archiving a directory tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymlinkMode {
+    /// Dereference the link and copy its target
+    #[default]
+    Follow,
+    /// Recreate the link itself at the destination
+    ///
+    /// On Windows this falls back to `Follow`, since creating symlinks requires elevation.
+    Preserve,
+    /// Ignore symbolic links entirely
+    Skip,
+}
+
+impl SymlinkMode {
+    /// Returns the mode that is actually applied on the current platform.
+    fn effective(self) -> Self {
+        if cfg!(windows) && self == SymlinkMode::Preserve {
+            SymlinkMode::Follow
+        } else {
+            self
+        }
+    }
+}
+
+/// Options controlling `copy_dir_recursive_with_options`.
+#[derive(Debug, Clone, Default)]
+pub struct CopyOptions {
+    /// How symbolic links inside the source tree are handled
+    pub symlink_mode: SymlinkMode,
+    /// Maximum I/O throughput in bytes per second (None = unlimited)
+    pub max_bytes_per_sec: Option<u64>,
+}
+
+/// What a recursive directory copy actually copied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CopyStats {
+    /// Number of regular files copied
+    pub files_copied: usize,
+    /// Number of directories created, including the destination itself
+    pub dirs_created: usize,
+    /// Total bytes of file content copied
+    pub bytes_copied: u64,
+}
+
+/// Archive format used for backups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveFormat {
+    /// Gzip-compressed tar archive (`.tar.gz`)
+    #[default]
+    TarGz,
+    /// Zip archive (`.zip`), openable on Windows without extra tools
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// File extension (without leading dot) for archives of this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+
+    /// Detects the archive format from a file name's extension.
+    ///
+    /// # Returns
+    /// `None` if the file name does not end in a known archive extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        [ArchiveFormat::TarGz, ArchiveFormat::Zip]
+            .into_iter()
+            .find(|format| name.ends_with(&format!(".{}", format.extension())))
+    }
+
+    /// Detects the archive format of a file, falling back to its leading (magic)
+    /// bytes when the name has no known extension.
+    ///
+    /// # Returns
+    /// `None` if neither the extension nor the content identifies a known format
+    pub fn detect_from_path(path: &Path) -> Option<Self> {
+        Self::from_path(path).or_else(|| {
+            let mut magic = [0u8; 4];
+            fs::File::open(path).ok()?.read_exact(&mut magic).ok()?;
+            match magic {
+                [0x1f, 0x8b, _, _] => Some(ArchiveFormat::TarGz),
+                [b'P', b'K', 0x03, 0x04] => Some(ArchiveFormat::Zip),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Options controlling `create_tar_gz_with_options`.
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveOptions {
+    /// Maximum I/O throughput in bytes per second (None = unlimited)
+    pub max_bytes_per_sec: Option<u64>,
+    /// Gzip compression level from 0 (none) to 9 (best); None uses the flate2 default
+    pub compression_level: Option<u32>,
+    /// Wildcard patterns (`*`, `?`) for files and directories to leave out of the archive.
+    /// Matched against both the entry's file name and its path relative to the source
+    /// directory (using `/` separators).
+    pub exclude_patterns: Vec<String>,
+    /// Leave out files that cannot be opened because another process holds them
+    /// (e.g. the game on Windows) instead of failing the whole archive; files the user
+    /// may not read fail with `FileOpsError::PermissionDenied` instead
+    pub skip_locked_files: bool,
+    /// If set, only files whose relative path (as recorded in `ArchivedFile::path`) is
+    /// listed are archived; directories are still written so the tree structure is kept
+    pub include_only: Option<HashSet<String>>,
+    /// Number of threads compressing the gzip stream; 0 or 1 compresses on the calling
+    /// thread (see `create_tar_gz_parallel`)
+    pub threads: usize,
+    /// Checked before each entry; once set, archiving stops with `Cancelled` and the
+    /// partial archive is removed
+    pub cancel: Option<CancelFlag>,
+    /// Key/value records stored in a PAX extended header on the archive's first entry,
+    /// so the archive describes itself (see `read_tar_gz_pax_metadata`); tar.gz only
+    pub pax_metadata: Vec<(String, String)>,
+}
+
+/// Uncompressed bytes per gzip member written by `create_tar_gz_parallel`.
+const PARALLEL_GZIP_BLOCK_SIZE: usize = 1024 * 1024;
+
+/// Files larger than this are copied with `copy_file_atomic` by `copy_dir_recursive`.
+pub const ATOMIC_THRESHOLD_BYTES: u64 = 1024 * 1024;
+
+/// Read buffer size used when computing checksums.
+const CHECKSUM_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Maximum number of files recorded in `ArchiveReport::file_inventory`.
+pub const MAX_FILE_INVENTORY_ENTRIES: usize = 1000;
+
+/// A file written to an archive by `create_tar_gz_with_options`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedFile {
+    /// Path relative to the source directory (using `/` separators)
+    pub path: String,
+    /// File size in bytes
+    pub size_bytes: u64,
+    /// Last modification time, if the platform reports one
+    pub modified: Option<SystemTime>,
+}
+
+/// Outcome of `create_tar_gz_with_options`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchiveReport {
+    /// Files left out because they were locked (only with `skip_locked_files`)
+    pub skipped_locked_files: Vec<PathBuf>,
+    /// Files written to the archive, in archive order (at most `MAX_FILE_INVENTORY_ENTRIES`)
+    pub file_inventory: Vec<ArchivedFile>,
+    /// True if the archive holds more files than `file_inventory` lists
+    pub file_inventory_truncated: bool,
+}
+
+impl ArchiveReport {
+    /// Records a file written to the archive, respecting the inventory limit.
+    fn record_file(&mut self, path: String, metadata: &fs::Metadata) {
+        if self.file_inventory.len() >= MAX_FILE_INVENTORY_ENTRIES {
+            self.file_inventory_truncated = true;
+            return;
+        }
+        self.file_inventory.push(ArchivedFile {
+            path,
+            size_bytes: metadata.len(),
+            modified: metadata.modified().ok(),
+        });
+    }
+}
+
+/// Simple I/O rate limiter.
+///
+/// Tracks the bytes transferred in the current one-second window and blocks the
+/// calling thread once the configured rate has been used up. File operations run
+/// on blocking threads (`spawn_blocking`), so a plain thread sleep is used.
+pub struct RateLimiter {
+    bytes_per_sec: u64,
+    window_start: Instant,
+    window_bytes: u64,
+    clock: Box<dyn RateClock + Send>,
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("bytes_per_sec", &self.bytes_per_sec)
+            .field("window_start", &self.window_start)
+            .field("window_bytes", &self.window_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Time source for `RateLimiter`; replaceable so tests don't have to really sleep.
+trait RateClock {
+    fn now(&self) -> Instant;
+    fn sleep(&self, duration: Duration);
+}
+
+/// `RateClock` backed by `Instant::now` and `std::thread::sleep`.
+struct StdClock;
+
+impl RateClock for StdClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter allowing `bytes_per_sec` bytes per second.
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self::with_clock(bytes_per_sec, Box::new(StdClock))
+    }
+
+    /// Creates a rate limiter that reads the time from and sleeps through `clock`.
+    fn with_clock(bytes_per_sec: u64, clock: Box<dyn RateClock + Send>) -> Self {
+        Self {
+            bytes_per_sec: bytes_per_sec.max(1),
+            window_start: clock.now(),
+            window_bytes: 0,
+            clock,
+        }
+    }
+
+    /// Creates a rate limiter from an optional limit (None = unlimited).
+    fn from_limit(limit: Option<u64>) -> Option<Self> {
+        limit.map(Self::new)
+    }
+
+    /// Records `bytes` of I/O, sleeping for the rest of the window if the rate is exceeded.
+    pub fn consume(&mut self, bytes: u64) {
+        self.window_bytes += bytes;
+
+        let allowed = Duration::from_secs_f64(self.window_bytes as f64 / self.bytes_per_sec as f64);
+        let elapsed = self.clock.now() - self.window_start;
+        if allowed > elapsed {
+            self.clock.sleep(allowed - elapsed);
+        }
+
+        let now = self.clock.now();
+        if now - self.window_start >= Duration::from_secs(1) {
+            self.window_start = now;
+            self.window_bytes = 0;
+        }
+    }
+}
+
+/// Writer adapter that throttles writes through an optional `RateLimiter`.
+struct ThrottledWriter<W: Write> {
+    inner: W,
+    limiter: Option<RateLimiter>,
+}
+
+impl<W: Write> ThrottledWriter<W> {
+    fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ThrottledWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        if let Some(limiter) = self.limiter.as_mut() {
+            limiter.consume(written as u64);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Gzip encoder that compresses blocks of its input on several threads.
+///
+/// Each block becomes a separate gzip member; members are written in input order, so
+/// the output is one valid multi-member gzip stream (read with `MultiGzDecoder`).
+struct ParallelGzEncoder<W: Write> {
+    inner: W,
+    compression: Compression,
+    threads: usize,
+    /// Full blocks waiting to be compressed
+    pending: Vec<Vec<u8>>,
+    /// Block currently being filled
+    current: Vec<u8>,
+    members_written: usize,
+}
+
+impl<W: Write> ParallelGzEncoder<W> {
+    fn new(inner: W, compression: Compression, threads: usize) -> Self {
+        Self {
+            inner,
+            compression,
+            threads,
+            pending: Vec::with_capacity(threads),
+            current: Vec::with_capacity(PARALLEL_GZIP_BLOCK_SIZE),
+            members_written: 0,
+        }
+    }
+
+    /// Compresses all pending blocks, one thread per block, and writes them in order.
+    fn compress_pending(&mut self) -> io::Result<()> {
+        let compression = self.compression;
+        let members = std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .pending
+                .drain(..)
+                .map(|block| {
+                    scope.spawn(move || {
+                        let mut encoder = GzEncoder::new(Vec::new(), compression);
+                        encoder.write_all(&block)?;
+                        encoder.finish()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("gzip compression thread panicked"))
+                .collect::<io::Result<Vec<_>>>()
+        })?;
+
+        for member in members {
+            self.inner.write_all(&member)?;
+            self.members_written += 1;
+        }
+        Ok(())
+    }
+
+    /// Moves the block being filled to the pending blocks.
+    fn seal_current(&mut self) {
+        if !self.current.is_empty() {
+            let block = std::mem::replace(
+                &mut self.current,
+                Vec::with_capacity(PARALLEL_GZIP_BLOCK_SIZE),
+            );
+            self.pending.push(block);
+        }
+    }
+
+    /// Compresses the remaining input and returns the underlying writer.
+    fn finish(mut self) -> io::Result<W> {
+        self.seal_current();
+        // An empty input still needs one (empty) member to be a valid gzip stream
+        if self.pending.is_empty() && self.members_written == 0 {
+            self.pending.push(Vec::new());
+        }
+        self.compress_pending()?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for ParallelGzEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(PARALLEL_GZIP_BLOCK_SIZE - self.current.len());
+        self.current.extend_from_slice(&buf[..len]);
+        if self.current.len() == PARALLEL_GZIP_BLOCK_SIZE {
+            self.seal_current();
+            if self.pending.len() >= self.threads {
+                self.compress_pending()?;
+            }
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.seal_current();
+        self.compress_pending()?;
+        self.inner.flush()
+    }
+}
+
+/// Gzip stream written by `create_tar_gz_with_writer`.
+enum GzOutput<W: Write> {
+    Single(GzEncoder<W>),
+    Parallel(ParallelGzEncoder<W>),
+}
+
+impl<W: Write> GzOutput<W> {
+    fn new(inner: W, compression: Compression, threads: usize) -> Self {
+        if threads > 1 {
+            GzOutput::Parallel(ParallelGzEncoder::new(inner, compression, threads))
+        } else {
+            GzOutput::Single(GzEncoder::new(inner, compression))
+        }
+    }
+
+    fn finish(self) -> io::Result<W> {
+        match self {
+            GzOutput::Single(encoder) => encoder.finish(),
+            GzOutput::Parallel(encoder) => encoder.finish(),
+        }
+    }
+}
+
+impl<W: Write> Write for GzOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            GzOutput::Single(encoder) => encoder.write(buf),
+            GzOutput::Parallel(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            GzOutput::Single(encoder) => encoder.flush(),
+            GzOutput::Parallel(encoder) => encoder.flush(),
+        }
+    }
+}
+
 /// Recursively copies a directory from source to destination.
 ///
 /// # Arguments
@@ -73,13 +604,14 @@ pub type FileOpsResult<T> = Result<T, FileOpsError>;
 /// * `dst` - Destination directory path
 ///
 /// # Returns
-/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+/// `FileOpsResult<CopyStats>` - Number of files, directories and bytes copied
 ///
 /// # Behavior
 /// - Creates destination parent directories if they don't exist
 /// - Returns error if destination already exists
 /// - Copies all files and subdirectories recursively
 /// - Preserves file metadata (permissions, modification times)
+/// - Follows symbolic links (see `copy_dir_recursive_with_options` for other modes)
 ///
 /// # Example
 /// ```no_run
@@ -91,7 +623,60 @@ pub type FileOpsResult<T> = Result<T, FileOpsError>;
 ///     Path::new("/backup/save_2024-12-28")
 /// ).unwrap();
 /// ```
-pub fn copy_dir_recursive(src: &Path, dst: &Path) -> FileOpsResult<()> {
+pub fn copy_dir_recursive(src: &Path, dst: &Path) -> FileOpsResult<CopyStats> {
+    copy_dir_recursive_with_options(src, dst, &CopyOptions::default())
+}
+
+/// Recursively copies a directory from source to destination with explicit options.
+///
+/// # Arguments
+/// * `src` - Source directory path
+/// * `dst` - Destination directory path
+/// * `options` - Copy options (symlink handling)
+///
+/// # Returns
+/// `FileOpsResult<CopyStats>` - Number of files, directories and bytes copied
+///
+/// # Behavior
+/// - Same as `copy_dir_recursive`, except symbolic links are handled according to
+///   `options.symlink_mode`
+/// - Throttles file copies to `options.max_bytes_per_sec` if set
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::{copy_dir_recursive_with_options, CopyOptions, SymlinkMode};
+///
+/// let options = CopyOptions {
+///     symlink_mode: SymlinkMode::Preserve,
+///     ..Default::default()
+/// };
+/// copy_dir_recursive_with_options(
+///     Path::new("/source/save"),
+///     Path::new("/backup/save_2024-12-28"),
+///     &options,
+/// ).unwrap();
+/// ```
+pub fn copy_dir_recursive_with_options(
+    src: &Path,
+    dst: &Path,
+    options: &CopyOptions,
+) -> FileOpsResult<CopyStats> {
+    let mut limiter = RateLimiter::from_limit(options.max_bytes_per_sec);
+    let mut stats = CopyStats::default();
+    copy_dir_inner(src, dst, options, &mut limiter, &mut stats)?;
+    Ok(stats)
+}
+
+/// Recursive worker for `copy_dir_recursive_with_options`, sharing one rate limiter
+/// and accumulating into one `CopyStats`.
+fn copy_dir_inner(
+    src: &Path,
+    dst: &Path,
+    options: &CopyOptions,
+    limiter: &mut Option<RateLimiter>,
+    stats: &mut CopyStats,
+) -> FileOpsResult<()> {
     if !src.exists() {
         return Err(FileOpsError::SourceNotFound(src.to_path_buf()));
     }
@@ -102,20 +687,39 @@ pub fn copy_dir_recursive(src: &Path, dst: &Path) -> FileOpsResult<()> {
 
     // Create destination directory
     fs::create_dir_all(dst)?;
+    stats.dirs_created += 1;
 
     // Iterate through source directory entries
     for entry in fs::read_dir(src)? {
         let entry = entry?;
-        let ty = entry.file_type()?;
+        let mut ty = entry.file_type()?;
         let src_path = entry.path();
         let dst_path = dst.join(entry.file_name());
 
+        if ty.is_symlink() {
+            match options.symlink_mode.effective() {
+                SymlinkMode::Skip => continue,
+                SymlinkMode::Preserve => {
+                    copy_symlink(&src_path, &dst_path)?;
+                    continue;
+                }
+                // Dereference and copy whatever the link points to
+                SymlinkMode::Follow => ty = fs::metadata(&src_path)?.file_type(),
+            }
+        }
+
         if ty.is_dir() {
             // Recursively copy subdirectory
-            copy_dir_recursive(&src_path, &dst_path)?;
+            copy_dir_inner(&src_path, &dst_path, options, limiter, stats)?;
         } else {
-            // Copy file
-            copy_file(&src_path, &dst_path)?;
+            // Copy file; large files go through a temp file so a crash never
+            // leaves a partially written destination behind
+            stats.bytes_copied += if fs::metadata(&src_path)?.len() > ATOMIC_THRESHOLD_BYTES {
+                copy_file_atomic_with(&src_path, &dst_path, limiter, &StdRename)?
+            } else {
+                copy_file(&src_path, &dst_path, limiter)?
+            };
+            stats.files_copied += 1;
         }
     }
 
@@ -128,10 +732,15 @@ pub fn copy_dir_recursive(src: &Path, dst: &Path) -> FileOpsResult<()> {
 /// * `src` - Source file path
 /// * `dst` - Destination file path
 ///
+/// * `limiter` - Optional rate limiter applied after each buffer write
+///
+/// # Returns
+/// `FileOpsResult<u64>` - Number of bytes copied
+///
 /// # Behavior
 /// - Uses 64KB buffer to avoid loading entire file into memory
 /// - Creates parent directories if needed
-fn copy_file(src: &Path, dst: &Path) -> FileOpsResult<()> {
+fn copy_file(src: &Path, dst: &Path, limiter: &mut Option<RateLimiter>) -> FileOpsResult<u64> {
     let mut src_file = fs::File::open(src)?;
     let mut dst_file = fs::File::create(dst)?;
 
@@ -145,6 +754,7 @@ fn copy_file(src: &Path, dst: &Path) -> FileOpsResult<()> {
     // Copy with buffer to avoid loading large files into memory
     const BUFFER_SIZE: usize = 64 * 1024; // 64KB buffer
     let mut buffer = [0u8; BUFFER_SIZE];
+    let mut total_bytes = 0u64;
 
     loop {
         let bytes_read = src_file.read(&mut buffer)?;
@@ -152,12 +762,90 @@ fn copy_file(src: &Path, dst: &Path) -> FileOpsResult<()> {
             break;
         }
         dst_file.write_all(&buffer[..bytes_read])?;
+        total_bytes += bytes_read as u64;
+        if let Some(limiter) = limiter.as_mut() {
+            limiter.consume(bytes_read as u64);
+        }
     }
 
     // Ensure data is written to disk for backup integrity
     dst_file.flush()?;
     dst_file.sync_all()?;
 
+    Ok(total_bytes)
+}
+
+/// Renames files for `copy_file_atomic`; replaceable so tests can make the rename fail.
+trait RenameFs {
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+}
+
+/// `RenameFs` backed by `std::fs::rename`.
+struct StdRename;
+
+impl RenameFs for StdRename {
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        fs::rename(from, to)
+    }
+}
+
+/// Copies a single file so that `dst` is either absent or complete, never partially written.
+///
+/// # Arguments
+/// * `src` - Source file path
+/// * `dst` - Destination file path
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success
+///
+/// # Behavior
+/// - Writes to `<dst>.tmp` in the same directory, flushes and syncs it, then renames it to `dst`
+/// - If the rename fails because the paths are on different devices, copies the
+///   temp file to `dst` and deletes it instead
+/// - The temp file is removed if any step fails
+pub fn copy_file_atomic(src: &Path, dst: &Path) -> FileOpsResult<()> {
+    copy_file_atomic_with(src, dst, &mut None, &StdRename)?;
+    Ok(())
+}
+
+/// Worker for `copy_file_atomic`, returning the number of bytes copied.
+fn copy_file_atomic_with(
+    src: &Path,
+    dst: &Path,
+    limiter: &mut Option<RateLimiter>,
+    rename_fs: &dyn RenameFs,
+) -> FileOpsResult<u64> {
+    let mut tmp_name = dst.as_os_str().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_name);
+
+    // Removes the temp file on every path; disarmed only once it has been renamed away
+    let guard = PartialFileGuard::new(&tmp_path);
+    let total_bytes = copy_file(src, &tmp_path, limiter)?;
+
+    match rename_fs.rename(&tmp_path, dst) {
+        Ok(()) => guard.disarm(),
+        Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+            copy_file(&tmp_path, dst, &mut None)?;
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(total_bytes)
+}
+
+/// Recreates the symbolic link at `src` as a new link at `dst`.
+#[cfg(unix)]
+fn copy_symlink(src: &Path, dst: &Path) -> FileOpsResult<()> {
+    let target = fs::read_link(src)?;
+    std::os::unix::fs::symlink(target, dst)?;
+    Ok(())
+}
+
+/// Symlinks are never preserved on Windows (see `SymlinkMode::effective`).
+#[cfg(not(unix))]
+fn copy_symlink(src: &Path, dst: &Path) -> FileOpsResult<()> {
+    copy_file(src, dst, &mut None)?;
     Ok(())
 }
 
@@ -199,32 +887,60 @@ pub fn delete_dir_recursive(path: &Path) -> FileOpsResult<()> {
     Ok(())
 }
 
-/// Calculates the total size of a directory in bytes.
+/// Options controlling `delete_dir_with_options`.
+#[derive(Debug, Clone)]
+pub struct DeleteOptions {
+    /// Only report what would be deleted, without deleting anything
+    pub dry_run: bool,
+    /// Delete the directory's contents too; if false, non-empty directories are rejected
+    pub recursive: bool,
+}
+
+impl Default for DeleteOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            recursive: true,
+        }
+    }
+}
+
+/// Files and directories removed (or, for dry runs, that would be removed) by a delete.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryDeleteResult {
+    /// Every affected path, starting with the directory itself
+    pub paths: Vec<PathBuf>,
+    /// Total size of the affected files in bytes
+    pub total_bytes: u64,
+}
+
+/// Deletes a directory with explicit options.
 ///
 /// # Arguments
-/// * `path` - Path to directory
+/// * `path` - Path to directory to delete
+/// * `options` - Delete options (dry run, recursion)
 ///
 /// # Returns
-/// `FileOpsResult<u64>` - Size in bytes on success, Err on failure
+/// `FileOpsResult<DryDeleteResult>` - The paths deleted (or that would be deleted) and their total size
 ///
 /// # Behavior
-/// - Returns error if path doesn't exist
-/// - Returns error if path is not a directory
-/// - Recursively sums all file sizes
-/// - Does not count directory metadata, only file contents
-///
-/// # Performance
-/// Uses iterative approach with Vec to avoid stack overflow on deep directories.
+/// - Returns error if path doesn't exist or is not a directory
+/// - With `recursive: false`, returns `DirectoryNotEmpty` unless the directory is empty
+///   (like `rmdir`), including for dry runs
+/// - With `dry_run: true`, nothing is deleted
 ///
 /// # Example
 /// ```no_run
 /// use std::path::Path;
-/// use tauri_app_lib::file_ops::get_dir_size;
+/// use tauri_app_lib::file_ops::{delete_dir_with_options, DeleteOptions};
 ///
-/// let size = get_dir_size(Path::new("/save/game")).unwrap();
-/// println!("Save size: {} bytes", size);
+/// let preview = delete_dir_with_options(
+///     Path::new("/old/backup"),
+///     &DeleteOptions { dry_run: true, ..Default::default() },
+/// ).unwrap();
+/// println!("Would delete {} paths ({} bytes)", preview.paths.len(), preview.total_bytes);
 /// ```
-pub fn get_dir_size(path: &Path) -> FileOpsResult<u64> {
+pub fn delete_dir_with_options(path: &Path, options: &DeleteOptions) -> FileOpsResult<DryDeleteResult> {
     if !path.exists() {
         return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
     }
@@ -233,7 +949,14 @@ pub fn get_dir_size(path: &Path) -> FileOpsResult<u64> {
         return Err(FileOpsError::NotADirectory(path.to_path_buf()));
     }
 
-    let mut total_size = 0u64;
+    if !options.recursive && fs::read_dir(path)?.next().is_some() {
+        return Err(FileOpsError::DirectoryNotEmpty(path.to_path_buf()));
+    }
+
+    let mut result = DryDeleteResult {
+        paths: vec![path.to_path_buf()],
+        total_bytes: 0,
+    };
     let mut dirs_to_visit = vec![path.to_path_buf()];
 
     // Iterative approach to avoid stack overflow
@@ -244,27 +967,219 @@ pub fn get_dir_size(path: &Path) -> FileOpsResult<u64> {
             let ty = entry.file_type()?;
 
             if ty.is_dir() {
-                dirs_to_visit.push(entry_path);
+                dirs_to_visit.push(entry_path.clone());
             } else if ty.is_file() {
-                total_size += entry.metadata()?.len();
+                result.total_bytes += entry.metadata()?.len();
             }
+            result.paths.push(entry_path);
         }
     }
 
-    Ok(total_size)
+    if !options.dry_run {
+        fs::remove_dir_all(path)?;
+    }
+
+    Ok(result)
 }
 
-/// Formats a byte count as a human-readable string.
+/// Calculates the total size of a directory in bytes.
 ///
 /// # Arguments
-/// * `bytes` - Size in bytes
+/// * `path` - Path to directory
 ///
 /// # Returns
-/// Formatted string (e.g., "1.23 GB", "45.6 MB", "123 KB")
+/// `FileOpsResult<u64>` - Size in bytes on success, Err on failure
+///
+/// # Behavior
+/// - Returns error if path doesn't exist
+/// - Returns error if path is not a directory
+/// - Recursively sums all file sizes
+/// - Does not count directory metadata, only file contents
+/// - Does not follow symbolic links, so link cycles cannot loop forever
+/// - Does not descend into other file systems (e.g. a mounted network drive)
 ///
 /// # Example
 /// ```no_run
-/// use tauri_app_lib::file_ops::format_size;
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::get_dir_size;
+///
+/// let size = get_dir_size(Path::new("/save/game")).unwrap();
+/// println!("Save size: {} bytes", size);
+/// ```
+pub fn get_dir_size(path: &Path) -> FileOpsResult<u64> {
+    get_dir_size_with_options(path, &DirSizeOptions::default())
+}
+
+/// Gets the size of a directory together with its human-readable form.
+///
+/// # Arguments
+/// * `path` - Path to the directory
+///
+/// # Returns
+/// `FileOpsResult<(u64, String)>` - Size in bytes and `format_size` of it
+pub fn get_dir_size_human(path: &Path) -> FileOpsResult<(u64, String)> {
+    let bytes = get_dir_size(path)?;
+    Ok((bytes, format_size(bytes)))
+}
+
+/// Options controlling `get_dir_size_with_options`.
+#[derive(Debug, Clone)]
+pub struct DirSizeOptions {
+    /// Skip directories on a different file system than `path` (default: true)
+    pub same_file_system: bool,
+}
+
+impl Default for DirSizeOptions {
+    fn default() -> Self {
+        Self {
+            same_file_system: true,
+        }
+    }
+}
+
+/// Calculates the total size of a directory in bytes with explicit options.
+///
+/// # Arguments
+/// * `path` - Path to directory
+/// * `options` - Traversal options (file system boundary)
+///
+/// # Returns
+/// `FileOpsResult<u64>` - Size in bytes on success, Err on failure
+///
+/// # Behavior
+/// Same as `get_dir_size`, but mounted file systems are only included when
+/// `same_file_system` is false.
+pub fn get_dir_size_with_options(path: &Path, options: &DirSizeOptions) -> FileOpsResult<u64> {
+    get_dir_size_with(path, options, &StdDevice)
+}
+
+/// Tells which file system a directory is on for `get_dir_size_with_options`;
+/// replaceable so tests can simulate a mounted drive.
+trait DeviceFs {
+    fn device_id(&self, path: &Path) -> io::Result<u64>;
+}
+
+/// `DeviceFs` backed by the directory's metadata (device number or volume serial).
+struct StdDevice;
+
+impl DeviceFs for StdDevice {
+    #[cfg(unix)]
+    fn device_id(&self, path: &Path) -> io::Result<u64> {
+        use std::os::unix::fs::MetadataExt;
+
+        Ok(fs::symlink_metadata(path)?.dev())
+    }
+
+    #[cfg(windows)]
+    fn device_id(&self, path: &Path) -> io::Result<u64> {
+        use std::os::windows::fs::OpenOptionsExt;
+        use std::os::windows::io::AsRawHandle;
+        use windows::Win32::Foundation::HANDLE;
+        use windows::Win32::Storage::FileSystem::{
+            GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION, FILE_FLAG_BACKUP_SEMANTICS,
+        };
+
+        // Directories can only be opened with backup semantics
+        let dir = fs::OpenOptions::new()
+            .read(true)
+            .custom_flags(FILE_FLAG_BACKUP_SEMANTICS.0)
+            .open(path)?;
+        let mut info = BY_HANDLE_FILE_INFORMATION::default();
+        unsafe { GetFileInformationByHandle(HANDLE(dir.as_raw_handle()), &mut info) }
+            .map_err(io::Error::other)?;
+        Ok(u64::from(info.dwVolumeSerialNumber))
+    }
+}
+
+/// Worker for `get_dir_size_with_options`.
+fn get_dir_size_with(
+    path: &Path,
+    options: &DirSizeOptions,
+    device_fs: &dyn DeviceFs,
+) -> FileOpsResult<u64> {
+    if !path.exists() {
+        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+    }
+
+    if !path.is_dir() {
+        return Err(FileOpsError::NotADirectory(path.to_path_buf()));
+    }
+
+    let root_device = if options.same_file_system {
+        Some(device_fs.device_id(path)?)
+    } else {
+        None
+    };
+    let walker = walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| match root_device {
+            // Don't descend into a directory mounted from another file system
+            Some(root) if entry.depth() > 0 && entry.file_type().is_dir() => device_fs
+                .device_id(entry.path())
+                .map_or(true, |device| device == root),
+            _ => true,
+        });
+
+    let mut total_size = 0u64;
+    for entry in walker {
+        let entry = entry.map_err(io::Error::from)?;
+        if entry.file_type().is_file() {
+            total_size += entry.metadata().map_err(io::Error::from)?.len();
+        }
+    }
+
+    Ok(total_size)
+}
+
+/// Returns the modification time of the most recently modified file in a directory.
+///
+/// # Arguments
+/// * `path` - Path to the directory
+///
+/// # Returns
+/// `FileOpsResult<Option<SystemTime>>` - Newest file mtime, or None if the directory
+/// has no files
+///
+/// # Behavior
+/// Walks the directory like `get_dir_size` (same file system, links not followed),
+/// but keeps the latest mtime instead of summing sizes.
+pub fn get_dir_last_modified(path: &Path) -> FileOpsResult<Option<SystemTime>> {
+    if !path.exists() {
+        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+    }
+
+    if !path.is_dir() {
+        return Err(FileOpsError::NotADirectory(path.to_path_buf()));
+    }
+
+    let walker = walkdir::WalkDir::new(path)
+        .same_file_system(true)
+        .follow_links(false);
+
+    let mut latest: Option<SystemTime> = None;
+    for entry in walker {
+        let entry = entry.map_err(io::Error::from)?;
+        if entry.file_type().is_file() {
+            let modified = entry.metadata().map_err(io::Error::from)?.modified()?;
+            latest = latest.max(Some(modified));
+        }
+    }
+
+    Ok(latest)
+}
+
+/// Formats a byte count as a human-readable string.
+///
+/// # Arguments
+/// * `bytes` - Size in bytes
+///
+/// # Returns
+/// Formatted string (e.g., "1.23 GB", "45.6 MB", "123 KB")
+///
+/// # Example
+/// ```no_run
+/// use tauri_app_lib::file_ops::format_size;
 ///
 /// assert_eq!(format_size(1536), "1.50 KB");
 /// assert_eq!(format_size(1234567890), "1.15 GB");
@@ -286,6 +1201,75 @@ pub fn format_size(bytes: u64) -> String {
     }
 }
 
+/// Returns the disk space available to the current user on the volume holding a path.
+///
+/// # Arguments
+/// * `path` - Any path on the volume; if it doesn't exist yet, its nearest existing
+///   ancestor is used (e.g. a backup directory that is created on first backup)
+///
+/// # Returns
+/// `FileOpsResult<u64>` - Free bytes usable by this user (excludes space reserved for root)
+///
+/// # Behavior
+/// - Windows: `GetDiskFreeSpaceExW`
+/// - Linux/macOS: `statvfs`, counting the blocks available to unprivileged users
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::{available_space, format_size};
+///
+/// let free = available_space(Path::new("/backups")).unwrap();
+/// println!("{} free", format_size(free));
+/// ```
+pub fn available_space(path: &Path) -> FileOpsResult<u64> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| FileOpsError::SourceNotFound(path.to_path_buf()))?;
+
+    Ok(available_space_impl(existing)?)
+}
+
+#[cfg(target_os = "windows")]
+fn available_space_impl(path: &Path) -> io::Result<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::core::PCWSTR;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+    let mut available = 0u64;
+    unsafe {
+        GetDiskFreeSpaceExW(
+            PCWSTR(wide.as_ptr()),
+            Some(&mut available as *mut u64),
+            None,
+            None,
+        )
+    }
+    .map_err(io::Error::other)?;
+    Ok(available)
+}
+
+#[cfg(unix)]
+fn available_space_impl(path: &Path) -> io::Result<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let mut stats = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    if unsafe { libc::statvfs(c_path.as_ptr(), stats.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let stats = unsafe { stats.assume_init() };
+
+    // The field types differ between platforms (e.g. 32-bit block counts on macOS)
+    #[allow(clippy::unnecessary_cast)]
+    let available = stats.f_bavail as u64 * stats.f_frsize as u64;
+    Ok(available)
+}
+
 /// Normalizes a path for consistent string representation across platforms.
 ///
 /// This ensures all path separators use the standard separator for the current platform,
@@ -297,6 +1281,9 @@ pub fn format_size(bytes: u64) -> String {
 /// # Returns
 /// String with normalized path separators
 ///
+/// # Behavior
+/// Drive and UNC prefixes are kept intact, so `\\server\share\dir` stays a UNC path.
+///
 /// # Example
 /// ```no_run
 /// use std::path::Path;
@@ -453,8 +1440,10 @@ pub fn show_in_file_manager(path: &Path) -> FileOpsResult<()> {
 /// - Returns error if source doesn't exist
 /// - Returns error if destination already exists
 /// - Uses default gzip compression (level 6) for balanced speed/compression ratio
+/// - Archives symbolic links as links (`SymlinkMode::Preserve`), following them on Windows
 /// - **Atomic write**: Writes to a temporary file first, then atomically renames to final path
 ///   This ensures that if the process crashes during backup, no incomplete backup file is left
+/// - The temporary file is deleted if archiving fails (e.g. disk full)
 ///
 /// # Example
 /// ```no_run
@@ -467,6 +1456,167 @@ pub fn show_in_file_manager(path: &Path) -> FileOpsResult<()> {
 /// ).unwrap();
 /// ```
 pub fn create_tar_gz(src_dir: &Path, dst_file: &Path) -> FileOpsResult<()> {
+    create_tar_gz_with_options(src_dir, dst_file, &ArchiveOptions::default())?;
+    Ok(())
+}
+
+/// Creates a compressed tar.gz archive of a directory, compressing on several threads.
+///
+/// # Arguments
+/// * `src_dir` - Source directory to compress
+/// * `dst_file` - Destination .tar.gz file path
+/// * `threads` - Number of compression threads; 0 or 1 is the same as `create_tar_gz`
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+///
+/// # Behavior
+/// - Same as `create_tar_gz`, but the tar stream is split into 1 MiB blocks that are
+///   compressed concurrently, `threads` at a time
+/// - Each block is a separate gzip member, so the file is a multi-member gzip stream;
+///   `extract_tar_gz`, `gunzip` and `tar -xzf` read it like any other tar.gz
+/// - Archives are slightly larger, since blocks don't share a compression dictionary
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::create_tar_gz_parallel;
+///
+/// create_tar_gz_parallel(
+///     Path::new("/save/game"),
+///     Path::new("/backup/game_2024-12-28.tar.gz"),
+///     4,
+/// ).unwrap();
+/// ```
+pub fn create_tar_gz_parallel(
+    src_dir: &Path,
+    dst_file: &Path,
+    threads: usize,
+) -> FileOpsResult<()> {
+    let options = ArchiveOptions {
+        threads,
+        ..Default::default()
+    };
+    create_tar_gz_with_options(src_dir, dst_file, &options)?;
+    Ok(())
+}
+
+/// Creates a compressed tar.gz archive of a directory with explicit options.
+///
+/// # Arguments
+/// * `src_dir` - Source directory to compress
+/// * `dst_file` - Destination .tar.gz file path
+/// * `options` - Archive options (I/O throttling, compression level, exclusions, locked files)
+///
+/// # Returns
+/// `FileOpsResult<ArchiveReport>` - Report listing archived and skipped files on success, Err on failure
+///
+/// # Behavior
+/// - Same as `create_tar_gz`
+/// - Throttles the archive stream to `options.max_bytes_per_sec` if set, so a backup
+///   running while the game loads does not saturate the disk
+/// - Compresses with `options.compression_level` (clamped to 9) if set
+/// - Skips files and directories matching `options.exclude_patterns`
+/// - With `options.skip_locked_files`, files that fail to open with a sharing or lock
+///   violation are left out and listed in the report, while a file that can't be read
+///   for lack of permissions fails with `FileOpsError::PermissionDenied`
+/// - Lists the archived files (up to `MAX_FILE_INVENTORY_ENTRIES`) in the report
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::{create_tar_gz_with_options, ArchiveOptions};
+///
+/// let options = ArchiveOptions {
+///     max_bytes_per_sec: Some(10 * 1024 * 1024),
+///     exclude_patterns: vec!["*.log".to_string()],
+///     ..Default::default()
+/// };
+/// create_tar_gz_with_options(
+///     Path::new("/save/game"),
+///     Path::new("/backup/game_2024-12-28.tar.gz"),
+///     &options,
+/// ).unwrap();
+/// ```
+pub fn create_tar_gz_with_options(
+    src_dir: &Path,
+    dst_file: &Path,
+    options: &ArchiveOptions,
+) -> FileOpsResult<ArchiveReport> {
+    create_tar_gz_with_writer(src_dir, dst_file, options, |file| file, None)
+}
+
+/// Creates a compressed tar.gz archive of a directory, reporting byte progress.
+///
+/// # Arguments
+/// * `src_dir` - Source directory to compress
+/// * `dst_file` - Destination .tar.gz file path
+/// * `options` - Archive options (see `create_tar_gz_with_options`)
+/// * `progress` - Called with `(processed_bytes, total_bytes)`
+///
+/// # Returns
+/// `FileOpsResult<ArchiveReport>` - Same as `create_tar_gz_with_options`
+///
+/// # Behavior
+/// - The total is the size of `src_dir` (as `get_dir_size`), computed once before archiving
+/// - `progress` is called once at the start, after each archived file and once more with
+///   `processed == total` when the archive is complete (excluded or skipped files
+///   never add to `processed`)
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::{create_tar_gz_with_progress, ArchiveOptions};
+///
+/// create_tar_gz_with_progress(
+///     Path::new("/save/game"),
+///     Path::new("/backup/game_2024-12-28.tar.gz"),
+///     &ArchiveOptions::default(),
+///     |processed, total| println!("{}/{} bytes", processed, total),
+/// ).unwrap();
+/// ```
+pub fn create_tar_gz_with_progress<P>(
+    src_dir: &Path,
+    dst_file: &Path,
+    options: &ArchiveOptions,
+    mut progress: P,
+) -> FileOpsResult<ArchiveReport>
+where
+    P: FnMut(u64, u64),
+{
+    create_tar_gz_with_writer(src_dir, dst_file, options, |file| file, Some(&mut progress))
+}
+
+/// Byte progress of an archive being written, reported to a callback.
+struct ArchiveProgress<'a> {
+    processed: u64,
+    total: u64,
+    callback: &'a mut dyn FnMut(u64, u64),
+}
+
+impl ArchiveProgress<'_> {
+    /// Adds `bytes` to the processed count and reports it.
+    fn advance(&mut self, bytes: u64) {
+        // Files may grow while they are archived; never report more than the total
+        self.processed = (self.processed + bytes).min(self.total);
+        (self.callback)(self.processed, self.total);
+    }
+}
+
+/// Implementation of `create_tar_gz_with_options` and `create_tar_gz_with_progress`;
+/// `wrap` adapts the temporary file before the gzip stream is written to it (tests use
+/// it to inject write errors).
+fn create_tar_gz_with_writer<W, F>(
+    src_dir: &Path,
+    dst_file: &Path,
+    options: &ArchiveOptions,
+    wrap: F,
+    progress: Option<&mut dyn FnMut(u64, u64)>,
+) -> FileOpsResult<ArchiveReport>
+where
+    W: Write,
+    F: FnOnce(fs::File) -> W,
+{
     if !src_dir.exists() {
         return Err(FileOpsError::SourceNotFound(src_dir.to_path_buf()));
     }
@@ -482,28 +1632,230 @@ pub fn create_tar_gz(src_dir: &Path, dst_file: &Path) -> FileOpsResult<()> {
         }
     }
 
+    // Compute the total once, before anything is written
+    let mut progress = match progress {
+        Some(callback) => {
+            let total = get_dir_size(src_dir)?;
+            callback(0, total);
+            Some(ArchiveProgress { processed: 0, total, callback })
+        }
+        None => None,
+    };
+
     // Create a temporary file path for atomic write
     let temp_file = dst_file.with_extension("tar.gz.tmp");
+    // Declared before the file so it is dropped (and removed) after the file is closed
+    let guard = PartialFileGuard::new(&temp_file);
 
     // Create the tar.gz file to temporary location
     let gz_file = fs::File::create(&temp_file)?;
-    let encoder = GzEncoder::new(gz_file, Compression::default());
-    let mut tar = Builder::new(encoder);
+    let compression = options
+        .compression_level
+        .map(|level| Compression::new(level.min(9)))
+        .unwrap_or_default();
+    let encoder = GzOutput::new(wrap(gz_file), compression, options.threads);
+    let writer = ThrottledWriter {
+        inner: encoder,
+        limiter: RateLimiter::from_limit(options.max_bytes_per_sec),
+    };
+    let mut tar = Builder::new(writer);
+    tar.follow_symlinks(SymlinkMode::Preserve.effective() == SymlinkMode::Follow);
 
     // Add the source directory to the archive
-    tar.append_dir_all(".", src_dir)?;
+    let mut report = ArchiveReport::default();
+    tar.append_pax_extensions(
+        options.pax_metadata.iter().map(|(key, value)| (key.as_str(), value.as_bytes())),
+    )?;
+    tar.append_dir(".", src_dir)?;
+    append_dir_filtered(&mut tar, src_dir, Path::new(""), options, &mut report, &mut progress)?;
 
     // Finish the archive (this flushes and completes the gzip stream)
-    let encoder = tar.into_inner()?;
+    let encoder = tar.into_inner()?.into_inner();
     encoder.finish()?;
 
     // Atomically rename the temporary file to the final destination
     // fs::rename is atomic on POSIX systems when files are on the same filesystem
     fs::rename(&temp_file, dst_file)?;
+    guard.disarm();
+
+    if let Some(progress) = progress.as_mut() {
+        progress.advance(progress.total);
+    }
+
+    Ok(report)
+}
+
+/// Removes a partially written file when dropped, unless `disarm` was called.
+///
+/// Archive writers create this before the temporary file so that any early
+/// return (disk full, I/O error) cleans up after itself.
+struct PartialFileGuard {
+    path: PathBuf,
+    armed: bool,
+}
+
+impl PartialFileGuard {
+    fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            armed: true,
+        }
+    }
+
+    /// Keeps the file; call once it has been completed and moved into place.
+    fn disarm(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for PartialFileGuard {
+    fn drop(&mut self) {
+        if self.armed {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Recursively appends the contents of `dir` to `tar`, skipping excluded entries
+/// and (with `skip_locked_files`) locked files, and records archived files in `report`.
+///
+/// `relative` is the path of `dir` relative to the archive root. `progress`, if set,
+/// advances by the size of each archived file.
+fn append_dir_filtered<W: Write>(
+    tar: &mut Builder<W>,
+    dir: &Path,
+    relative: &Path,
+    options: &ArchiveOptions,
+    report: &mut ArchiveReport,
+    progress: &mut Option<ArchiveProgress<'_>>,
+) -> FileOpsResult<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        check_cancelled(options.cancel.as_ref())?;
+        let path = entry.path();
+        let name = entry.file_name();
+        let entry_relative = relative.join(&name);
+
+        let name_str = name.to_string_lossy();
+        let relative_str = normalize_path_for_display(&entry_relative);
+        if is_excluded(&options.exclude_patterns, &name_str, &relative_str) {
+            continue;
+        }
+
+        let archive_path = Path::new(".").join(&entry_relative);
+        let ty = entry.file_type()?;
+        let is_dir = if ty.is_symlink() {
+            // Mirror append_dir_all: only followed symlinks are descended into
+            SymlinkMode::Preserve.effective() == SymlinkMode::Follow && path.is_dir()
+        } else {
+            ty.is_dir()
+        };
+
+        if !is_dir {
+            if let Some(include_only) = &options.include_only {
+                if !include_only.contains(&relative_str) {
+                    continue;
+                }
+            }
+        }
+
+        if is_dir {
+            tar.append_dir(&archive_path, &path)?;
+            append_dir_filtered(tar, &path, &entry_relative, options, report, progress)?;
+        } else if ty.is_file() && options.skip_locked_files {
+            match fs::File::open(&path) {
+                Ok(mut file) => {
+                    tar.append_file(&archive_path, &mut file)?;
+                    let metadata = file.metadata()?;
+                    report.record_file(relative_str, &metadata);
+                    if let Some(progress) = progress.as_mut() {
+                        progress.advance(metadata.len());
+                    }
+                }
+                Err(err) if is_locked_file_error(&err) => {
+                    report.skipped_locked_files.push(path);
+                }
+                Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                    return Err(FileOpsError::PermissionDenied(path));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        } else {
+            tar.append_path_with_name(&path, &archive_path)?;
+            if ty.is_file() {
+                let metadata = entry.metadata()?;
+                report.record_file(relative_str, &metadata);
+                if let Some(progress) = progress.as_mut() {
+                    progress.advance(metadata.len());
+                }
+            }
+        }
+    }
 
     Ok(())
 }
 
+/// Returns true if an entry is matched by any of the `exclude_patterns`.
+///
+/// `name` is the entry's file name and `relative` its path relative to the archived
+/// directory, as built by `normalize_path_for_display`.
+pub(crate) fn is_excluded(exclude_patterns: &[String], name: &str, relative: &str) -> bool {
+    exclude_patterns
+        .iter()
+        .any(|pattern| wildcard_match(pattern, name) || wildcard_match(pattern, relative))
+}
+
+/// Returns true if `err` means the file is held by another process.
+///
+/// On Windows an exclusively opened file fails with a sharing violation
+/// (`ERROR_SHARING_VIOLATION`) or lock violation (`ERROR_LOCK_VIOLATION`).
+/// Plain `PermissionDenied` is not a lock: the file is unreadable no matter
+/// whether the game runs, so it must not be silently skipped.
+fn is_locked_file_error(err: &io::Error) -> bool {
+    cfg!(windows) && matches!(err.raw_os_error(), Some(32) | Some(33))
+}
+
+/// Matches `text` against a simple wildcard `pattern`.
+///
+/// `*` matches any sequence of characters (including none) and `?` matches exactly one.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Options controlling `extract_tar_gz_with_options` and `extract_archive_with_options`.
+#[derive(Debug, Clone, Default)]
+pub struct ExtractOptions {
+    /// Number of leading path components to strip from each entry (like `tar --strip-components`)
+    pub strip_components: usize,
+    /// Checked before each entry; once set, extraction stops with `Cancelled`, leaving
+    /// the entries extracted so far in place for the caller to clean up
+    pub cancel: Option<CancelFlag>,
+}
+
 /// Extracts a compressed tar.gz archive to a directory.
 ///
 /// # Arguments
@@ -529,335 +1881,2383 @@ pub fn create_tar_gz(src_dir: &Path, dst_file: &Path) -> FileOpsResult<()> {
 /// ).unwrap();
 /// ```
 pub fn extract_tar_gz(src_file: &Path, dst_dir: &Path) -> FileOpsResult<()> {
-    if !src_file.exists() {
-        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
-    }
-
-    if dst_dir.exists() {
-        return Err(FileOpsError::DestinationExists(dst_dir.to_path_buf()));
-    }
-
-    // Create parent directories if needed
-    if let Some(parent) = dst_dir.parent() {
-        if !parent.exists() {
-            fs::create_dir_all(parent)?;
-        }
-    }
+    extract_tar_gz_with_options(src_file, dst_dir, &ExtractOptions::default())
+}
 
-    // Open the gz file and create a decoder
-    let gz_file = fs::File::open(src_file)?;
-    let decoder = flate2::read::GzDecoder::new(gz_file);
-    let mut archive = tar::Archive::new(decoder);
+/// Extracts a compressed tar.gz archive to a directory with explicit options.
+///
+/// # Arguments
+/// * `src_file` - Source .tar.gz file path
+/// * `dst_dir` - Destination directory to extract to
+/// * `options` - Extraction options (component stripping)
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+///
+/// # Behavior
+/// - Same as `extract_tar_gz` when `strip_components` is 0
+/// - Otherwise strips the first N path components from each entry, so an archive
+///   containing `MySave/file` extracts to `<dst_dir>/file` with `strip_components: 1`
+/// - Directory entries that are stripped away entirely are skipped
+/// - Returns `EmptyPath` if stripping leaves a file entry with an empty path
+/// - Skips entries whose paths would escape `dst_dir` (absolute paths or `..`)
+/// - When stripping, symlink and hard link entries are skipped, and so is any entry
+///   whose parent inside `dst_dir` is a symlink, so nothing is written through a link
+/// - Checks `cancel` between entries and returns `Cancelled` once it is set
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::{extract_tar_gz_with_options, ExtractOptions};
+///
+/// extract_tar_gz_with_options(
+///     Path::new("/imports/MySave.tar.gz"),
+///     Path::new("/save/game"),
+///     &ExtractOptions { strip_components: 1, ..Default::default() },
+/// ).unwrap();
+/// ```
+pub fn extract_tar_gz_with_options(
+    src_file: &Path,
+    dst_dir: &Path,
+    options: &ExtractOptions,
+) -> FileOpsResult<()> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
 
-    // Extract the archive
-    archive.unpack(dst_dir)?;
+    if dst_dir.exists() {
+        return Err(FileOpsError::DestinationExists(dst_dir.to_path_buf()));
+    }
+
+    // Create parent directories if needed
+    if let Some(parent) = dst_dir.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    // Open the gz file and create a decoder
+    let gz_file = fs::File::open(src_file)?;
+    let decoder = MultiGzDecoder::new(gz_file);
+    let mut archive = tar::Archive::new(decoder);
+
+    if options.strip_components == 0 && options.cancel.is_none() {
+        // Extract the archive
+        archive.unpack(dst_dir)?;
+        return Ok(());
+    }
+
+    fs::create_dir_all(dst_dir)?;
+
+    for entry in archive.entries()? {
+        check_cancelled(options.cancel.as_ref())?;
+        let mut entry = entry?;
+        if options.strip_components == 0 {
+            // Skips paths escaping dst_dir, like `Archive::unpack`
+            entry.unpack_in(dst_dir)?;
+            continue;
+        }
+        let entry_path = entry.path()?.into_owned();
+
+        let Some(parts) = normal_components(&entry_path) else {
+            // Never write outside dst_dir, like `Archive::unpack`
+            continue;
+        };
+
+        if parts.len() <= options.strip_components {
+            if entry.header().entry_type().is_dir() {
+                continue;
+            }
+            return Err(FileOpsError::EmptyPath(entry_path));
+        }
+
+        // `entry.unpack` has none of `unpack_in`'s link checks: a link entry followed by
+        // a file written through it could land outside dst_dir
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_symlink() || entry_type.is_hard_link() {
+            continue;
+        }
+        let relative: PathBuf = parts[options.strip_components..].iter().collect();
+        if has_symlink_parent(dst_dir, &relative) {
+            continue;
+        }
+        let target = dst_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        entry.unpack(&target)?;
+    }
 
     Ok(())
 }
 
-/// Reads a specific file from a tar.gz archive and returns base64-encoded data URL.
+/// Extracts a compressed tar.gz archive over an existing directory.
 ///
 /// # Arguments
 /// * `src_file` - Source .tar.gz file path
-/// * `file_path` - Path of the file to read inside the archive (e.g., "thumb.png")
+/// * `dst_dir` - Destination directory (created if missing)
 ///
 /// # Returns
-/// `FileOpsResult<Option<String>>` - Some(data URL) if file exists, None if not found
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
 ///
 /// # Behavior
-/// - Searches for the file in the archive
-/// - Returns base64-encoded data URL if found
-/// - Returns None if file not found (not an error)
+/// - Unlike `extract_tar_gz`, an existing destination is allowed: files in the archive
+///   replace files at the same path, and all other files are left in place
+/// - Skips entries whose paths would escape `dst_dir` (absolute paths or `..`)
 ///
 /// # Example
 /// ```no_run
 /// use std::path::Path;
-/// use tauri_app_lib::file_ops::read_file_from_tar_gz_base64;
+/// use tauri_app_lib::file_ops::extract_tar_gz_over;
 ///
-/// let result = read_file_from_tar_gz_base64(
-///     Path::new("/backup/game_2024-12-28.tar.gz"),
-///     "thumb.png"
+/// extract_tar_gz_over(
+///     Path::new("/backup/game_2024-12-29.tar.gz"),
+///     Path::new("/save/game")
 /// ).unwrap();
 /// ```
-pub fn read_file_from_tar_gz_base64(src_file: &Path, file_path: &str) -> FileOpsResult<Option<String>> {
+pub fn extract_tar_gz_over(src_file: &Path, dst_dir: &Path) -> FileOpsResult<()> {
     if !src_file.exists() {
         return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
     }
 
-    // Open the gz file and create a decoder
+    fs::create_dir_all(dst_dir)?;
+    let mut archive = tar::Archive::new(MultiGzDecoder::new(fs::File::open(src_file)?));
+    archive.set_overwrite(true);
+    archive.unpack(dst_dir)?;
+    Ok(())
+}
+
+/// Lists the regular files in a tar.gz archive without extracting it.
+///
+/// # Arguments
+/// * `src_file` - Source .tar.gz file path
+///
+/// # Returns
+/// `FileOpsResult<Vec<ArchivedFile>>` - Files in archive order, with paths relative to
+/// the archive root (as `create_tar_gz_with_options` reports them)
+///
+/// # Behavior
+/// - Directories, links and entries whose paths would escape the archive root are skipped
+/// - `modified` comes from the entry header, so it has one-second precision
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::list_tar_gz_files;
+///
+/// let files = list_tar_gz_files(Path::new("/backup/game_2024-12-28.tar.gz")).unwrap();
+/// println!("{} files", files.len());
+/// ```
+pub fn list_tar_gz_files(src_file: &Path) -> FileOpsResult<Vec<ArchivedFile>> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    let mut archive = tar::Archive::new(MultiGzDecoder::new(fs::File::open(src_file)?));
+    let mut files = Vec::new();
+    for entry in archive.entries()? {
+        let entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let entry_path = entry.path()?;
+        let Some(parts) = normal_components(&entry_path) else {
+            continue;
+        };
+        let relative: PathBuf = parts.iter().collect();
+        let modified = entry
+            .header()
+            .mtime()
+            .ok()
+            .map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        files.push(ArchivedFile {
+            path: normalize_path_for_display(&relative),
+            size_bytes: entry.size(),
+            modified,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Checks whether any directory between `dst_dir` and `dst_dir/relative` is a symlink.
+fn has_symlink_parent(dst_dir: &Path, relative: &Path) -> bool {
+    let mut current = dst_dir.to_path_buf();
+    let Some(parent) = relative.parent() else {
+        return false;
+    };
+    for part in parent.components() {
+        current.push(part);
+        if fs::symlink_metadata(&current).is_ok_and(|meta| meta.file_type().is_symlink()) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the normal components of an archive entry path, ignoring `.` components.
+///
+/// Returns `None` if the path contains a root, prefix or `..` component.
+fn normal_components(path: &Path) -> Option<Vec<&std::ffi::OsStr>> {
+    use std::path::Component;
+
+    let mut parts = Vec::new();
+    for comp in path.components() {
+        match comp {
+            Component::Normal(part) => parts.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(parts)
+}
+
+/// Verifies the integrity of a tar.gz archive without extracting it.
+///
+/// # Arguments
+/// * `src_file` - Source .tar.gz file path
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) if the archive is intact, Err otherwise
+///
+/// # Behavior
+/// - Decodes the whole gzip stream, which validates its CRC32 checksum and length
+/// - Reads every tar entry, so truncated or malformed archives are detected
+/// - Nothing is written to disk
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::verify_tar_gz;
+///
+/// verify_tar_gz(Path::new("/backup/game_2024-12-28.tar.gz")).unwrap();
+/// ```
+pub fn verify_tar_gz(src_file: &Path) -> FileOpsResult<()> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    scan_tar_gz(src_file, &mut ArchiveVerificationResult::default())?;
+    Ok(())
+}
+
+/// Reads a whole tar.gz archive, counting its files and uncompressed bytes in `stats`.
+fn scan_tar_gz(src_file: &Path, stats: &mut ArchiveVerificationResult) -> io::Result<()> {
     let gz_file = fs::File::open(src_file)?;
-    let decoder = GzDecoder::new(gz_file);
+    let decoder = MultiGzDecoder::new(gz_file);
     let mut archive = tar::Archive::new(decoder);
 
-    // Iterate through entries to find the target file
-    for entry in archive.entries()? {
-        let mut entry = entry?;
-        let path = entry.path()?;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let is_file = entry.header().entry_type().is_file();
+        let bytes = io::copy(&mut entry, &mut io::sink())?;
+        if is_file {
+            stats.file_count += 1;
+            stats.total_uncompressed_bytes += bytes;
+        }
+    }
+
+    // The gzip checksum is only validated once the stream is read to its end,
+    // so drain anything the tar reader left behind (end-of-archive padding)
+    let mut decoder = archive.into_inner();
+    io::copy(&mut decoder, &mut io::sink())?;
+
+    Ok(())
+}
+
+/// Creates a zip archive of a directory.
+///
+/// # Arguments
+/// * `src_dir` - Source directory to compress
+/// * `dst_file` - Destination .zip file path
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+///
+/// # Behavior
+/// - Same as `create_tar_gz`, but writes a deflate-compressed zip archive
+/// - Entries are stored with paths relative to `src_dir`
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::create_zip;
+///
+/// create_zip(
+///     Path::new("/save/game"),
+///     Path::new("/backup/game_2024-12-28.zip")
+/// ).unwrap();
+/// ```
+pub fn create_zip(src_dir: &Path, dst_file: &Path) -> FileOpsResult<()> {
+    create_zip_with_options(src_dir, dst_file, &ArchiveOptions::default())?;
+    Ok(())
+}
+
+/// Creates a zip archive of a directory with explicit options.
+///
+/// # Arguments
+/// * `src_dir` - Source directory to compress
+/// * `dst_file` - Destination .zip file path
+/// * `options` - Archive options (I/O throttling, compression level, exclusions, locked files)
+///
+/// # Returns
+/// `FileOpsResult<ArchiveReport>` - Report listing archived and skipped files on success, Err on failure
+///
+/// # Behavior
+/// - Same as `create_tar_gz_with_options`, but writes a zip archive
+/// - Symbolic links are followed on Windows and left out elsewhere, since zip
+///   has no portable way to store them
+pub fn create_zip_with_options(
+    src_dir: &Path,
+    dst_file: &Path,
+    options: &ArchiveOptions,
+) -> FileOpsResult<ArchiveReport> {
+    if !src_dir.exists() {
+        return Err(FileOpsError::SourceNotFound(src_dir.to_path_buf()));
+    }
+
+    if dst_file.exists() {
+        return Err(FileOpsError::DestinationExists(dst_file.to_path_buf()));
+    }
+
+    // Create parent directories if needed
+    if let Some(parent) = dst_file.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    // Create a temporary file path for atomic write
+    let temp_file = dst_file.with_extension("zip.tmp");
+    let guard = PartialFileGuard::new(&temp_file);
+
+    let zip_file = fs::File::create(&temp_file)?;
+    let mut writer = ThrottledWriter {
+        inner: zip::ZipWriter::new(zip_file),
+        limiter: RateLimiter::from_limit(options.max_bytes_per_sec),
+    };
+
+    let mut report = ArchiveReport::default();
+    append_zip_dir(&mut writer, src_dir, Path::new(""), options, &mut report)?;
+    writer.into_inner().finish().map_err(io::Error::from)?;
+
+    // Atomically rename the temporary file to the final destination
+    fs::rename(&temp_file, dst_file)?;
+    guard.disarm();
+
+    Ok(report)
+}
+
+/// Entry options for files and directories written by `create_zip_with_options`.
+fn zip_file_options(options: &ArchiveOptions) -> zip::write::SimpleFileOptions {
+    zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .compression_level(options.compression_level.map(|level| i64::from(level.min(9))))
+}
+
+/// Recursively appends the contents of `dir` to a zip archive, skipping excluded
+/// entries and (with `skip_locked_files`) locked files, and records archived files in `report`.
+///
+/// `relative` is the path of `dir` relative to the archive root.
+fn append_zip_dir(
+    writer: &mut ThrottledWriter<zip::ZipWriter<fs::File>>,
+    dir: &Path,
+    relative: &Path,
+    options: &ArchiveOptions,
+    report: &mut ArchiveReport,
+) -> FileOpsResult<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        check_cancelled(options.cancel.as_ref())?;
+        let path = entry.path();
+        let name = entry.file_name();
+        let entry_relative = relative.join(&name);
+
+        let name_str = name.to_string_lossy();
+        // Zip entry names always use `/` separators
+        let relative_str = normalize_path_for_display(&entry_relative).replace('\\', "/");
+        if is_excluded(&options.exclude_patterns, &name_str, &relative_str) {
+            continue;
+        }
+
+        let ty = entry.file_type()?;
+        let (is_dir, is_file) = if ty.is_symlink() {
+            if SymlinkMode::Preserve.effective() != SymlinkMode::Follow {
+                continue;
+            }
+            (path.is_dir(), path.is_file())
+        } else {
+            (ty.is_dir(), ty.is_file())
+        };
+
+        if !is_dir {
+            if let Some(include_only) = &options.include_only {
+                if !include_only.contains(&relative_str) {
+                    continue;
+                }
+            }
+        }
+
+        if is_dir {
+            writer
+                .inner
+                .add_directory(format!("{}/", relative_str), zip_file_options(options))
+                .map_err(io::Error::from)?;
+            append_zip_dir(writer, &path, &entry_relative, options, report)?;
+        } else if is_file {
+            let mut file = match fs::File::open(&path) {
+                Ok(file) => file,
+                Err(err) if options.skip_locked_files && is_locked_file_error(&err) => {
+                    report.skipped_locked_files.push(path);
+                    continue;
+                }
+                Err(err) if options.skip_locked_files
+                    && err.kind() == io::ErrorKind::PermissionDenied =>
+                {
+                    return Err(FileOpsError::PermissionDenied(path));
+                }
+                Err(err) => return Err(err.into()),
+            };
+            writer
+                .inner
+                .start_file(relative_str.clone(), zip_file_options(options))
+                .map_err(io::Error::from)?;
+            io::copy(&mut file, writer)?;
+            report.record_file(relative_str, &file.metadata()?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Extracts a zip archive to a directory.
+///
+/// # Arguments
+/// * `src_file` - Source .zip file path
+/// * `dst_dir` - Destination directory to extract to
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+///
+/// # Behavior
+/// - Returns error if source file doesn't exist
+/// - Returns error if destination already exists
+/// - Creates parent directories if needed
+/// - Skips entries whose paths would escape `dst_dir` (absolute paths or `..`)
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::extract_zip;
+///
+/// extract_zip(
+///     Path::new("/backup/game_2024-12-28.zip"),
+///     Path::new("/save/game")
+/// ).unwrap();
+/// ```
+pub fn extract_zip(src_file: &Path, dst_dir: &Path) -> FileOpsResult<()> {
+    extract_zip_with_options(src_file, dst_dir, &ExtractOptions::default())
+}
+
+/// Extracts a zip archive to a directory with explicit options.
+///
+/// # Arguments
+/// * `src_file` - Source .zip file path
+/// * `dst_dir` - Destination directory to extract to
+/// * `options` - Extraction options (component stripping, cancellation)
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+///
+/// # Behavior
+/// Same as `extract_tar_gz_with_options`, for zip archives.
+pub fn extract_zip_with_options(
+    src_file: &Path,
+    dst_dir: &Path,
+    options: &ExtractOptions,
+) -> FileOpsResult<()> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    if dst_dir.exists() {
+        return Err(FileOpsError::DestinationExists(dst_dir.to_path_buf()));
+    }
+
+    let zip_file = fs::File::open(src_file)?;
+    let mut archive = zip::ZipArchive::new(zip_file).map_err(io::Error::from)?;
+
+    fs::create_dir_all(dst_dir)?;
+
+    for index in 0..archive.len() {
+        check_cancelled(options.cancel.as_ref())?;
+        let mut entry = archive.by_index(index).map_err(io::Error::from)?;
+        let entry_path = PathBuf::from(entry.name());
+
+        let Some(parts) = normal_components(&entry_path) else {
+            // Same policy as `extract_tar_gz`: never write outside dst_dir
+            continue;
+        };
+        if parts.len() <= options.strip_components {
+            if entry.is_dir() {
+                continue;
+            }
+            return Err(FileOpsError::EmptyPath(entry_path));
+        }
+        let target = parts[options.strip_components..]
+            .iter()
+            .fold(dst_dir.to_path_buf(), |acc, part| acc.join(part));
+
+        if entry.is_dir() {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out = fs::File::create(&target)?;
+        io::copy(&mut entry, &mut out)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies the integrity of a zip archive without extracting it.
+///
+/// # Arguments
+/// * `src_file` - Source .zip file path
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) if the archive is intact, Err otherwise
+///
+/// # Behavior
+/// Reads every entry to the end, which validates its CRC32 checksum.
+pub fn verify_zip(src_file: &Path) -> FileOpsResult<()> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    scan_zip(src_file, &mut ArchiveVerificationResult::default())?;
+    Ok(())
+}
+
+/// Reads a whole zip archive, counting its files and uncompressed bytes in `stats`.
+fn scan_zip(src_file: &Path, stats: &mut ArchiveVerificationResult) -> io::Result<()> {
+    let zip_file = fs::File::open(src_file)?;
+    let mut archive = zip::ZipArchive::new(zip_file)?;
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        let is_file = !entry.is_dir();
+        let bytes = io::copy(&mut entry, &mut io::sink())?;
+        if is_file {
+            stats.file_count += 1;
+            stats.total_uncompressed_bytes += bytes;
+        }
+    }
+
+    Ok(())
+}
+
+/// Outcome of `verify_archive`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveVerificationResult {
+    /// True if the whole archive could be read without errors
+    pub valid: bool,
+    /// Number of files read (up to the first error if the archive is corrupt)
+    pub file_count: usize,
+    /// Uncompressed size of the files read, in bytes
+    pub total_uncompressed_bytes: u64,
+    /// Description of the corruption, if any
+    pub error: Option<String>,
+}
+
+/// Extracts a backup archive, picking the format with `ArchiveFormat::detect_from_path`.
+///
+/// # Arguments
+/// * `src_file` - Source archive (.tar.gz or .zip)
+/// * `dst_dir` - Destination directory to extract to
+///
+/// # Behavior
+/// Files whose format can't be detected are treated as tar.gz.
+pub fn extract_archive(src_file: &Path, dst_dir: &Path) -> FileOpsResult<()> {
+    match ArchiveFormat::detect_from_path(src_file).unwrap_or_default() {
+        ArchiveFormat::TarGz => extract_tar_gz(src_file, dst_dir),
+        ArchiveFormat::Zip => extract_zip(src_file, dst_dir),
+    }
+}
+
+/// Extracts a backup archive with explicit options, picking the format like `extract_archive`.
+///
+/// # Arguments
+/// * `src_file` - Source archive (.tar.gz or .zip)
+/// * `dst_dir` - Destination directory to extract to
+/// * `options` - Extraction options (component stripping, cancellation)
+pub fn extract_archive_with_options(
+    src_file: &Path,
+    dst_dir: &Path,
+    options: &ExtractOptions,
+) -> FileOpsResult<()> {
+    match ArchiveFormat::detect_from_path(src_file).unwrap_or_default() {
+        ArchiveFormat::TarGz => extract_tar_gz_with_options(src_file, dst_dir, options),
+        ArchiveFormat::Zip => extract_zip_with_options(src_file, dst_dir, options),
+    }
+}
+
+/// Extracts only some files of an archive into a directory, replacing existing files.
+///
+/// # Arguments
+/// * `src_file` - Source archive (.tar.gz or .zip)
+/// * `dst_dir` - Destination directory (created if missing; other files are left alone)
+/// * `files` - Paths of the files to extract, relative to the archive root with `/`
+///   separators (e.g. "map/pchunk_0_0.dat")
+///
+/// # Returns
+/// `FileOpsResult<usize>` - Number of files extracted
+///
+/// # Behavior
+/// - Entries are streamed, so nothing outside `files` is written anywhere
+/// - Directories, links and entries whose paths would escape `dst_dir` are skipped,
+///   including entries below a symlink that already exists in `dst_dir`
+/// - Existing files are unlinked before being written, so a symlink in their place is
+///   replaced rather than followed
+pub fn extract_archive_files(
+    src_file: &Path,
+    dst_dir: &Path,
+    files: &HashSet<String>,
+) -> FileOpsResult<usize> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+    fs::create_dir_all(dst_dir)?;
+
+    let selected = |path: &Path| {
+        normal_components(path).and_then(|parts| {
+            let key = parts.iter().map(|part| part.to_string_lossy()).collect::<Vec<_>>();
+            files.contains(&key.join("/")).then_some(parts.iter().collect::<PathBuf>())
+        })
+    };
+
+    let mut extracted = 0;
+    match ArchiveFormat::detect_from_path(src_file).unwrap_or_default() {
+        ArchiveFormat::TarGz => {
+            let mut archive = tar::Archive::new(MultiGzDecoder::new(fs::File::open(src_file)?));
+            for entry in archive.entries()? {
+                let mut entry = entry?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+                let Some(relative) = selected(&entry.path()?) else {
+                    continue;
+                };
+                if has_symlink_parent(dst_dir, &relative) {
+                    continue;
+                }
+                if entry.unpack_in(dst_dir)? {
+                    extracted += 1;
+                }
+            }
+        }
+        ArchiveFormat::Zip => {
+            let zip_file = fs::File::open(src_file)?;
+            let mut archive = zip::ZipArchive::new(zip_file).map_err(io::Error::from)?;
+            for index in 0..archive.len() {
+                let mut entry = archive.by_index(index).map_err(io::Error::from)?;
+                if entry.is_dir() {
+                    continue;
+                }
+                let Some(relative) = selected(Path::new(entry.name())) else {
+                    continue;
+                };
+                if has_symlink_parent(dst_dir, &relative) {
+                    continue;
+                }
+                let target = dst_dir.join(relative);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                // Same as tar: unlink first so a symlink at `target` isn't followed
+                match fs::remove_file(&target) {
+                    Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+                    _ => {}
+                }
+                let mut out = fs::File::options().write(true).create_new(true).open(&target)?;
+                io::copy(&mut entry, &mut out)?;
+                extracted += 1;
+            }
+        }
+    }
+
+    Ok(extracted)
+}
+
+/// Checks an archive for corruption without extracting it.
+///
+/// # Arguments
+/// * `src_file` - Source archive (.tar.gz or .zip)
+///
+/// # Returns
+/// `FileOpsResult<ArchiveVerificationResult>` - Verification outcome; Err only if the
+/// archive does not exist
+///
+/// # Behavior
+/// - Decompresses the whole archive to `io::sink()` and reads every entry, so both
+///   compression and container errors (truncation, bad checksums) are found
+/// - A corrupt archive yields `valid: false` with the error message, not an Err
+/// - Files whose format can't be detected are treated as tar.gz
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::verify_archive;
+///
+/// let result = verify_archive(Path::new("/backup/game_2024-12-28.tar.gz")).unwrap();
+/// if !result.valid {
+///     eprintln!("Corrupt backup: {}", result.error.unwrap_or_default());
+/// }
+/// ```
+pub fn verify_archive(src_file: &Path) -> FileOpsResult<ArchiveVerificationResult> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    let mut result = ArchiveVerificationResult::default();
+    let scan = match ArchiveFormat::detect_from_path(src_file).unwrap_or_default() {
+        ArchiveFormat::TarGz => scan_tar_gz(src_file, &mut result),
+        ArchiveFormat::Zip => scan_zip(src_file, &mut result),
+    };
+    match scan {
+        Ok(()) => result.valid = true,
+        Err(err) => result.error = Some(err.to_string()),
+    }
+
+    Ok(result)
+}
+
+/// File count and uncompressed size of an archive, read by `read_archive_meta`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveMeta {
+    /// Number of files in the archive (directories are not counted)
+    pub entry_count: usize,
+    /// Combined uncompressed size of the files, in bytes
+    pub total_uncompressed_bytes: u64,
+}
+
+/// Reads the file count and uncompressed size of an archive from its entry headers.
+///
+/// # Arguments
+/// * `src_file` - Source archive (.tar.gz or .zip)
+///
+/// # Returns
+/// `FileOpsResult<ArchiveMeta>` - Counts from the headers on success, Err on failure
+///
+/// # Behavior
+/// - Sizes come from the tar headers (or the zip central directory); file contents are
+///   skipped rather than copied, which makes this faster than `verify_archive`
+/// - The gzip stream still has to be decompressed to reach each tar header
+/// - Unlike `verify_archive`, a corrupt archive is an Err
+pub fn read_archive_meta(src_file: &Path) -> FileOpsResult<ArchiveMeta> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    let mut meta = ArchiveMeta::default();
+    match ArchiveFormat::detect_from_path(src_file).unwrap_or_default() {
+        ArchiveFormat::TarGz => {
+            let mut archive = tar::Archive::new(MultiGzDecoder::new(fs::File::open(src_file)?));
+            for entry in archive.entries()? {
+                let entry = entry?;
+                if entry.header().entry_type().is_file() {
+                    meta.entry_count += 1;
+                    meta.total_uncompressed_bytes += entry.size();
+                }
+            }
+        }
+        ArchiveFormat::Zip => {
+            let mut archive =
+                zip::ZipArchive::new(fs::File::open(src_file)?).map_err(io::Error::from)?;
+            for index in 0..archive.len() {
+                let entry = archive.by_index(index).map_err(io::Error::from)?;
+                if !entry.is_dir() {
+                    meta.entry_count += 1;
+                    meta.total_uncompressed_bytes += entry.size();
+                }
+            }
+        }
+    }
+
+    Ok(meta)
+}
+
+/// Reads the records written through `ArchiveOptions::pax_metadata` from a tar.gz archive.
+///
+/// # Arguments
+/// * `src_file` - Source .tar.gz file path
+///
+/// # Returns
+/// `FileOpsResult<HashMap<String, String>>` - The records of the first entry's PAX header
+/// (empty if it has none); only the first entry is read
+pub fn read_tar_gz_pax_metadata(src_file: &Path) -> FileOpsResult<HashMap<String, String>> {
+    let mut archive = tar::Archive::new(MultiGzDecoder::new(fs::File::open(src_file)?));
+    let mut metadata = HashMap::new();
+    let Some(entry) = archive.entries()?.next() else {
+        return Ok(metadata);
+    };
+    if let Some(extensions) = entry?.pax_extensions()? {
+        for extension in extensions {
+            let extension = extension?;
+            if let (Ok(key), Ok(value)) = (extension.key(), extension.value()) {
+                metadata.insert(key.to_string(), value.to_string());
+            }
+        }
+    }
+    Ok(metadata)
+}
+
+/// Reads a specific file from a tar.gz archive and returns base64-encoded data URL.
+///
+/// # Arguments
+/// * `src_file` - Source .tar.gz file path
+/// * `file_path` - Path of the file to read inside the archive (e.g., "thumb.png")
+///
+/// # Returns
+/// `FileOpsResult<Option<String>>` - Some(data URL) if file exists, None if not found
+///
+/// # Behavior
+/// - Searches for the file in the archive
+/// - Returns base64-encoded data URL if found
+/// - Returns None if file not found (not an error)
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::file_ops::read_file_from_tar_gz_base64;
+///
+/// let result = read_file_from_tar_gz_base64(
+///     Path::new("/backup/game_2024-12-28.tar.gz"),
+///     "thumb.png"
+/// ).unwrap();
+/// ```
+pub fn read_file_from_tar_gz_base64(src_file: &Path, file_path: &str) -> FileOpsResult<Option<String>> {
+    if !src_file.exists() {
+        return Err(FileOpsError::SourceNotFound(src_file.to_path_buf()));
+    }
+
+    // Open the gz file and create a decoder
+    let gz_file = fs::File::open(src_file)?;
+    let decoder = MultiGzDecoder::new(gz_file);
+    let mut archive = tar::Archive::new(decoder);
+
+    // Iterate through entries to find the target file
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?;
+
+        // Check if this is the file we're looking for
+        // The path in tar might start with "./", so we need to handle that
+        let entry_path = path.to_string_lossy();
+        let normalized_path = entry_path.strip_prefix("./").unwrap_or(&entry_path);
+
+        if normalized_path == file_path {
+            // Read the file content
+            let mut buffer = Vec::new();
+            entry.read_to_end(&mut buffer)?;
+
+            // Determine MIME type based on file extension
+            let mime_type = if file_path.to_lowercase().ends_with(".png") {
+                "image/png"
+            } else if file_path.to_lowercase().ends_with(".jpg") || file_path.to_lowercase().ends_with(".jpeg") {
+                "image/jpeg"
+            } else if file_path.to_lowercase().ends_with(".gif") {
+                "image/gif"
+            } else if file_path.to_lowercase().ends_with(".webp") {
+                "image/webp"
+            } else {
+                "image/png"
+            };
+
+            // Encode to base64
+            use base64::Engine;
+            let base64_engine = base64::engine::general_purpose::STANDARD;
+            let base64_string = base64_engine.encode(&buffer);
+
+            return Ok(Some(format!("data:{};base64,{}", mime_type, base64_string)));
+        }
+    }
+
+    // File not found in archive
+    Ok(None)
+}
+
+/// Gets the size of a file.
+///
+/// # Arguments
+/// * `path` - Path to the file
+///
+/// # Returns
+/// `FileOpsResult<u64>` - Size in bytes on success, Err on failure
+pub fn get_file_size(path: &Path) -> FileOpsResult<u64> {
+    if !path.exists() {
+        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+    }
+
+    let metadata = fs::metadata(path)?;
+    Ok(metadata.len())
+}
+
+/// Gets the size of a file together with its human-readable form.
+///
+/// # Arguments
+/// * `path` - Path to the file
+///
+/// # Returns
+/// `FileOpsResult<(u64, String)>` - Size in bytes and `format_size` of it
+pub fn get_file_size_human(path: &Path) -> FileOpsResult<(u64, String)> {
+    let bytes = get_file_size(path)?;
+    Ok((bytes, format_size(bytes)))
+}
+
+/// Computes the SHA-256 checksum of a file.
+///
+/// # Arguments
+/// * `path` - Path to the file
+///
+/// # Returns
+/// `FileOpsResult<String>` - Lowercase hex digest on success, Err on failure
+///
+/// # Behavior
+/// The file is streamed in 64 KB chunks, so large archives are not loaded into memory.
+pub fn calculate_checksum_sha256(path: &Path) -> FileOpsResult<String> {
+    if !path.exists() {
+        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+    }
+
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; CHECKSUM_BUFFER_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Computes a fingerprint of a directory's contents for change detection.
+///
+/// # Arguments
+/// * `path` - Directory to fingerprint
+///
+/// # Returns
+/// `FileOpsResult<String>` - Lowercase SHA-256 hex digest on success, Err on failure
+///
+/// # Behavior
+/// - Walks all files in sorted order and hashes each file's relative path (with `/`
+///   separators), size and modification time (whole seconds)
+/// - File contents are not read, so this is cheap even for large saves; a change that
+///   keeps both size and mtime is not detected
+pub fn calculate_dir_checksum(path: &Path) -> FileOpsResult<String> {
+    if !path.exists() {
+        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+    }
+    if !path.is_dir() {
+        return Err(FileOpsError::NotADirectory(path.to_path_buf()));
+    }
+
+    let mut hasher = Sha256::new();
+    for entry in walkdir::WalkDir::new(path).sort_by_file_name() {
+        let entry = entry.map_err(io::Error::from)?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(path).unwrap_or(entry.path());
+        let metadata = entry.metadata().map_err(io::Error::from)?;
+        let mtime_secs = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        // NUL-separated so that paths can't run into the numeric fields
+        hasher.update(normalize_path_for_display(relative).replace('\\', "/").as_bytes());
+        hasher.update([0u8]);
+        hasher.update(metadata.len().to_le_bytes());
+        hasher.update(mtime_secs.to_le_bytes());
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Checks a file against an expected SHA-256 checksum.
+///
+/// # Arguments
+/// * `path` - Path to the file
+/// * `expected` - Expected hex digest (case-insensitive)
+///
+/// # Returns
+/// `FileOpsResult<bool>` - true if the checksum matches, false otherwise
+pub fn verify_checksum_sha256(path: &Path, expected: &str) -> FileOpsResult<bool> {
+    let actual = calculate_checksum_sha256(path)?;
+    Ok(actual.eq_ignore_ascii_case(expected.trim()))
+}
+
+/// Deletes a file.
+///
+/// # Arguments
+/// * `path` - Path to the file to delete
+///
+/// # Returns
+/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
+pub fn delete_file(path: &Path) -> FileOpsResult<()> {
+    if !path.exists() {
+        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+    }
+
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, File};
+    use tempfile::TempDir;
+
+    // Helper function to create a test directory structure
+    fn create_test_structure() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let base = temp_dir.path();
+
+        // Create structure: base/file1.txt, base/subdir/file2.txt, base/subdir/nested/file3.txt
+        File::create(base.join("file1.txt")).unwrap().write_all(b"hello").unwrap();
+        fs::create_dir_all(base.join("subdir/nested")).unwrap();
+        File::create(base.join("subdir/file2.txt"))
+            .unwrap()
+            .write_all(b"world test content")
+            .unwrap();
+        File::create(base.join("subdir/nested/file3.txt"))
+            .unwrap()
+            .write_all(b"nested data here")
+            .unwrap();
+
+        temp_dir
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_success() {
+        let src_dir = create_test_structure();
+        let dst_base = TempDir::new().unwrap();
+        let dst_dir = dst_base.path().join("copy");
+
+        copy_dir_recursive(src_dir.path(), &dst_dir).unwrap();
+
+        // Verify all files were copied
+        assert!(dst_dir.join("file1.txt").exists());
+        assert!(dst_dir.join("subdir/file2.txt").exists());
+        assert!(dst_dir.join("subdir/nested/file3.txt").exists());
+
+        // Verify content matches
+        let src_content = fs::read_to_string(src_dir.path().join("file1.txt")).unwrap();
+        let dst_content = fs::read_to_string(dst_dir.join("file1.txt")).unwrap();
+        assert_eq!(src_content, dst_content);
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_reports_stats() {
+        let src_dir = create_test_structure();
+        let dst_base = TempDir::new().unwrap();
+        let dst_dir = dst_base.path().join("copy");
+
+        let stats = copy_dir_recursive(src_dir.path(), &dst_dir).unwrap();
+        assert_eq!(stats.files_copied, 3);
+        // copy, copy/subdir and copy/subdir/nested
+        assert_eq!(stats.dirs_created, 3);
+        assert!(stats.bytes_copied >= 37, "Expected at least 37 bytes, got {}", stats.bytes_copied);
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_source_not_found() {
+        let dst_base = TempDir::new().unwrap();
+        let result = copy_dir_recursive(Path::new("/nonexistent/path"), dst_base.path());
+        assert!(matches!(result, Err(FileOpsError::SourceNotFound(_))));
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_destination_exists() {
+        let src_dir = create_test_structure();
+        let dst_dir = TempDir::new().unwrap();
+
+        let result = copy_dir_recursive(src_dir.path(), dst_dir.path());
+        assert!(matches!(result, Err(FileOpsError::DestinationExists(_))));
+    }
+
+    /// `RenameFs` that always fails with the given error kind.
+    struct FailingRename(io::ErrorKind);
+
+    impl RenameFs for FailingRename {
+        fn rename(&self, _from: &Path, _to: &Path) -> io::Result<()> {
+            Err(io::Error::from(self.0))
+        }
+    }
+
+    #[test]
+    fn test_copy_file_atomic_success() {
+        let temp_dir = create_test_structure();
+        let dst = temp_dir.path().join("copy.txt");
+
+        copy_file_atomic(&temp_dir.path().join("file1.txt"), &dst).unwrap();
+
+        assert_eq!(fs::read_to_string(&dst).unwrap(), "hello");
+        assert!(!temp_dir.path().join("copy.txt.tmp").exists());
+    }
+
+    #[test]
+    fn test_copy_file_atomic_rename_failure_removes_tmp() {
+        let temp_dir = create_test_structure();
+        let dst = temp_dir.path().join("copy.txt");
+
+        let result = copy_file_atomic_with(
+            &temp_dir.path().join("file1.txt"),
+            &dst,
+            &mut None,
+            &FailingRename(io::ErrorKind::PermissionDenied),
+        );
+
+        assert!(matches!(result, Err(FileOpsError::Io(_))));
+        assert!(!dst.exists());
+        assert!(!temp_dir.path().join("copy.txt.tmp").exists());
+    }
+
+    #[test]
+    fn test_copy_file_atomic_cross_device_falls_back_to_copy() {
+        let temp_dir = create_test_structure();
+        let dst = temp_dir.path().join("copy.txt");
+
+        let bytes = copy_file_atomic_with(
+            &temp_dir.path().join("file1.txt"),
+            &dst,
+            &mut None,
+            &FailingRename(io::ErrorKind::CrossesDevices),
+        )
+        .unwrap();
+
+        assert_eq!(bytes, 5);
+        assert_eq!(fs::read_to_string(&dst).unwrap(), "hello");
+        assert!(!temp_dir.path().join("copy.txt.tmp").exists());
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_large_file_leaves_no_tmp() {
+        let src_dir = create_test_structure();
+        let large = vec![b'x'; ATOMIC_THRESHOLD_BYTES as usize + 1];
+        fs::write(src_dir.path().join("large.bin"), &large).unwrap();
+        let dst_temp = TempDir::new().unwrap();
+        let dst_path = dst_temp.path().join("copy");
+
+        copy_dir_recursive(src_dir.path(), &dst_path).unwrap();
+
+        assert_eq!(fs::read(dst_path.join("large.bin")).unwrap(), large);
+        assert!(!dst_path.join("large.bin.tmp").exists());
+    }
+
+    #[test]
+    fn test_delete_dir_recursive_success() {
+        let temp_dir = create_test_structure();
+        let path = temp_dir.path();
+
+        delete_dir_recursive(path).unwrap();
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_delete_dir_recursive_not_found() {
+        let result = delete_dir_recursive(Path::new("/nonexistent/path"));
+        assert!(matches!(result, Err(FileOpsError::SourceNotFound(_))));
+    }
+
+    #[test]
+    fn test_delete_dir_recursive_not_a_directory() {
+        let temp_file = TempDir::new().unwrap();
+        let file_path = temp_file.path().join("file.txt");
+        File::create(&file_path).unwrap().write_all(b"test").unwrap();
+
+        let result = delete_dir_recursive(&file_path);
+        assert!(matches!(result, Err(FileOpsError::NotADirectory(_))));
+    }
+
+    #[test]
+    fn test_get_dir_size_success() {
+        let temp_dir = create_test_structure();
+        let path = temp_dir.path();
+
+        // file1.txt: "hello" = 5 bytes
+        // file2.txt: "world test content" = 17 bytes
+        // file3.txt: "nested data here" = 15 bytes
+        // Total: 37 bytes
+        let size = get_dir_size(path).unwrap();
+        // Note: on some systems, directories may have metadata that adds bytes
+        // We check that the size is at least the expected file content size
+        assert!(size >= 37, "Expected at least 37 bytes, got {}", size);
+        // And not unreasonably large
+        assert!(size < 1024, "Expected less than 1KB, got {}", size);
+    }
+
+    #[test]
+    fn test_get_dir_size_empty_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let size = get_dir_size(temp_dir.path()).unwrap();
+        assert_eq!(size, 0);
+    }
+
+    #[test]
+    fn test_get_dir_last_modified() {
+        let temp_dir = create_test_structure();
+        let newest = SystemTime::UNIX_EPOCH + Duration::from_secs(2_000_000_000);
+        let file = fs::File::options()
+            .write(true)
+            .open(temp_dir.path().join("subdir/nested/file3.txt"))
+            .unwrap();
+        file.set_modified(newest).unwrap();
+
+        assert_eq!(get_dir_last_modified(temp_dir.path()).unwrap(), Some(newest));
+        let empty = TempDir::new().unwrap();
+        assert_eq!(get_dir_last_modified(empty.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_get_dir_size_not_found() {
+        let result = get_dir_size(Path::new("/nonexistent/path"));
+        assert!(matches!(result, Err(FileOpsError::SourceNotFound(_))));
+    }
+
+    #[test]
+    fn test_get_dir_size_not_a_directory() {
+        let temp_file = TempDir::new().unwrap();
+        let file_path = temp_file.path().join("file.txt");
+        File::create(&file_path).unwrap().write_all(b"test").unwrap();
+
+        let result = get_dir_size(&file_path);
+        assert!(matches!(result, Err(FileOpsError::NotADirectory(_))));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_size_symlink_cycle() {
+        let temp_dir = create_test_structure();
+        let path = temp_dir.path();
+        let expected = get_dir_size(path).unwrap();
+
+        // subdir/loop -> .. would recurse forever if links were followed
+        std::os::unix::fs::symlink("..", path.join("subdir").join("loop")).unwrap();
+
+        let size = get_dir_size(path).unwrap();
+        assert_eq!(size, expected);
+    }
+
+    /// `DeviceFs` that puts every directory named `mount` on a device of its own.
+    struct FakeMount;
+
+    impl DeviceFs for FakeMount {
+        fn device_id(&self, path: &Path) -> io::Result<u64> {
+            Ok(if path.ends_with("mount") { 2 } else { 1 })
+        }
+    }
+
+    #[test]
+    fn test_get_dir_size_with_options_across_file_systems() {
+        let temp_dir = create_test_structure();
+        let expected = get_dir_size(temp_dir.path()).unwrap();
+        fs::create_dir_all(temp_dir.path().join("subdir/mount/nested")).unwrap();
+        fs::write(temp_dir.path().join("subdir/mount/nested/big.bin"), [0u8; 100]).unwrap();
+
+        let same_file_system = DirSizeOptions::default();
+        let size = get_dir_size_with(temp_dir.path(), &same_file_system, &FakeMount).unwrap();
+        assert_eq!(size, expected);
+
+        let options = DirSizeOptions {
+            same_file_system: false,
+        };
+        let size = get_dir_size_with(temp_dir.path(), &options, &FakeMount).unwrap();
+        assert_eq!(size, expected + 100);
+    }
+
+    #[test]
+    fn test_normalize_path_for_display_keeps_absolute_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("backups").join("Survival.tar.gz");
+        assert_eq!(PathBuf::from(normalize_path_for_display(&path)), path);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_normalize_path_for_display_unc_path() {
+        let path: PathBuf = [r"\\server\share\", "backups", "Survival", "save.tar.gz"]
+            .iter()
+            .collect();
+        assert_eq!(
+            normalize_path_for_display(&path),
+            r"\\server\share\backups\Survival\save.tar.gz"
+        );
+
+        let verbatim = Path::new(r"\\?\UNC\server\share\backups");
+        assert_eq!(normalize_path_for_display(verbatim), r"\\?\UNC\server\share\backups");
+    }
+
+    #[test]
+    fn test_available_space() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(available_space(temp_dir.path()).unwrap() > 0);
+        // Paths that don't exist yet report the space of their nearest existing ancestor
+        let missing = temp_dir.path().join("not/created/yet");
+        assert!(available_space(&missing).unwrap() > 0);
+    }
+
+    #[test]
+    fn test_format_size_bytes() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1023), "1023 B");
+    }
+
+    #[test]
+    fn test_format_size_kilobytes() {
+        assert_eq!(format_size(1024), "1.00 KB");
+        assert_eq!(format_size(1536), "1.50 KB");
+        assert_eq!(format_size(10240), "10.00 KB");
+    }
+
+    #[test]
+    fn test_format_size_megabytes() {
+        assert_eq!(format_size(1_048_576), "1.00 MB");
+        assert_eq!(format_size(5_242_880), "5.00 MB");
+        assert_eq!(format_size(123456789), "117.74 MB");
+    }
+
+    #[test]
+    fn test_format_size_gigabytes() {
+        assert_eq!(format_size(1_073_741_824), "1.00 GB");
+        assert_eq!(format_size(2_147_483_648), "2.00 GB");
+        assert_eq!(format_size(123456789012), "114.98 GB");
+    }
+
+    #[test]
+    fn test_get_file_size_human() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("data.bin");
+        fs::write(&file_path, vec![0u8; 1536]).unwrap();
+
+        let (bytes, formatted) = get_file_size_human(&file_path).unwrap();
+        assert_eq!(bytes, 1536);
+        assert_eq!(formatted, format_size(bytes));
+        assert_eq!(formatted, "1.50 KB");
+    }
+
+    #[test]
+    fn test_calculate_checksum_sha256() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("hello.txt");
+        fs::write(&file_path, b"hello").unwrap();
+
+        assert_eq!(
+            calculate_checksum_sha256(&file_path).unwrap(),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert!(matches!(
+            calculate_checksum_sha256(&temp_dir.path().join("missing")),
+            Err(FileOpsError::SourceNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_calculate_dir_checksum() {
+        let temp_dir = create_test_structure();
+        let original = calculate_dir_checksum(temp_dir.path()).unwrap();
+        assert_eq!(original.len(), 64);
+        assert_eq!(calculate_dir_checksum(temp_dir.path()).unwrap(), original);
+
+        // A size change is picked up
+        fs::write(temp_dir.path().join("file1.txt"), "hello, zomboid").unwrap();
+        let modified = calculate_dir_checksum(temp_dir.path()).unwrap();
+        assert_ne!(modified, original);
+
+        // So is a new file
+        fs::write(temp_dir.path().join("subdir/new.txt"), "new").unwrap();
+        assert_ne!(calculate_dir_checksum(temp_dir.path()).unwrap(), modified);
+
+        assert!(matches!(
+            calculate_dir_checksum(&temp_dir.path().join("file1.txt")),
+            Err(FileOpsError::NotADirectory(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_checksum_sha256() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("hello.txt");
+        fs::write(&file_path, b"hello").unwrap();
+
+        let expected = "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824";
+        assert!(verify_checksum_sha256(&file_path, expected).unwrap());
+        let wrong = "0000000000000000000000000000000000000000000000000000000000000000";
+        assert!(!verify_checksum_sha256(&file_path, wrong).unwrap());
+    }
+
+    #[test]
+    fn test_get_dir_size_human() {
+        let temp_dir = create_test_structure();
+
+        let (bytes, formatted) = get_dir_size_human(temp_dir.path()).unwrap();
+        assert_eq!(bytes, get_dir_size(temp_dir.path()).unwrap());
+        assert_eq!(formatted, format_size(bytes));
+    }
+
+    #[test]
+    fn test_copy_preserves_content() {
+        let src_dir = create_test_structure();
+        let dst_base = TempDir::new().unwrap();
+        let dst_dir = dst_base.path().join("copy");
+
+        copy_dir_recursive(src_dir.path(), &dst_dir).unwrap();
+
+        // Verify content of all files
+        let content1 = fs::read_to_string(dst_dir.join("file1.txt")).unwrap();
+        assert_eq!(content1, "hello");
+
+        let content2 = fs::read_to_string(dst_dir.join("subdir/file2.txt")).unwrap();
+        assert_eq!(content2, "world test content");
+
+        let content3 = fs::read_to_string(dst_dir.join("subdir/nested/file3.txt")).unwrap();
+        assert_eq!(content3, "nested data here");
+    }
+
+    #[test]
+    fn test_copy_deeply_nested_structure() {
+        let src_base = TempDir::new().unwrap();
+        let mut current = src_base.path().to_path_buf();
+
+        // Create a deep directory structure (10 levels)
+        for i in 0..10 {
+            current = current.join(format!("level_{}", i));
+            fs::create_dir(&current).unwrap();
+            File::create(current.join(format!("file_{}.txt", i)))
+                .unwrap()
+                .write_all(format!("content_{}", i).as_bytes())
+                .unwrap();
+        }
+
+        let dst_base = TempDir::new().unwrap();
+        let dst_dir = dst_base.path().join("deep_copy");
+
+        copy_dir_recursive(src_base.path(), &dst_dir).unwrap();
+
+        // Verify deep structure was copied
+        let deep_file = dst_dir.join("level_0/level_1/level_2/level_3/level_4/level_5/level_6/level_7/level_8/level_9/file_9.txt");
+        assert!(deep_file.exists());
+    }
+
+    /// `RateClock` whose time only moves when it is asked to sleep.
+    #[derive(Clone)]
+    struct FakeClock {
+        now: Arc<Mutex<Instant>>,
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self {
+                now: Arc::new(Mutex::new(Instant::now())),
+                sleeps: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        fn sleeps(&self) -> Vec<Duration> {
+            self.sleeps.lock().unwrap().clone()
+        }
+    }
+
+    impl RateClock for FakeClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+            self.sleeps.lock().unwrap().push(duration);
+        }
+    }
+
+    #[test]
+    fn test_copy_file_rate_limited() {
+        let src_dir = TempDir::new().unwrap();
+        let data = vec![0xABu8; 5 * 1024 * 1024];
+        let src = src_dir.path().join("big.bin");
+        fs::write(&src, &data).unwrap();
+        let dst = src_dir.path().join("copy.bin");
+
+        let clock = FakeClock::new();
+        let mut limiter = Some(RateLimiter::with_clock(1024 * 1024, Box::new(clock.clone())));
+        assert_eq!(copy_file(&src, &dst, &mut limiter).unwrap(), data.len() as u64);
+
+        // 5 MiB at 1 MiB/s: the sleeps add up to the file's 5 seconds of budget
+        let sleeps = clock.sleeps();
+        let total: Duration = sleeps.iter().sum();
+        assert!(total > Duration::from_millis(4900), "Slept only {:?}", total);
+        assert!(total <= Duration::from_secs(5), "Slept {:?}", total);
+        assert!(sleeps.iter().all(|sleep| *sleep <= Duration::from_secs(1)));
+        assert_eq!(fs::metadata(&dst).unwrap().len(), data.len() as u64);
+    }
+
+    #[test]
+    fn test_rate_limiter_allows_burst_within_budget() {
+        let clock = FakeClock::new();
+        let mut limiter = RateLimiter::with_clock(10 * 1024 * 1024, Box::new(clock.clone()));
+        limiter.consume(1024);
+        // Only the 1 KiB's own share of the second is waited for
+        let total: Duration = clock.sleeps().iter().sum();
+        assert!(total <= Duration::from_micros(100), "Slept {:?}", total);
+    }
+
+    #[test]
+    fn test_extract_tar_gz_strip_components() {
+        let src_base = create_test_structure();
+        let wrapper = TempDir::new().unwrap();
+        copy_dir_recursive(src_base.path(), &wrapper.path().join("MySave")).unwrap();
+
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("import.tar.gz");
+        let extract_dir = work_dir.path().join("extracted");
+        create_tar_gz(wrapper.path(), &archive).unwrap();
+
+        let options = ExtractOptions {
+            strip_components: 1,
+            ..Default::default()
+        };
+        extract_tar_gz_with_options(&archive, &extract_dir, &options).unwrap();
+
+        assert!(!extract_dir.join("MySave").exists());
+        assert_eq!(fs::read_to_string(extract_dir.join("file1.txt")).unwrap(), "hello");
+        assert_eq!(
+            fs::read_to_string(extract_dir.join("subdir/nested/file3.txt")).unwrap(),
+            "nested data here"
+        );
+    }
+
+    #[test]
+    fn test_extract_archive_files() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("backup.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+
+        let dst_dir = work_dir.path().join("save");
+        fs::create_dir_all(&dst_dir).unwrap();
+        fs::write(dst_dir.join("file1.txt"), "live").unwrap();
+        fs::write(dst_dir.join("keep.txt"), "untouched").unwrap();
+
+        let files: HashSet<String> = ["file1.txt", "subdir/nested/file3.txt"]
+            .iter()
+            .map(|file| file.to_string())
+            .collect();
+        assert_eq!(extract_archive_files(&archive, &dst_dir, &files).unwrap(), 2);
+
+        assert_eq!(fs::read_to_string(dst_dir.join("file1.txt")).unwrap(), "hello");
+        assert!(dst_dir.join("subdir/nested/file3.txt").exists());
+        assert!(!dst_dir.join("subdir/file2.txt").exists());
+        assert_eq!(fs::read_to_string(dst_dir.join("keep.txt")).unwrap(), "untouched");
+    }
+
+    #[cfg(unix)]
+    fn assert_extract_files_skips_symlinked_dir(archive: &Path) {
+        let work_dir = TempDir::new().unwrap();
+        let outside = work_dir.path().join("outside");
+        fs::create_dir_all(&outside).unwrap();
+
+        // The live save has `subdir` linked outside, so `subdir/file2.txt` must not go through it
+        let dst_dir = work_dir.path().join("save");
+        fs::create_dir_all(&dst_dir).unwrap();
+        std::os::unix::fs::symlink(&outside, dst_dir.join("subdir")).unwrap();
+
+        let files: HashSet<String> =
+            ["file1.txt", "subdir/file2.txt"].iter().map(|file| file.to_string()).collect();
+        assert_eq!(extract_archive_files(archive, &dst_dir, &files).unwrap(), 1);
+
+        assert_eq!(fs::read_to_string(dst_dir.join("file1.txt")).unwrap(), "hello");
+        assert!(!outside.join("file2.txt").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_extract_archive_files_tar_gz_skips_symlinked_dir() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("backup.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+        assert_extract_files_skips_symlinked_dir(&archive);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_extract_archive_files_zip_skips_symlinked_dir() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("backup.zip");
+        create_zip(src_dir.path(), &archive).unwrap();
+        assert_extract_files_skips_symlinked_dir(&archive);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_extract_archive_files_zip_replaces_symlinked_file() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("backup.zip");
+        create_zip(src_dir.path(), &archive).unwrap();
+
+        let outside = work_dir.path().join("outside.txt");
+        fs::write(&outside, "outside").unwrap();
+        let dst_dir = work_dir.path().join("save");
+        fs::create_dir_all(&dst_dir).unwrap();
+        std::os::unix::fs::symlink(&outside, dst_dir.join("file1.txt")).unwrap();
+
+        let files: HashSet<String> = ["file1.txt".to_string()].into_iter().collect();
+        assert_eq!(extract_archive_files(&archive, &dst_dir, &files).unwrap(), 1);
+
+        assert_eq!(fs::read_to_string(&outside).unwrap(), "outside");
+        let meta = fs::symlink_metadata(dst_dir.join("file1.txt")).unwrap();
+        assert!(!meta.file_type().is_symlink());
+        assert_eq!(fs::read_to_string(dst_dir.join("file1.txt")).unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_extract_tar_gz_strip_components_empty_path() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("flat.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+
+        // file1.txt sits at the archive root, so stripping one component leaves nothing
+        let options = ExtractOptions {
+            strip_components: 1,
+            ..Default::default()
+        };
+        let result = extract_tar_gz_with_options(&archive, &work_dir.path().join("out"), &options);
+        assert!(matches!(result, Err(FileOpsError::EmptyPath(_))));
+    }
+
+    #[test]
+    fn test_extract_tar_gz_strip_components_skips_symlink_escape() {
+        let work_dir = TempDir::new().unwrap();
+        let outside = work_dir.path().join("outside");
+        fs::create_dir_all(&outside).unwrap();
+
+        // `MySave/link` points outside, then `MySave/link/evil.txt` is written through it
+        let archive = work_dir.path().join("malicious.tar.gz");
+        let encoder = GzEncoder::new(fs::File::create(&archive).unwrap(), Compression::default());
+        let mut builder = Builder::new(encoder);
+        let mut link = tar::Header::new_gnu();
+        link.set_entry_type(tar::EntryType::Symlink);
+        link.set_size(0);
+        builder.append_link(&mut link, "MySave/link", &outside).unwrap();
+        let mut file = tar::Header::new_gnu();
+        file.set_size(4);
+        file.set_mode(0o644);
+        builder.append_data(&mut file, "MySave/link/evil.txt", &b"evil"[..]).unwrap();
+        let mut save = tar::Header::new_gnu();
+        save.set_size(4);
+        save.set_mode(0o644);
+        builder.append_data(&mut save, "MySave/save.bin", &b"save"[..]).unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let extract_dir = work_dir.path().join("extracted");
+        let options = ExtractOptions {
+            strip_components: 1,
+            ..Default::default()
+        };
+        extract_tar_gz_with_options(&archive, &extract_dir, &options).unwrap();
+
+        assert!(!outside.join("evil.txt").exists());
+        // The link entry is dropped, so the file lands in a plain directory instead
+        let link_meta = fs::symlink_metadata(extract_dir.join("link")).unwrap();
+        assert!(!link_meta.file_type().is_symlink());
+        assert_eq!(fs::read_to_string(extract_dir.join("save.bin")).unwrap(), "save");
+    }
+
+    #[test]
+    fn test_delete_dir_dry_run() {
+        let temp_dir = create_test_structure();
+        let options = DeleteOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+
+        let result = delete_dir_with_options(temp_dir.path(), &options).unwrap();
+
+        let base = temp_dir.path();
+        let mut expected = vec![
+            base.to_path_buf(),
+            base.join("file1.txt"),
+            base.join("subdir"),
+            base.join("subdir/file2.txt"),
+            base.join("subdir/nested"),
+            base.join("subdir/nested/file3.txt"),
+        ];
+        let mut paths = result.paths.clone();
+        expected.sort();
+        paths.sort();
+        assert_eq!(paths, expected);
+        assert_eq!(result.total_bytes, get_dir_size(base).unwrap());
+
+        // Nothing was deleted
+        assert!(base.join("subdir/nested/file3.txt").exists());
+    }
+
+    #[test]
+    fn test_delete_dir_non_recursive() {
+        let temp_dir = create_test_structure();
+        let options = DeleteOptions {
+            recursive: false,
+            ..Default::default()
+        };
+
+        let result = delete_dir_with_options(temp_dir.path(), &options);
+        assert!(matches!(result, Err(FileOpsError::DirectoryNotEmpty(_))));
+        assert!(temp_dir.path().join("file1.txt").exists());
+
+        // Empty directories are removed like `rmdir`
+        let empty = temp_dir.path().join("empty");
+        fs::create_dir(&empty).unwrap();
+        let result = delete_dir_with_options(&empty, &options).unwrap();
+        assert_eq!(result.paths, vec![empty.clone()]);
+        assert!(!empty.exists());
+    }
+
+    #[test]
+    fn test_delete_dir_with_options_deletes() {
+        let temp_dir = create_test_structure();
+        let target = temp_dir.path().join("subdir");
+
+        let result = delete_dir_with_options(&target, &DeleteOptions::default()).unwrap();
+        assert_eq!(result.paths.len(), 4);
+        assert!(!target.exists());
+        assert!(temp_dir.path().join("file1.txt").exists());
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.log", "debug.log"));
+        assert!(wildcard_match("map/*", "map/pchunk_0_0.dat"));
+        assert!(wildcard_match("file?.txt", "file1.txt"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("*.log", "debug.txt"));
+        assert!(!wildcard_match("file?.txt", "file10.txt"));
+    }
+
+    #[test]
+    fn test_create_tar_gz_with_exclude_patterns() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("filtered.tar.gz");
+
+        let options = ArchiveOptions {
+            // One pattern matches by file name, the other by relative path
+            exclude_patterns: vec!["file2.*".to_string(), "subdir/nested".to_string()],
+            ..Default::default()
+        };
+        create_tar_gz_with_options(src_dir.path(), &archive, &options).unwrap();
+
+        let out = work_dir.path().join("out");
+        extract_tar_gz(&archive, &out).unwrap();
+        assert_eq!(fs::read_to_string(out.join("file1.txt")).unwrap(), "hello");
+        assert!(out.join("subdir").is_dir());
+        assert!(!out.join("subdir/file2.txt").exists());
+        assert!(!out.join("subdir/nested").exists());
+    }
+
+    #[test]
+    fn test_create_tar_gz_include_only_and_list_files() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("partial.tar.gz");
+
+        let file2 = normalize_path_for_display(Path::new("subdir/file2.txt"));
+        let options = ArchiveOptions {
+            include_only: Some(HashSet::from([file2.clone()])),
+            ..Default::default()
+        };
+        create_tar_gz_with_options(src_dir.path(), &archive, &options).unwrap();
+
+        let files = list_tar_gz_files(&archive).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, file2);
+        assert_eq!(files[0].size_bytes, 18);
+        assert!(files[0].modified.is_some());
+
+        // Directories are kept even when none of their files are included
+        let out = work_dir.path().join("out");
+        extract_tar_gz(&archive, &out).unwrap();
+        assert!(!out.join("file1.txt").exists());
+        assert!(out.join("subdir/nested").is_dir());
+    }
+
+    #[test]
+    fn test_extract_tar_gz_over_existing_dir() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("overlay.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+
+        let dst = work_dir.path().join("dst");
+        fs::create_dir_all(&dst).unwrap();
+        fs::write(dst.join("file1.txt"), "stale contents").unwrap();
+        fs::write(dst.join("extra.txt"), "kept").unwrap();
+
+        extract_tar_gz_over(&archive, &dst).unwrap();
+        assert_eq!(fs::read_to_string(dst.join("file1.txt")).unwrap(), "hello");
+        assert_eq!(fs::read_to_string(dst.join("extra.txt")).unwrap(), "kept");
+        assert!(dst.join("subdir/nested/file3.txt").exists());
+    }
+
+    /// Writer that fails once more than `remaining` bytes have been written.
+    struct FailingWriter {
+        inner: fs::File,
+        remaining: usize,
+    }
+
+    impl Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.len() > self.remaining {
+                return Err(io::Error::new(io::ErrorKind::StorageFull, "disk full"));
+            }
+            self.remaining -= buf.len();
+            self.inner.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    #[test]
+    fn test_create_tar_gz_removes_partial_file_on_failure() {
+        let src_dir = TempDir::new().unwrap();
+        // Pseudo-random bytes, so the gzip output is about as large as the input
+        let mut state: u32 = 12345;
+        let data: Vec<u8> = (0..256 * 1024)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect();
+        fs::write(src_dir.path().join("map.bin"), data).unwrap();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("partial.tar.gz");
+
+        let result = create_tar_gz_with_writer(
+            src_dir.path(),
+            &archive,
+            &ArchiveOptions::default(),
+            |file| FailingWriter { inner: file, remaining: 64 * 1024 },
+            None,
+        );
 
-        // Check if this is the file we're looking for
-        // The path in tar might start with "./", so we need to handle that
-        let entry_path = path.to_string_lossy();
-        let normalized_path = entry_path.strip_prefix("./").unwrap_or(&entry_path);
+        assert!(result.is_err());
+        assert!(!archive.exists());
+        assert_eq!(fs::read_dir(work_dir.path()).unwrap().count(), 0);
+    }
 
-        if normalized_path == file_path {
-            // Read the file content
-            let mut buffer = Vec::new();
-            entry.read_to_end(&mut buffer)?;
+    #[test]
+    fn test_create_tar_gz_parallel_round_trip() {
+        let src_dir = TempDir::new().unwrap();
+        // Several blocks' worth of pseudo-random bytes, so every thread gets work
+        let mut state: u32 = 54321;
+        let data: Vec<u8> = (0..3 * PARALLEL_GZIP_BLOCK_SIZE + 123)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect();
+        fs::create_dir_all(src_dir.path().join("map")).unwrap();
+        fs::write(src_dir.path().join("map/chunk.bin"), &data).unwrap();
+        fs::write(src_dir.path().join("save.bin"), b"game state").unwrap();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("parallel.tar.gz");
 
-            // Determine MIME type based on file extension
-            let mime_type = if file_path.to_lowercase().ends_with(".png") {
-                "image/png"
-            } else if file_path.to_lowercase().ends_with(".jpg") || file_path.to_lowercase().ends_with(".jpeg") {
-                "image/jpeg"
-            } else if file_path.to_lowercase().ends_with(".gif") {
-                "image/gif"
-            } else if file_path.to_lowercase().ends_with(".webp") {
-                "image/webp"
-            } else {
-                "image/png"
-            };
+        create_tar_gz_parallel(src_dir.path(), &archive, 4).unwrap();
 
-            // Encode to base64
-            use base64::Engine;
-            let base64_engine = base64::engine::general_purpose::STANDARD;
-            let base64_string = base64_engine.encode(&buffer);
+        // More than one gzip member: a single-member decoder stops after the first block
+        let mut first_member = Vec::new();
+        flate2::read::GzDecoder::new(fs::File::open(&archive).unwrap())
+            .read_to_end(&mut first_member)
+            .unwrap();
+        assert_eq!(first_member.len(), PARALLEL_GZIP_BLOCK_SIZE);
 
-            return Ok(Some(format!("data:{};base64,{}", mime_type, base64_string)));
-        }
+        verify_tar_gz(&archive).unwrap();
+        let extracted = work_dir.path().join("extracted");
+        extract_tar_gz(&archive, &extracted).unwrap();
+        assert_eq!(fs::read(extracted.join("map/chunk.bin")).unwrap(), data);
+        assert_eq!(fs::read(extracted.join("save.bin")).unwrap(), b"game state");
     }
 
-    // File not found in archive
-    Ok(None)
-}
+    #[test]
+    fn test_create_tar_gz_parallel_empty_dir() {
+        let src_dir = TempDir::new().unwrap();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("empty.tar.gz");
 
-/// Gets the size of a file.
-///
-/// # Arguments
-/// * `path` - Path to the file
-///
-/// # Returns
-/// `FileOpsResult<u64>` - Size in bytes on success, Err on failure
-pub fn get_file_size(path: &Path) -> FileOpsResult<u64> {
-    if !path.exists() {
-        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
+        create_tar_gz_parallel(src_dir.path(), &archive, 2).unwrap();
+
+        verify_tar_gz(&archive).unwrap();
+        assert!(list_tar_gz_files(&archive).unwrap().is_empty());
     }
 
-    let metadata = fs::metadata(path)?;
-    Ok(metadata.len())
-}
+    #[test]
+    fn test_cancelled_archive_operations() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("archive.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+        let cancel = CancelFlag::new(AtomicBool::new(true));
 
-/// Deletes a file.
-///
-/// # Arguments
-/// * `path` - Path to the file to delete
-///
-/// # Returns
-/// `FileOpsResult<()>` - Ok(()) on success, Err on failure
-pub fn delete_file(path: &Path) -> FileOpsResult<()> {
-    if !path.exists() {
-        return Err(FileOpsError::SourceNotFound(path.to_path_buf()));
-    }
+        let options = ArchiveOptions {
+            cancel: Some(cancel.clone()),
+            ..Default::default()
+        };
+        let tar_gz = work_dir.path().join("cancelled.tar.gz");
+        let result = create_tar_gz_with_options(src_dir.path(), &tar_gz, &options);
+        assert!(matches!(result, Err(FileOpsError::Cancelled)));
+        let zip = work_dir.path().join("cancelled.zip");
+        let result = create_zip_with_options(src_dir.path(), &zip, &options);
+        assert!(matches!(result, Err(FileOpsError::Cancelled)));
+        // Partial archives are removed
+        assert!(!tar_gz.exists());
+        assert!(!zip.exists());
+        assert_eq!(fs::read_dir(work_dir.path()).unwrap().count(), 1);
 
-    fs::remove_file(path)?;
-    Ok(())
-}
+        let options = ExtractOptions {
+            cancel: Some(cancel),
+            ..Default::default()
+        };
+        let result = extract_tar_gz_with_options(&archive, &work_dir.path().join("out"), &options);
+        assert!(matches!(result, Err(FileOpsError::Cancelled)));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs::{self, File};
-    use tempfile::TempDir;
+    #[test]
+    fn test_cancellation_registry() {
+        let registry = CancellationRegistry::new();
+        assert!(!registry.cancel(OperationKind::Backup, "Survival"));
 
-    // Helper function to create a test directory structure
-    fn create_test_structure() -> TempDir {
-        let temp_dir = TempDir::new().unwrap();
-        let base = temp_dir.path();
+        let guard = registry.register(OperationKind::Backup, "Survival").unwrap();
+        assert!(!registry.cancel(OperationKind::Restore, "Survival"));
+        assert!(!guard.flag().load(Ordering::Relaxed));
 
-        // Create structure: base/file1.txt, base/subdir/file2.txt, base/subdir/nested/file3.txt
-        File::create(base.join("file1.txt")).unwrap().write_all(b"hello").unwrap();
-        fs::create_dir_all(base.join("subdir/nested")).unwrap();
-        File::create(base.join("subdir/file2.txt"))
-            .unwrap()
-            .write_all(b"world test content")
-            .unwrap();
-        File::create(base.join("subdir/nested/file3.txt"))
-            .unwrap()
-            .write_all(b"nested data here")
-            .unwrap();
+        // A second backup of the same save can't take over the running one's flag
+        assert!(registry.register(OperationKind::Backup, "Survival").is_none());
+        assert!(registry.cancel(OperationKind::Backup, "Survival"));
+        assert!(guard.flag().load(Ordering::Relaxed));
 
-        temp_dir
+        drop(guard);
+        assert!(!registry.cancel(OperationKind::Backup, "Survival"));
+        assert!(registry.register(OperationKind::Backup, "Survival").is_some());
     }
 
     #[test]
-    fn test_copy_dir_recursive_success() {
+    fn test_create_tar_gz_with_progress() {
         let src_dir = create_test_structure();
-        let dst_base = TempDir::new().unwrap();
-        let dst_dir = dst_base.path().join("copy");
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("progress.tar.gz");
 
-        copy_dir_recursive(src_dir.path(), &dst_dir).unwrap();
+        let mut calls = Vec::new();
+        create_tar_gz_with_progress(
+            src_dir.path(),
+            &archive,
+            &ArchiveOptions::default(),
+            |processed, total| calls.push((processed, total)),
+        )
+        .unwrap();
 
-        // Verify all files were copied
-        assert!(dst_dir.join("file1.txt").exists());
-        assert!(dst_dir.join("subdir/file2.txt").exists());
-        assert!(dst_dir.join("subdir/nested/file3.txt").exists());
+        // file1.txt (5) + file2.txt (18) + file3.txt (16)
+        let total = 39;
+        // Start, one call per file, completion
+        assert_eq!(calls.len(), 5);
+        assert_eq!(calls.first(), Some(&(0, total)));
+        assert_eq!(calls.last(), Some(&(total, total)));
+        assert!(calls.iter().all(|&(_, t)| t == total));
+        assert!(calls.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
 
-        // Verify content matches
-        let src_content = fs::read_to_string(src_dir.path().join("file1.txt")).unwrap();
-        let dst_content = fs::read_to_string(dst_dir.join("file1.txt")).unwrap();
-        assert_eq!(src_content, dst_content);
+    #[test]
+    fn test_create_tar_gz_compression_level() {
+        let src_dir = TempDir::new().unwrap();
+        fs::write(src_dir.path().join("data.txt"), "zomboid ".repeat(10_000)).unwrap();
+        let work_dir = TempDir::new().unwrap();
+
+        let stored = work_dir.path().join("stored.tar.gz");
+        let best = work_dir.path().join("best.tar.gz");
+        let level = |level| ArchiveOptions {
+            compression_level: Some(level),
+            ..Default::default()
+        };
+        create_tar_gz_with_options(src_dir.path(), &stored, &level(0)).unwrap();
+        create_tar_gz_with_options(src_dir.path(), &best, &level(9)).unwrap();
+
+        assert!(get_file_size(&best).unwrap() < get_file_size(&stored).unwrap());
+        extract_tar_gz(&stored, &work_dir.path().join("out")).unwrap();
     }
 
     #[test]
-    fn test_copy_dir_recursive_source_not_found() {
-        let dst_base = TempDir::new().unwrap();
-        let result = copy_dir_recursive(Path::new("/nonexistent/path"), dst_base.path());
-        assert!(matches!(result, Err(FileOpsError::SourceNotFound(_))));
+    fn test_create_tar_gz_truncates_file_inventory() {
+        let src_dir = TempDir::new().unwrap();
+        for i in 0..=MAX_FILE_INVENTORY_ENTRIES {
+            fs::write(src_dir.path().join(format!("chunk_{}.bin", i)), b"x").unwrap();
+        }
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("many.tar.gz");
+
+        let report =
+            create_tar_gz_with_options(src_dir.path(), &archive, &ArchiveOptions::default())
+                .unwrap();
+        assert_eq!(report.file_inventory.len(), MAX_FILE_INVENTORY_ENTRIES);
+        assert!(report.file_inventory_truncated);
+        assert!(report.file_inventory.iter().all(|file| file.size_bytes == 1));
     }
 
     #[test]
-    fn test_copy_dir_recursive_destination_exists() {
+    fn test_create_tar_gz_skip_locked_files_reports_nothing_when_unlocked() {
         let src_dir = create_test_structure();
-        let dst_dir = TempDir::new().unwrap();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("unlocked.tar.gz");
 
-        let result = copy_dir_recursive(src_dir.path(), dst_dir.path());
-        assert!(matches!(result, Err(FileOpsError::DestinationExists(_))));
+        let options = ArchiveOptions {
+            skip_locked_files: true,
+            ..Default::default()
+        };
+        let report = create_tar_gz_with_options(src_dir.path(), &archive, &options).unwrap();
+        assert!(report.skipped_locked_files.is_empty());
+
+        let out = work_dir.path().join("out");
+        extract_tar_gz(&archive, &out).unwrap();
+        assert_eq!(fs::read_to_string(out.join("subdir/nested/file3.txt")).unwrap(), "nested data here");
     }
 
+    #[cfg(windows)]
     #[test]
-    fn test_delete_dir_recursive_success() {
-        let temp_dir = create_test_structure();
-        let path = temp_dir.path();
+    fn test_create_tar_gz_skips_locked_file() {
+        use std::os::windows::fs::OpenOptionsExt;
 
-        delete_dir_recursive(path).unwrap();
+        let src_dir = create_test_structure();
+        let locked_path = src_dir.path().join("locked.bin");
+        fs::write(&locked_path, b"in use by the game").unwrap();
 
-        assert!(!path.exists());
+        // Open with no sharing, like the game holding its save files
+        let _lock = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .share_mode(0)
+            .open(&locked_path)
+            .unwrap();
+
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("partial.tar.gz");
+        let options = ArchiveOptions {
+            skip_locked_files: true,
+            ..Default::default()
+        };
+        let report = create_tar_gz_with_options(src_dir.path(), &archive, &options).unwrap();
+        assert_eq!(report.skipped_locked_files, vec![locked_path]);
+
+        let out = work_dir.path().join("out");
+        extract_tar_gz(&archive, &out).unwrap();
+        assert!(out.join("file1.txt").exists());
+        assert!(!out.join("locked.bin").exists());
+
+        // Without skipping, the lock fails the archive
+        let strict = work_dir.path().join("strict.tar.gz");
+        assert!(create_tar_gz(src_dir.path(), &strict).is_err());
     }
 
     #[test]
-    fn test_delete_dir_recursive_not_found() {
-        let result = delete_dir_recursive(Path::new("/nonexistent/path"));
-        assert!(matches!(result, Err(FileOpsError::SourceNotFound(_))));
+    fn test_verify_tar_gz_intact() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("intact.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+
+        assert!(verify_tar_gz(&archive).is_ok());
     }
 
     #[test]
-    fn test_delete_dir_recursive_not_a_directory() {
-        let temp_file = TempDir::new().unwrap();
-        let file_path = temp_file.path().join("file.txt");
-        File::create(&file_path).unwrap().write_all(b"test").unwrap();
+    fn test_verify_tar_gz_detects_checksum_mismatch() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("corrupt.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
 
-        let result = delete_dir_recursive(&file_path);
-        assert!(matches!(result, Err(FileOpsError::NotADirectory(_))));
+        // The gzip trailer is CRC32 followed by the input size; flip a CRC byte
+        let mut bytes = fs::read(&archive).unwrap();
+        let crc_index = bytes.len() - 8;
+        bytes[crc_index] ^= 0xFF;
+        fs::write(&archive, bytes).unwrap();
+
+        assert!(verify_tar_gz(&archive).is_err());
     }
 
     #[test]
-    fn test_get_dir_size_success() {
-        let temp_dir = create_test_structure();
-        let path = temp_dir.path();
+    fn test_verify_archive_intact() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("intact.tar.gz");
+        create_tar_gz(src_dir.path(), &archive).unwrap();
 
-        // file1.txt: "hello" = 5 bytes
-        // file2.txt: "world test content" = 17 bytes
-        // file3.txt: "nested data here" = 15 bytes
-        // Total: 37 bytes
-        let size = get_dir_size(path).unwrap();
-        // Note: on some systems, directories may have metadata that adds bytes
-        // We check that the size is at least the expected file content size
-        assert!(size >= 37, "Expected at least 37 bytes, got {}", size);
-        // And not unreasonably large
-        assert!(size < 1024, "Expected less than 1KB, got {}", size);
+        let result = verify_archive(&archive).unwrap();
+        assert_eq!(
+            result,
+            ArchiveVerificationResult {
+                valid: true,
+                file_count: 3,
+                total_uncompressed_bytes: 5 + 18 + 16,
+                error: None,
+            }
+        );
     }
 
     #[test]
-    fn test_get_dir_size_empty_directory() {
-        let temp_dir = TempDir::new().unwrap();
-        let size = get_dir_size(temp_dir.path()).unwrap();
-        assert_eq!(size, 0);
+    fn test_verify_archive_truncated() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+
+        let tar_gz = work_dir.path().join("truncated.tar.gz");
+        let zip = work_dir.path().join("truncated.zip");
+        create_tar_gz(src_dir.path(), &tar_gz).unwrap();
+        create_zip(src_dir.path(), &zip).unwrap();
+
+        for archive in [tar_gz, zip] {
+            let bytes = fs::read(&archive).unwrap();
+            fs::write(&archive, &bytes[..bytes.len() / 2]).unwrap();
+
+            let result = verify_archive(&archive).unwrap();
+            assert!(!result.valid, "{} should be invalid", archive.display());
+            assert!(!result.error.unwrap().is_empty());
+        }
     }
 
     #[test]
-    fn test_get_dir_size_not_found() {
-        let result = get_dir_size(Path::new("/nonexistent/path"));
+    fn test_verify_archive_not_found() {
+        let result = verify_archive(Path::new("/nonexistent/backup.tar.gz"));
         assert!(matches!(result, Err(FileOpsError::SourceNotFound(_))));
     }
 
     #[test]
-    fn test_get_dir_size_not_a_directory() {
-        let temp_file = TempDir::new().unwrap();
-        let file_path = temp_file.path().join("file.txt");
-        File::create(&file_path).unwrap().write_all(b"test").unwrap();
-
-        let result = get_dir_size(&file_path);
-        assert!(matches!(result, Err(FileOpsError::NotADirectory(_))));
+    fn test_archive_format_from_path() {
+        assert_eq!(ArchiveFormat::from_path(Path::new("/b/2024.tar.gz")), Some(ArchiveFormat::TarGz));
+        assert_eq!(ArchiveFormat::from_path(Path::new("/b/2024.zip")), Some(ArchiveFormat::Zip));
+        assert_eq!(ArchiveFormat::from_path(Path::new("/b/2024.zip.tmp")), None);
+        assert_eq!(ArchiveFormat::from_path(Path::new("/b/2024.tar.gz.json")), None);
     }
 
     #[test]
-    fn test_format_size_bytes() {
-        assert_eq!(format_size(0), "0 B");
-        assert_eq!(format_size(512), "512 B");
-        assert_eq!(format_size(1023), "1023 B");
+    fn test_read_archive_meta() {
+        // file1.txt (5 bytes), subdir/file2.txt (18 bytes), subdir/nested/file3.txt (16 bytes)
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let tar_gz = work_dir.path().join("backup.tar.gz");
+        let zip = work_dir.path().join("backup.zip");
+        create_tar_gz(src_dir.path(), &tar_gz).unwrap();
+        create_zip(src_dir.path(), &zip).unwrap();
+
+        let expected = ArchiveMeta {
+            entry_count: 3,
+            total_uncompressed_bytes: 5 + 18 + 16,
+        };
+        assert_eq!(read_archive_meta(&tar_gz).unwrap(), expected);
+        assert_eq!(read_archive_meta(&zip).unwrap(), expected);
+
+        let verification = verify_archive(&tar_gz).unwrap();
+        assert_eq!(verification.file_count, expected.entry_count);
+        assert_eq!(verification.total_uncompressed_bytes, expected.total_uncompressed_bytes);
     }
 
     #[test]
-    fn test_format_size_kilobytes() {
-        assert_eq!(format_size(1024), "1.00 KB");
-        assert_eq!(format_size(1536), "1.50 KB");
-        assert_eq!(format_size(10240), "10.00 KB");
+    fn test_tar_gz_pax_metadata() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let plain = work_dir.path().join("plain.tar.gz");
+        let tagged = work_dir.path().join("tagged.tar.gz");
+        create_tar_gz(src_dir.path(), &plain).unwrap();
+        let options = ArchiveOptions {
+            pax_metadata: vec![("TEST.kind".to_string(), "incremental".to_string())],
+            ..Default::default()
+        };
+        create_tar_gz_with_options(src_dir.path(), &tagged, &options).unwrap();
+
+        assert!(read_tar_gz_pax_metadata(&plain).unwrap().is_empty());
+        let metadata = read_tar_gz_pax_metadata(&tagged).unwrap();
+        assert_eq!(metadata.get("TEST.kind").map(String::as_str), Some("incremental"));
+
+        // The header doesn't become an entry of its own
+        assert_eq!(read_archive_meta(&tagged).unwrap(), read_archive_meta(&plain).unwrap());
+        let extracted = work_dir.path().join("extracted");
+        extract_tar_gz(&tagged, &extracted).unwrap();
+        assert_eq!(fs::read_to_string(extracted.join("file1.txt")).unwrap(), "hello");
     }
 
     #[test]
-    fn test_format_size_megabytes() {
-        assert_eq!(format_size(1_048_576), "1.00 MB");
-        assert_eq!(format_size(5_242_880), "5.00 MB");
-        assert_eq!(format_size(123456789), "117.74 MB");
+    fn test_archive_format_detect_from_path() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let zip = work_dir.path().join("backup.zip");
+        let tar_gz = work_dir.path().join("backup.tar.gz");
+        create_zip(src_dir.path(), &zip).unwrap();
+        create_tar_gz(src_dir.path(), &tar_gz).unwrap();
+
+        // Renamed archives are recognized by their content
+        let renamed_zip = work_dir.path().join("renamed_zip");
+        let renamed_tar_gz = work_dir.path().join("renamed_tar_gz");
+        fs::rename(&zip, &renamed_zip).unwrap();
+        fs::rename(&tar_gz, &renamed_tar_gz).unwrap();
+        assert_eq!(ArchiveFormat::detect_from_path(&renamed_zip), Some(ArchiveFormat::Zip));
+        assert_eq!(ArchiveFormat::detect_from_path(&renamed_tar_gz), Some(ArchiveFormat::TarGz));
+
+        let notes = work_dir.path().join("notes.txt");
+        fs::write(&notes, b"not an archive").unwrap();
+        assert_eq!(ArchiveFormat::detect_from_path(&notes), None);
+
+        let out = work_dir.path().join("out");
+        extract_archive(&renamed_zip, &out).unwrap();
+        assert_eq!(fs::read_to_string(out.join("file1.txt")).unwrap(), "hello");
     }
 
     #[test]
-    fn test_format_size_gigabytes() {
-        assert_eq!(format_size(1_073_741_824), "1.00 GB");
-        assert_eq!(format_size(2_147_483_648), "2.00 GB");
-        assert_eq!(format_size(123456789012), "114.98 GB");
+    fn test_create_and_extract_zip_round_trip() {
+        let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("save.zip");
+
+        create_zip(src_dir.path(), &archive).unwrap();
+        assert!(archive.exists());
+        assert!(!work_dir.path().join("save.zip.tmp").exists());
+        assert!(verify_zip(&archive).is_ok());
+
+        let out = work_dir.path().join("out");
+        extract_archive(&archive, &out).unwrap();
+        assert_eq!(fs::read_to_string(out.join("file1.txt")).unwrap(), "hello");
+        assert_eq!(fs::read_to_string(out.join("subdir/file2.txt")).unwrap(), "world test content");
+        assert_eq!(fs::read_to_string(out.join("subdir/nested/file3.txt")).unwrap(), "nested data here");
     }
 
     #[test]
-    fn test_copy_preserves_content() {
+    fn test_create_zip_destination_exists() {
         let src_dir = create_test_structure();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("save.zip");
+        File::create(&archive).unwrap();
+
+        let result = create_zip(src_dir.path(), &archive);
+        assert!(matches!(result, Err(FileOpsError::DestinationExists(_))));
+    }
+
+    #[test]
+    fn test_extract_zip_skips_path_traversal() {
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("evil.zip");
+
+        let mut zip = zip::ZipWriter::new(File::create(&archive).unwrap());
+        let options = zip::write::SimpleFileOptions::default;
+        zip.start_file("../escaped.txt", options()).unwrap();
+        zip.write_all(b"outside").unwrap();
+        zip.start_file("inner/../../escaped2.txt", options()).unwrap();
+        zip.write_all(b"outside").unwrap();
+        zip.start_file("ok.txt", options()).unwrap();
+        zip.write_all(b"inside").unwrap();
+        zip.finish().unwrap();
+
+        let out = work_dir.path().join("nested").join("out");
+        extract_zip(&archive, &out).unwrap();
+
+        assert_eq!(fs::read_to_string(out.join("ok.txt")).unwrap(), "inside");
+        assert!(!work_dir.path().join("nested/escaped.txt").exists());
+        assert!(!work_dir.path().join("escaped2.txt").exists());
+        assert!(!work_dir.path().join("nested/escaped2.txt").exists());
+    }
+
+    #[cfg(unix)]
+    fn create_test_structure_with_symlink() -> TempDir {
+        let temp_dir = create_test_structure();
+        std::os::unix::fs::symlink("file1.txt", temp_dir.path().join("link.txt")).unwrap();
+        temp_dir
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_symlink_follow() {
+        let src_dir = create_test_structure_with_symlink();
         let dst_base = TempDir::new().unwrap();
         let dst_dir = dst_base.path().join("copy");
 
         copy_dir_recursive(src_dir.path(), &dst_dir).unwrap();
 
-        // Verify content of all files
-        let content1 = fs::read_to_string(dst_dir.join("file1.txt")).unwrap();
-        assert_eq!(content1, "hello");
+        let link_meta = fs::symlink_metadata(dst_dir.join("link.txt")).unwrap();
+        assert!(link_meta.file_type().is_file());
+        assert_eq!(fs::read_to_string(dst_dir.join("link.txt")).unwrap(), "hello");
+    }
 
-        let content2 = fs::read_to_string(dst_dir.join("subdir/file2.txt")).unwrap();
-        assert_eq!(content2, "world test content");
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_symlink_preserve() {
+        let src_dir = create_test_structure_with_symlink();
+        let dst_base = TempDir::new().unwrap();
+        let dst_dir = dst_base.path().join("copy");
 
-        let content3 = fs::read_to_string(dst_dir.join("subdir/nested/file3.txt")).unwrap();
-        assert_eq!(content3, "nested data here");
+        let options = CopyOptions {
+            symlink_mode: SymlinkMode::Preserve,
+            ..Default::default()
+        };
+        copy_dir_recursive_with_options(src_dir.path(), &dst_dir, &options).unwrap();
+
+        let link_meta = fs::symlink_metadata(dst_dir.join("link.txt")).unwrap();
+        assert!(link_meta.file_type().is_symlink());
+        assert_eq!(fs::read_link(dst_dir.join("link.txt")).unwrap(), Path::new("file1.txt"));
     }
 
+    #[cfg(unix)]
     #[test]
-    fn test_copy_deeply_nested_structure() {
-        let src_base = TempDir::new().unwrap();
-        let mut current = src_base.path().to_path_buf();
+    fn test_copy_symlink_skip() {
+        let src_dir = create_test_structure_with_symlink();
+        let dst_base = TempDir::new().unwrap();
+        let dst_dir = dst_base.path().join("copy");
 
-        // Create a deep directory structure (10 levels)
-        for i in 0..10 {
-            current = current.join(format!("level_{}", i));
-            fs::create_dir(&current).unwrap();
-            File::create(current.join(format!("file_{}.txt", i)))
-                .unwrap()
-                .write_all(format!("content_{}", i).as_bytes())
-                .unwrap();
-        }
+        let options = CopyOptions {
+            symlink_mode: SymlinkMode::Skip,
+            ..Default::default()
+        };
+        copy_dir_recursive_with_options(src_dir.path(), &dst_dir, &options).unwrap();
 
-        let dst_base = TempDir::new().unwrap();
-        let dst_dir = dst_base.path().join("deep_copy");
+        assert!(fs::symlink_metadata(dst_dir.join("link.txt")).is_err());
+        assert!(dst_dir.join("file1.txt").exists());
+    }
 
-        copy_dir_recursive(src_base.path(), &dst_dir).unwrap();
+    #[cfg(unix)]
+    #[test]
+    fn test_tar_gz_preserves_symlinks() {
+        let src_dir = create_test_structure_with_symlink();
+        let work_dir = TempDir::new().unwrap();
+        let archive = work_dir.path().join("save.tar.gz");
+        let extract_dir = work_dir.path().join("extracted");
 
-        // Verify deep structure was copied
-        let deep_file = dst_dir.join("level_0/level_1/level_2/level_3/level_4/level_5/level_6/level_7/level_8/level_9/file_9.txt");
-        assert!(deep_file.exists());
+        create_tar_gz(src_dir.path(), &archive).unwrap();
+        extract_tar_gz(&archive, &extract_dir).unwrap();
+
+        let link_meta = fs::symlink_metadata(extract_dir.join("link.txt")).unwrap();
+        assert!(link_meta.file_type().is_symlink());
+        assert_eq!(fs::read_link(extract_dir.join("link.txt")).unwrap(), Path::new("file1.txt"));
+        assert_eq!(fs::read_to_string(extract_dir.join("link.txt")).unwrap(), "hello");
     }
 }
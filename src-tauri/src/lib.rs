@@ -2,18 +2,32 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 pub mod backup;
+pub mod backup_stats;
 pub mod config;
 pub mod file_ops;
 pub mod restore;
 pub mod tags;
 pub mod update_checker;
 
-use backup::{BackupInfo, BackupResult, BackupResultT};
-use config::{Config, ConfigResult, SaveEntry};
+use backup::{
+    BackupDiff, BackupDirStats, BackupEntry, BackupError, BackupInfo, BackupManifest,
+    BackupOptions, BackupOptionsPatch, BackupProgressFn, BackupResult, BackupResultT,
+    BackupSpaceCheck, BackupStorageStats, BackupSummary, GlobalBackupSemaphores,
+};
+use backup_stats::BackupStatistics;
+use config::{
+    Config, ConfigPathsValidation, ConfigResult, HealthIssue, SaveEntry, SaveListCache,
+};
 use tags::{Tag, TagsResultT};
-use file_ops::FileOpsResult;
-use std::path::Path;
-use restore::{GameProcessCheckResult, RestoreResult, RestoreResultT, UndoSnapshotInfo};
+use file_ops::{
+    ArchiveFormat, CancellationRegistry, CopyStats, DeleteOptions, DryDeleteResult, FileOpsError,
+    FileOpsResult, OperationKind,
+};
+use std::path::{Path, PathBuf};
+use restore::{
+    GameProcessCheckResult, ListSnapshotsOptions, PagedUndoSnapshots, PruneReport, RestoreOptions,
+    RestoreError, RestorePreview, RestoreResult, RestoreResultT, UndoSnapshotInfo,
+};
 use serde::{Deserialize, Serialize};
 use update_checker::UpdateInfo;
 
@@ -25,6 +39,27 @@ struct DirSizeResult {
     formatted: String,
 }
 
+/// Payload of the `backup-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupProgressEvent {
+    save_name: String,
+    /// "started", "compressing", "finished" or "failed"
+    stage: String,
+    backup_name: Option<String>,
+    /// Bytes compressed so far (only for "compressing")
+    processed: Option<u64>,
+    /// Total bytes to compress (only for "compressing")
+    total: Option<u64>,
+}
+
+/// Payload of the `waiting-for-game` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GameWaitEvent {
+    save_name: String,
+    /// Name of the running game process
+    process_name: String,
+}
+
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 
 /// Greet command - kept for testing from the original template
@@ -40,23 +75,24 @@ fn greet(name: &str) -> String {
 /// * `dst_path` - Destination directory path (as string)
 ///
 /// # Returns
-/// `Result<(), FileOpsError>` - Ok(()) on success, Err with message on failure
+/// `FileOpsResult<CopyStats>` - Files, directories and bytes copied, or Err with message on failure
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
 /// try {
-///   await invoke('copy_dir_recursive', {
+///   const stats = await invoke('copy_dir_recursive', {
 ///     srcPath: '/path/to/source',
 ///     dstPath: '/path/to/destination'
 ///   });
+///   console.log(`Copied ${stats.files_copied} files (${stats.bytes_copied} bytes)`);
 /// } catch (err) {
 ///   console.error('Copy failed:', err);
 /// }
 /// ```
 #[tauri::command]
-fn copy_dir_recursive(src_path: String, dst_path: String) -> FileOpsResult<()> {
+fn copy_dir_recursive(src_path: String, dst_path: String) -> FileOpsResult<CopyStats> {
     file_ops::copy_dir_recursive(Path::new(&src_path), Path::new(&dst_path))
 }
 
@@ -88,6 +124,32 @@ fn delete_dir_recursive(path: String) -> FileOpsResult<()> {
     file_ops::delete_dir_recursive(Path::new(&path))
 }
 
+/// Tauri command: Previews what `delete_dir_recursive` would delete, without deleting anything.
+///
+/// # Arguments
+/// * `path` - Path to directory to preview (as string)
+///
+/// # Returns
+/// `FileOpsResult<DryDeleteResult>` - All paths that would be deleted and their total size in bytes
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const preview = await invoke('preview_delete_dir_command', {
+///   path: '/path/to/delete'
+/// });
+/// console.log(`${preview.paths.length} paths, ${preview.total_bytes} bytes`);
+/// ```
+#[tauri::command]
+fn preview_delete_dir_command(path: String) -> FileOpsResult<DryDeleteResult> {
+    let options = DeleteOptions {
+        dry_run: true,
+        recursive: true,
+    };
+    file_ops::delete_dir_with_options(Path::new(&path), &options)
+}
+
 /// Tauri command: Calculates the total size of a directory.
 ///
 /// # Arguments
@@ -107,8 +169,7 @@ fn delete_dir_recursive(path: String) -> FileOpsResult<()> {
 /// ```
 #[tauri::command]
 fn get_dir_size(path: String) -> FileOpsResult<DirSizeResult> {
-    let bytes = file_ops::get_dir_size(Path::new(&path))?;
-    let formatted = file_ops::format_size(bytes);
+    let (bytes, formatted) = file_ops::get_dir_size_human(Path::new(&path))?;
     Ok(DirSizeResult {
         path,
         bytes,
@@ -148,6 +209,8 @@ fn format_size(bytes: u64) -> String {
 /// - On macOS: Reveals the file/directory in Finder
 /// - On Windows: Selects the file/directory in Explorer
 /// - On Linux: Opens the parent directory in the default file manager
+/// - Relative paths (reported when `use_relative_paths` is enabled) are resolved
+///   against the current backup path
 ///
 /// # Example (Frontend)
 /// ```javascript
@@ -163,7 +226,12 @@ fn format_size(bytes: u64) -> String {
 /// ```
 #[tauri::command]
 fn show_in_file_manager(target_path: String) -> FileOpsResult<()> {
-    file_ops::show_in_file_manager(Path::new(&target_path))
+    let target_path = Path::new(&target_path);
+    let resolved = config::load_config()
+        .ok()
+        .and_then(|config| config.resolve_backup_relative_path(target_path).ok())
+        .unwrap_or_else(|| target_path.to_path_buf());
+    file_ops::show_in_file_manager(&resolved)
 }
 
 // ============================================================================
@@ -209,8 +277,10 @@ fn load_config_command() -> ConfigResult<Config> {
 /// });
 /// ```
 #[tauri::command]
-fn save_config_command(config: Config) -> ConfigResult<()> {
-    config::save_config(&config)
+fn save_config_command(config: Config, cache: tauri::State<'_, SaveListCache>) -> ConfigResult<()> {
+    config::save_config(&config)?;
+    cache.invalidate();
+    Ok(())
 }
 
 /// Tauri command: Updates the save path in the configuration.
@@ -230,8 +300,118 @@ fn save_config_command(config: Config) -> ConfigResult<()> {
 /// });
 /// ```
 #[tauri::command]
-fn update_save_path(save_path: String) -> ConfigResult<()> {
-    config::update_save_path(save_path)
+fn update_save_path(save_path: String, cache: tauri::State<'_, SaveListCache>) -> ConfigResult<()> {
+    config::update_save_path(save_path)?;
+    config::mark_first_run_complete()?;
+    cache.invalidate();
+    Ok(())
+}
+
+/// Tauri command: Gets lifetime backup statistics.
+///
+/// # Returns
+/// `ConfigResult<BackupStatistics>` - Counters for backups, GC and restores, overall and per save
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const stats = await invoke('get_backup_statistics_command');
+/// console.log(`${stats.total_backups_created} backups created`);
+/// ```
+#[tauri::command]
+fn get_backup_statistics_command() -> ConfigResult<BackupStatistics> {
+    backup_stats::get_backup_statistics()
+}
+
+/// Tauri command: Resets lifetime backup statistics to zero.
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('reset_statistics_command');
+/// ```
+#[tauri::command]
+fn reset_statistics_command() -> ConfigResult<()> {
+    backup_stats::reset_statistics()
+}
+
+/// Tauri command: Checks the configured save path for problems.
+///
+/// # Returns
+/// `ConfigResult<Vec<HealthIssue>>` - Issues such as `{ kind: 'broken_save_path_symlink', path }`
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const issues = await invoke('check_save_path_health_command');
+/// if (issues.length > 0) console.warn('Save path problems:', issues);
+/// ```
+#[tauri::command]
+fn check_save_path_health_command() -> ConfigResult<Vec<HealthIssue>> {
+    config::check_save_path_health()
+}
+
+/// Tauri command: Checks whether the configured save and backup paths still exist.
+///
+/// # Returns
+/// `ConfigResult<ConfigPathsValidation>` - Existence of both paths plus suggested save paths
+///
+/// # Behavior
+/// Also run on startup, which emits a `config_paths_invalid` event with the same
+/// payload if either path is missing.
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const validation = await invoke('validate_config_paths_command');
+/// if (!validation.save_path_exists) {
+///   console.warn('Save path is missing, try one of:', validation.suggestions);
+/// }
+/// ```
+#[tauri::command]
+fn validate_config_paths_command() -> ConfigResult<ConfigPathsValidation> {
+    config::validate_config_paths()
+}
+
+/// Tauri command: Checks whether initial setup still has to be done.
+///
+/// # Returns
+/// `ConfigResult<bool>` - true on a fresh install until a save path is set or the wizard is skipped
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// if (await invoke('is_first_run_command')) {
+///   showSetupWizard();
+/// }
+/// ```
+#[tauri::command]
+fn is_first_run_command() -> ConfigResult<bool> {
+    Ok(config::load_config()?.first_run)
+}
+
+/// Tauri command: Dismisses the setup wizard without completing it.
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('skip_first_run_command');
+/// ```
+#[tauri::command]
+fn skip_first_run_command() -> ConfigResult<()> {
+    config::mark_first_run_complete()
 }
 
 /// Tauri command: Updates the backup path in the configuration.
@@ -258,10 +438,10 @@ fn update_backup_path(backup_path: String) -> ConfigResult<()> {
 /// Tauri command: Updates the backup retention count.
 ///
 /// # Arguments
-/// * `count` - New retention count (must be >= 1)
+/// * `count` - New retention count (0 keeps all backups, at most `MAX_RETENTION_COUNT`)
 ///
 /// # Returns
-/// `ConfigResult<()>` - Ok(()) on success
+/// `ConfigResult<()>` - Ok(()) on success, or an error above the maximum
 ///
 /// # Example (Frontend)
 /// ```javascript
@@ -274,209 +454,928 @@ fn update_retention_count(count: usize) -> ConfigResult<()> {
     config::update_retention_count(count)
 }
 
-/// Tauri command: Updates the last selected save in the configuration.
+/// Tauri command: Estimates the disk space a save's backups would take with a retention count.
 ///
 /// # Arguments
-/// * `relativePath` - Relative path of the selected save (e.g., "Survival/MySave")
+/// * `saveName` - Name of the save
+/// * `count` - Proposed retention count (0 keeps all backups)
 ///
 /// # Returns
-/// `ConfigResult<()>` - Ok(()) on success
+/// `ConfigResult<u64>` - Estimated bytes (average backup size times `count`)
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// await invoke('update_last_selected_save', {
-///   relativePath: 'Survival/MySave'
+/// const bytes = await invoke('estimate_retention_disk_usage_command', {
+///   saveName: 'Survival',
+///   count: 50
 /// });
+/// console.log(`This will keep ~${(bytes / 1e9).toFixed(1)} GB`);
 /// ```
 #[tauri::command]
-fn update_last_selected_save(relative_path: String) -> ConfigResult<()> {
-    config::update_last_selected_save(relative_path)
+fn estimate_retention_disk_usage_command(save_name: String, count: usize) -> ConfigResult<u64> {
+    config::estimate_retention_disk_usage(&save_name, count)
 }
 
-/// Tauri command: Lists all save directories in the Zomboid saves folder.
+/// Tauri command: Updates the undo snapshot retention count.
 ///
-/// # Deprecated
-/// Consider using `list_save_entries` instead for full game mode support.
-/// This command is kept for backward compatibility.
+/// # Arguments
+/// * `count` - Number of undo snapshots to keep per save (0 keeps all)
 ///
 /// # Returns
-/// `ConfigResult<Vec<String>>` - List of save names
+/// `RestoreResultT<PruneReport>` - Snapshots pruned under the new retention
+///
+/// # Behavior
+/// Saves the setting first, then prunes existing undo snapshots of all saves.
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const saves = await invoke('list_save_directories');
-/// console.log('Available saves:', saves);
-/// // ["Survival", "Builder", "Adventure"]
+/// const report = await invoke('update_undo_snapshot_retention', { count: 5 });
+/// console.log(`Deleted ${report.deleted_count} old undo snapshots`);
 /// ```
 #[tauri::command]
-fn list_save_directories() -> ConfigResult<Vec<String>> {
-    config::list_save_directories()
+fn update_undo_snapshot_retention(count: usize) -> RestoreResultT<PruneReport> {
+    config::update_undo_snapshot_retention(count)?;
+    restore::prune_all_undo_snapshots()
 }
 
-/// Tauri command: Lists all save entries with game mode information.
+/// Tauri command: Updates the minimum save size for backups.
+///
+/// # Arguments
+/// * `bytes` - Saves smaller than this are skipped with a `SaveTooSmall` error (0 disables the check)
 ///
 /// # Returns
-/// `ConfigResult<Vec<SaveEntry>>` - List of save entries with game mode info
+/// `ConfigResult<()>` - Ok(()) on success
 ///
-/// # Behavior
-/// Scans the Zomboid saves directory for the two-level structure `Saves/<GameMode>/<SaveName>`.
-/// Also supports legacy flat structure for backwards compatibility.
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('update_min_save_size', { bytes: 4096 });
+/// ```
+#[tauri::command]
+fn update_min_save_size(bytes: u64) -> ConfigResult<()> {
+    config::update_min_save_size(bytes)
+}
+
+/// Tauri command: Updates the archive format for new backups.
+///
+/// # Arguments
+/// * `format` - "tar_gz" or "zip" (zip backups open natively on Windows)
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const entries = await invoke('list_save_entries');
-/// console.log('Available saves:', entries);
-/// // [
-/// //   { gameMode: "Survival", saveName: "MySave1", relativePath: "Survival/MySave1" },
-/// //   { gameMode: "Builder", saveName: "Builder1", relativePath: "Builder/Builder1" },
-/// //   { gameMode: "", saveName: "OldSave", relativePath: "OldSave" }
-/// // ]
+/// await invoke('update_archive_format', { format: 'zip' });
 /// ```
 #[tauri::command]
-fn list_save_entries() -> ConfigResult<Vec<SaveEntry>> {
-    config::list_save_entries()
+fn update_archive_format(format: ArchiveFormat) -> ConfigResult<()> {
+    config::update_archive_format(format)
 }
 
-/// Tauri command: Lists save entries grouped by game mode.
+/// Tauri command: Updates whether backup names use local time or UTC.
+///
+/// # Arguments
+/// * `useLocalTime` - true for local time, false for UTC
 ///
 /// # Returns
-/// `ConfigResult<std::collections::HashMap<String, Vec<SaveEntry>>>` - Map of game mode to save entries
+/// `ConfigResult<()>` - Ok(()) on success
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const grouped = await invoke('list_save_entries_by_game_mode');
-/// console.log('Saves by mode:', grouped);
-/// // {
-/// //   "Survival": [
-/// //     { gameMode: "Survival", saveName: "MySave1", relativePath: "Survival/MySave1" }
-/// //   ],
-/// //   "Builder": [
-/// //     { gameMode: "Builder", saveName: "Builder1", relativePath: "Builder/Builder1" }
-/// //   ]
-/// // }
+/// await invoke('update_use_local_time', { useLocalTime: false });
 /// ```
 #[tauri::command]
-fn list_save_entries_by_game_mode() -> ConfigResult<std::collections::HashMap<String, Vec<SaveEntry>>> {
-    config::list_save_entries_by_game_mode()
+fn update_use_local_time(use_local_time: bool) -> ConfigResult<()> {
+    config::update_use_local_time(use_local_time)
 }
 
-/// Tauri command: Detects the default Zomboid save path for the current platform.
+/// Tauri command: Updates the UI theme.
+///
+/// # Arguments
+/// * `theme` - One of "dark", "light", "system", "custom", or null for the default
 ///
 /// # Returns
-/// `FileOpsResult<String>` - Detected save path
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue for an unknown theme
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const path = await invoke('detect_zomboid_save_path');
-/// console.log('Auto-detected path:', path);
+/// await invoke('update_ui_theme', { theme: 'dark' });
 /// ```
 #[tauri::command]
-fn detect_zomboid_save_path() -> FileOpsResult<String> {
-    let path = config::detect_zomboid_save_path()?;
-    Ok(path.to_string_lossy().to_string())
+fn update_ui_theme(theme: Option<String>) -> ConfigResult<()> {
+    config::update_ui_theme(theme)
 }
 
-// ============================================================================
-// Backup Commands (CORE-03)
-// ============================================================================
+/// Tauri command: Updates the UI language.
+///
+/// # Arguments
+/// * `language` - BCP 47 language tag (e.g., "en", "zh-CN"), or null to follow the system
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue for a malformed tag
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('update_ui_language', { language: 'zh-CN' });
+/// ```
+#[tauri::command]
+fn update_ui_language(language: Option<String>) -> ConfigResult<()> {
+    config::update_ui_language(language)
+}
 
-/// Tauri command: Creates a backup of the specified save directory (async).
+/// Tauri command: Exports the current configuration to a file.
 ///
 /// # Arguments
-/// * `saveName` - Name of the save to backup (must exist in save path)
+/// * `path` - Destination file path (overwritten if it exists)
 ///
 /// # Returns
-/// `BackupResultT<BackupResult>` - Information about the created backup
+/// `ConfigResult<()>` - Ok(()) on success
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const result = await invoke('create_backup', {
-///   saveName: 'Survival'
+/// await invoke('export_config_command', { path: 'D:\\zomboid-backup-config.json' });
+/// ```
+#[tauri::command]
+fn export_config_command(path: String) -> ConfigResult<()> {
+    config::export_config(Path::new(&path))
+}
+
+/// Tauri command: Imports a configuration file written by `export_config_command`.
+///
+/// # Arguments
+/// * `path` - File to import
+///
+/// # Returns
+/// `ConfigResult<Config>` - The imported configuration, or InvalidValue if the file is
+/// malformed, has unknown fields, or its paths fail validation
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const config = await invoke('import_config_command', {
+///   path: 'D:\\zomboid-backup-config.json'
 /// });
-/// console.log('Backup created:', result.backup_path);
-/// console.log('Backups retained:', result.retained_count);
+/// console.log('Save path:', config.save_path);
+/// ```
+#[tauri::command]
+fn import_config_command(path: String) -> ConfigResult<Config> {
+    config::import_config(Path::new(&path))
+}
+
+/// Tauri command: Sets the backup I/O rate limit.
+///
+/// # Arguments
+/// * `mbPerSec` - Maximum throughput in MB/s, or null for unlimited
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_io_rate_limit', { mbPerSec: 20 });
+/// await invoke('set_io_rate_limit', { mbPerSec: null }); // unlimited
+/// ```
+#[tauri::command]
+fn set_io_rate_limit(mb_per_sec: Option<u64>) -> ConfigResult<()> {
+    config::update_io_rate_limit(mb_per_sec.map(|mb| mb.saturating_mul(1024 * 1024)))
+}
+
+/// Tauri command: Sets the maximum backup age used by garbage collection.
+///
+/// # Arguments
+/// * `days` - Delete backups older than this many days after each backup, or null to
+///   only prune by count
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success, InvalidValue if `days` is 0
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('update_max_backup_age', { days: 30 });
+/// ```
+#[tauri::command]
+fn update_max_backup_age(days: Option<u64>) -> ConfigResult<()> {
+    config::update_max_backup_age(days)
+}
+
+/// Tauri command: Adds a custom process name that counts as the game running.
+///
+/// # Arguments
+/// * `name` - Process name (e.g., a launcher or wrapper script)
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('add_custom_process_name', { name: 'PZLauncher' });
+/// ```
+#[tauri::command]
+fn add_custom_process_name(name: String) -> ConfigResult<()> {
+    config::add_custom_process_name(name)
+}
+
+/// Tauri command: Removes a custom game process name.
+///
+/// # Arguments
+/// * `name` - Process name to remove
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('remove_custom_process_name', { name: 'PZLauncher' });
+/// ```
+#[tauri::command]
+fn remove_custom_process_name(name: String) -> ConfigResult<()> {
+    config::remove_custom_process_name(name)
+}
+
+/// Tauri command: Updates the last selected save in the configuration.
+///
+/// # Arguments
+/// * `relativePath` - Relative path of the selected save (e.g., "Survival/MySave")
+///
+/// # Returns
+/// `ConfigResult<()>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('update_last_selected_save', {
+///   relativePath: 'Survival/MySave'
+/// });
+/// ```
+#[tauri::command]
+fn update_last_selected_save(relative_path: String) -> ConfigResult<()> {
+    config::update_last_selected_save(relative_path)
+}
+
+/// Tauri command: Gets the time of the latest backup of every save.
+///
+/// # Returns
+/// `ConfigResult<HashMap<String, String>>` - Relative save path to ISO 8601 timestamp
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const times = await invoke('get_last_backup_times_command');
+/// console.log('Last Survival/MySave backup:', times['Survival/MySave']);
+/// ```
+#[tauri::command]
+fn get_last_backup_times_command() -> ConfigResult<std::collections::HashMap<String, String>> {
+    Ok(config::load_config()?.last_backup_per_save)
+}
+
+/// Tauri command: Lists all save directories in the Zomboid saves folder.
+///
+/// # Deprecated
+/// Consider using `list_save_entries` instead for full game mode support.
+/// This command is kept for backward compatibility.
+///
+/// # Returns
+/// `ConfigResult<Vec<String>>` - List of save names
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const saves = await invoke('list_save_directories');
+/// console.log('Available saves:', saves);
+/// // ["Survival", "Builder", "Adventure"]
+/// ```
+#[tauri::command]
+fn list_save_directories() -> ConfigResult<Vec<String>> {
+    config::list_save_directories()
+}
+
+/// Tauri command: Lists all save entries with game mode information.
+///
+/// # Returns
+/// `ConfigResult<Vec<SaveEntry>>` - List of save entries with game mode info
+///
+/// # Behavior
+/// Scans the Zomboid saves directory for the two-level structure `Saves/<GameMode>/<SaveName>`.
+/// Also supports legacy flat structure for backwards compatibility.
+/// Results are cached for `CACHE_TTL_SECS` seconds (see `invalidate_save_list_cache_command`).
+/// Directories that cannot be read are skipped and reported through a
+/// `save_scan_warning` event carrying the list of errors.
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+/// import { listen } from '@tauri-apps/api/event';
+///
+/// await listen('save_scan_warning', (event) => console.warn('Skipped:', event.payload));
+/// const entries = await invoke('list_save_entries');
+/// console.log('Available saves:', entries);
+/// // [
+/// //   { gameMode: "Survival", saveName: "MySave1", relativePath: "Survival/MySave1" },
+/// //   { gameMode: "Builder", saveName: "Builder1", relativePath: "Builder/Builder1" },
+/// //   { gameMode: "", saveName: "OldSave", relativePath: "OldSave" }
+/// // ]
+/// ```
+#[tauri::command]
+fn list_save_entries(
+    app: tauri::AppHandle,
+    cache: tauri::State<'_, SaveListCache>,
+) -> ConfigResult<Vec<SaveEntry>> {
+    cache.get_or_load(|| scan_save_entries(&app, false))
+}
+
+/// Tauri command: Lists all save entries, optionally with the size of each save.
+///
+/// # Arguments
+/// * `includeSizes` - Fill in `size_bytes` and `size_formatted` and an exact `last_modified`
+///   (walks every save, so slower)
+///
+/// # Returns
+/// `ConfigResult<Vec<SaveEntry>>` - List of save entries with game mode info
+///
+/// # Behavior
+/// Like `list_save_entries`, but never cached, so sizes are always current.
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const entries = await invoke('list_save_entries_detailed', { includeSizes: true });
+/// for (const entry of entries) {
+///   console.log(`${entry.relative_path}: ${entry.size_formatted}`);
+/// }
+/// ```
+#[tauri::command]
+async fn list_save_entries_detailed(
+    app: tauri::AppHandle,
+    include_sizes: bool,
+) -> ConfigResult<Vec<SaveEntry>> {
+    tokio::task::spawn_blocking(move || scan_save_entries(&app, include_sizes))
+        .await
+        .map_err(|e| FileOpsError::Io(std::io::Error::other(format!("Task join error: {}", e))))?
+}
+
+/// Scans save entries, emitting a `save_scan_warning` event with the list of
+/// directories that could not be read.
+fn scan_save_entries(app: &tauri::AppHandle, include_sizes: bool) -> ConfigResult<Vec<SaveEntry>> {
+    use tauri::Emitter;
+
+    let scan = config::list_save_entries_detailed(include_sizes)?;
+    if !scan.scan_errors.is_empty() {
+        let _ = app.emit("save_scan_warning", &scan.scan_errors);
+    }
+    Ok(scan.value)
+}
+
+/// Tauri command: Lists save entries grouped by game mode.
+///
+/// # Returns
+/// `ConfigResult<std::collections::BTreeMap<String, Vec<SaveEntry>>>` - Map of game mode to save entries
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const grouped = await invoke('list_save_entries_by_game_mode');
+/// console.log('Saves by mode:', grouped);
+/// // Keys are sorted by game mode name:
+/// // {
+/// //   "Builder": [
+/// //     { gameMode: "Builder", saveName: "Builder1", relativePath: "Builder/Builder1" }
+/// //   ],
+/// //   "Survival": [
+/// //     { gameMode: "Survival", saveName: "MySave1", relativePath: "Survival/MySave1" }
+/// //   ]
+/// // }
+/// ```
+#[tauri::command]
+fn list_save_entries_by_game_mode(
+    app: tauri::AppHandle,
+    cache: tauri::State<'_, SaveListCache>,
+) -> ConfigResult<std::collections::BTreeMap<String, Vec<SaveEntry>>> {
+    cache
+        .get_or_load(|| scan_save_entries(&app, false))
+        .map(config::group_save_entries_by_game_mode)
+}
+
+/// Tauri command: Checks whether a save still exists in the configured saves directory.
+///
+/// # Arguments
+/// * `gameMode` - Game mode of the save (empty string for legacy flat saves)
+/// * `saveName` - Save folder name
+///
+/// # Returns
+/// `ConfigResult<bool>` - true if the save exists, false if it was moved or deleted
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const exists = await invoke('validate_save_entry_command', {
+///   gameMode: 'Survival',
+///   saveName: 'MySave'
+/// });
+/// if (!exists) console.warn('Save was moved or deleted');
+/// ```
+#[tauri::command]
+fn validate_save_entry_command(game_mode: String, save_name: String) -> ConfigResult<bool> {
+    config::validate_save_entry(&game_mode, &save_name)
+}
+
+/// Tauri command: Clears the cached save list so the next listing rescans the saves directory.
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('invalidate_save_list_cache_command');
+/// const grouped = await invoke('list_save_entries_by_game_mode');
+/// ```
+#[tauri::command]
+fn invalidate_save_list_cache_command(cache: tauri::State<'_, SaveListCache>) {
+    cache.invalidate();
+}
+
+/// Tauri command: Detects the default Zomboid save path for the current platform.
+///
+/// # Returns
+/// `FileOpsResult<String>` - Detected save path
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const path = await invoke('detect_zomboid_save_path');
+/// console.log('Auto-detected path:', path);
+/// ```
+#[tauri::command]
+fn detect_zomboid_save_path() -> FileOpsResult<String> {
+    let path = config::detect_zomboid_save_path()?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+// ============================================================================
+// Backup Commands (CORE-03)
+// ============================================================================
+
+/// Tauri command: Creates a backup of the specified save directory (async).
+///
+/// # Arguments
+/// * `saveName` - Name of the save to backup (must exist in save path)
+/// * `options` - Optional backup options; omitted fields keep the configured defaults.
+///   `incremental: true` only stores files changed since the latest full backup
+///
+/// # Returns
+/// `BackupResultT<BackupResult>` - Information about the created backup; fails
+/// immediately if another operation on the same save is already in progress
+///
+/// # Events
+/// - With `emit_progress: true`, emits `backup-progress` with stage "started",
+///   "compressing" (with `processed` and `total` bytes, after each archived file)
+///   and then "finished" or "failed"
+/// - Emits `backup_completed_with_warnings` with the `BackupResult` if locked files were
+///   skipped, so the frontend can offer to retry once the game is closed
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke('create_backup', {
+///   saveName: 'Survival',
+///   options: { label: 'Before horde night', compression_level: 9 }
+/// });
+/// console.log('Backup created:', result.backup_path);
+/// console.log('Backups retained:', result.retained_count);
+/// ```
+#[tauri::command]
+async fn create_backup_command(
+    app: tauri::AppHandle,
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    cancellations: tauri::State<'_, CancellationRegistry>,
+    save_name: String,
+    options: Option<BackupOptionsPatch>,
+) -> BackupResultT<BackupResult> {
+    use tauri::Emitter;
+
+    let mut options = options
+        .unwrap_or_default()
+        .apply(BackupOptions::from_config(&config::load_config()?));
+    // Only register once the save's permit is held, so a refused second backup can't
+    // touch the running one's cancellation. The options carry it so that GC which
+    // isn't awaited keeps holding it.
+    options.permit = Some(std::sync::Arc::new(semaphores.try_acquire(&save_name)?));
+    let cancellation = cancellations
+        .register(OperationKind::Backup, &save_name)
+        .ok_or_else(|| BackupError::AlreadyInProgress(save_name.clone()))?;
+    options.cancel = Some(cancellation.flag());
+
+    let emit_progress = options.emit_progress;
+    let emit = |stage: &str, backup_name: Option<String>| {
+        if emit_progress {
+            let _ = app.emit("backup-progress", BackupProgressEvent {
+                save_name: save_name.clone(),
+                stage: stage.to_string(),
+                backup_name,
+                processed: None,
+                total: None,
+            });
+        }
+    };
+
+    let progress: Option<BackupProgressFn> = if emit_progress {
+        let app = app.clone();
+        let save_name = save_name.clone();
+        Some(Box::new(move |processed, total| {
+            let _ = app.emit("backup-progress", BackupProgressEvent {
+                save_name: save_name.clone(),
+                stage: "compressing".to_string(),
+                backup_name: None,
+                processed: Some(processed),
+                total: Some(total),
+            });
+        }))
+    } else {
+        None
+    };
+
+    emit("started", None);
+    let result = backup::create_backup_async(&save_name, options, progress).await;
+    match &result {
+        Ok(backup) => {
+            emit("finished", Some(backup.backup_name.clone()));
+            if backup.warning.is_some() {
+                let _ = app.emit("backup_completed_with_warnings", backup);
+            }
+        }
+        Err(_) => emit("failed", None),
+    }
+    result
+}
+
+/// Tauri command: Cancels the backup running for a save.
+///
+/// # Arguments
+/// * `saveName` - Name of the save being backed up
+///
+/// # Returns
+/// `bool` - true if a backup was running; it fails with "Operation was cancelled"
+/// after the file being archived, and no partial archive is kept
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('cancel_backup', { saveName: 'Survival' });
+/// ```
+#[tauri::command]
+fn cancel_backup(
+    cancellations: tauri::State<'_, CancellationRegistry>,
+    save_name: String,
+) -> bool {
+    cancellations.cancel(OperationKind::Backup, &save_name)
+}
+
+/// Tauri command: Lists all backups for a specific save.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+///
+/// # Returns
+/// `BackupResultT<Vec<BackupInfo>>` - List of backups sorted by creation time (newest first)
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const backups = await invoke('list_backups', {
+///   saveName: 'Survival'
+/// });
+/// backups.forEach(backup => {
+///   console.log(`${backup.name}: ${backup.size_formatted}`);
+/// });
+/// ```
+#[tauri::command]
+fn list_backups_command(save_name: String) -> BackupResultT<Vec<BackupInfo>> {
+    backup::list_backups(&save_name)
+}
+
+/// Tauri command: Gets detailed information about a specific backup.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup directory
+///
+/// # Returns
+/// `BackupResultT<BackupInfo>` - Detailed backup information
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const info = await invoke('get_backup_info', {
+///   saveName: 'Survival',
+///   backupName: 'Survival_2024-12-28_14-30-45'
+/// });
+/// console.log('Size:', info.size_formatted);
+/// console.log('Created:', info.created_at);
+/// ```
+#[tauri::command]
+fn get_backup_info_command(save_name: String, backup_name: String) -> BackupResultT<BackupInfo> {
+    backup::get_backup_info(&save_name, &backup_name)
+}
+
+/// Tauri command: Verifies a backup against the checksum recorded when it was created.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup file
+///
+/// # Returns
+/// `BackupResultT<Option<bool>>` - true if the archive is intact, false if it is corrupt,
+/// null if the backup has no checksum
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const verified = await invoke('verify_backup_command', {
+///   saveName: 'Survival',
+///   backupName: 'Survival_2024-12-28_14-30-45.tar.gz'
+/// });
+/// if (verified === false) console.warn('Backup is corrupt');
+/// ```
+#[tauri::command]
+fn verify_backup_command(save_name: String, backup_name: String) -> BackupResultT<Option<bool>> {
+    backup::verify_backup(&save_name, &backup_name)
+}
+
+/// Tauri command: Gets the manifest (label and file inventory) of a specific backup.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup file
+///
+/// # Returns
+/// `BackupResultT<BackupManifest>` - Manifest of the backup
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const manifest = await invoke('get_backup_manifest_command', {
+///   saveName: 'Survival',
+///   backupName: 'Survival_2024-12-28_14-30-45.tar.gz'
+/// });
+/// (manifest.file_inventory ?? []).forEach(file => {
+///   console.log(`${file.path}: ${file.size_bytes} bytes`);
+/// });
+/// ```
+#[tauri::command]
+fn get_backup_manifest_command(
+    save_name: String,
+    backup_name: String,
+) -> BackupResultT<BackupManifest> {
+    backup::get_backup_manifest(&save_name, &backup_name)
+}
+
+/// Tauri command: Lists the files and directories inside a backup without extracting it.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup file
+///
+/// # Returns
+/// `BackupResultT<Vec<BackupEntry>>` - Entries in archive order
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const entries = await invoke('list_backup_contents_command', {
+///   saveName: 'Survival',
+///   backupName: 'Survival_2024-12-28_14-30-45.tar.gz'
+/// });
+/// entries.filter(entry => !entry.is_dir).forEach(entry => {
+///   console.log(`${entry.path}: ${entry.size} bytes`);
+/// });
+/// ```
+#[tauri::command]
+fn list_backup_contents_command(
+    save_name: String,
+    backup_name: String,
+) -> BackupResultT<Vec<BackupEntry>> {
+    backup::list_backup_contents(&save_name, &backup_name)
+}
+
+/// Tauri command: Pins or unpins a backup so garbage collection never deletes it.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup file
+/// * `pinned` - true to keep the backup forever, false to let GC manage it again
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success, or a "Backup not found" error
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_backup_pinned_command', {
+///   saveName: 'Survival',
+///   backupName: '2024-12-28_14-30-45.tar.gz',
+///   pinned: true
+/// });
+/// ```
+#[tauri::command]
+fn set_backup_pinned_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    backup_name: String,
+    pinned: bool,
+) -> BackupResultT<()> {
+    // Keeps GC and deletes of the save from racing the update of `.pinned`
+    let _permit = semaphores.try_acquire(&save_name)?;
+    backup::set_backup_pinned(&save_name, &backup_name, pinned)
+}
+
+/// Tauri command: Compares two backups of a save without extracting them.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupA` - Name of the older (or reference) backup
+/// * `backupB` - Name of the backup to compare against it
+///
+/// # Returns
+/// `BackupResultT<BackupDiff>` - Files added, removed and changed in size from
+/// `backupA` to `backupB`
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const diff = await invoke('diff_backups_command', {
+///   saveName: 'Survival',
+///   backupA: '2024-12-28_14-30-45.tar.gz',
+///   backupB: '2024-12-29_09-12-03.tar.gz'
+/// });
+/// console.log(`${diff.added.length} added, ${diff.removed.length} removed`);
+/// ```
+#[tauri::command]
+fn diff_backups_command(
+    save_name: String,
+    backup_a: String,
+    backup_b: String,
+) -> BackupResultT<BackupDiff> {
+    backup::diff_backups(&save_name, &backup_a, &backup_b)
+}
+
+/// Tauri command: Lists all saves that have at least one backup.
+///
+/// # Returns
+/// `BackupResultT<Vec<String>>` - List of save names with backups
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const saves = await invoke('list_saves_with_backups');
+/// console.log('Saves with backups:', saves);
+/// ```
+#[tauri::command]
+fn list_saves_with_backups_command() -> BackupResultT<Vec<String>> {
+    backup::list_saves_with_backups()
+}
+
+/// Tauri command: Gets aggregate statistics over all backups.
+///
+/// # Returns
+/// `BackupResultT<BackupSummary>` - Totals plus the oldest, newest and largest backup
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const summary = await invoke('get_backup_summary_command');
+/// console.log(`${summary.total_backups} backups, ${summary.total_size_bytes} bytes`);
+/// ```
+#[tauri::command]
+fn get_backup_summary_command() -> BackupResultT<BackupSummary> {
+    backup::get_backup_summary()
+}
+
+/// Tauri command: Reports the disk space used by backups across all saves.
+///
+/// # Returns
+/// `BackupResultT<BackupStorageStats>` - Total and per-save archive bytes, archive
+/// count and undo snapshot bytes (all zero if no backups were made yet)
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const stats = await invoke('get_backup_storage_stats_command');
+/// for (const [save, bytes] of Object.entries(stats.per_save_bytes)) {
+///   console.log(`${save}: ${bytes} bytes`);
+/// }
+/// console.log(`Undo snapshots: ${stats.undo_snapshot_bytes} bytes`);
 /// ```
 #[tauri::command]
-async fn create_backup_command(save_name: String) -> BackupResultT<BackupResult> {
-    backup::create_backup_async(&save_name).await
+fn get_backup_storage_stats_command() -> BackupResultT<BackupStorageStats> {
+    backup::get_backup_storage_stats()
 }
 
-/// Tauri command: Lists all backups for a specific save.
+/// Tauri command: Reports the disk usage of a save's backup directory by kind of file.
 ///
 /// # Arguments
-/// * `saveName` - Name of the save
+/// * `saveName` - Relative path of the save (e.g., "Survival/MySave")
 ///
 /// # Returns
-/// `BackupResultT<Vec<BackupInfo>>` - List of backups sorted by creation time (newest first)
+/// `BackupResultT<BackupDirStats>` - Archive, sidecar and other bytes
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const backups = await invoke('list_backups', {
-///   saveName: 'Survival'
-/// });
-/// backups.forEach(backup => {
-///   console.log(`${backup.name}: ${backup.size_formatted}`);
-/// });
+/// const stats = await invoke('get_backup_dir_stats_command', { saveName: 'Survival/MySave' });
+/// console.log(`${stats.archive_bytes} bytes of archives, ${stats.other_bytes} bytes other`);
 /// ```
 #[tauri::command]
-fn list_backups_command(save_name: String) -> BackupResultT<Vec<BackupInfo>> {
-    backup::list_backups(&save_name)
+fn get_backup_dir_stats_command(save_name: String) -> BackupResultT<BackupDirStats> {
+    backup::get_backup_dir_stats(&save_name)
 }
 
-/// Tauri command: Gets detailed information about a specific backup.
+/// Tauri command: Checks whether the backup drive has room for a backup of a save.
 ///
 /// # Arguments
-/// * `saveName` - Name of the save
-/// * `backupName` - Name of the backup directory
+/// * `saveName` - Relative path of the save (e.g., "Survival/MySave")
 ///
 /// # Returns
-/// `BackupResultT<BackupInfo>` - Detailed backup information
+/// `BackupResultT<BackupSpaceCheck>` - Needed bytes (save size plus a 10% margin),
+/// available bytes and whether `create_backup` would go ahead
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const info = await invoke('get_backup_info', {
-///   saveName: 'Survival',
-///   backupName: 'Survival_2024-12-28_14-30-45'
-/// });
-/// console.log('Size:', info.size_formatted);
-/// console.log('Created:', info.created_at);
+/// const space = await invoke('check_backup_space', { saveName: 'Survival/MySave' });
+/// if (!space.sufficient) {
+///   console.warn(`Need ${space.needed_bytes} bytes, only ${space.available_bytes} free`);
+/// }
 /// ```
 #[tauri::command]
-fn get_backup_info_command(save_name: String, backup_name: String) -> BackupResultT<BackupInfo> {
-    backup::get_backup_info(&save_name, &backup_name)
+fn check_backup_space(save_name: String) -> BackupResultT<BackupSpaceCheck> {
+    backup::check_backup_space(&save_name)
 }
 
-/// Tauri command: Lists all saves that have at least one backup.
+/// Tauri command: Exports the backups of a save as a CSV file.
+///
+/// # Arguments
+/// * `saveName` - Relative path of the save (e.g., "Survival/MySave")
+/// * `dstPath` - CSV file to write
 ///
 /// # Returns
-/// `BackupResultT<Vec<String>>` - List of save names with backups
+/// `BackupResultT<usize>` - Number of backups exported
 ///
 /// # Example (Frontend)
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// const saves = await invoke('list_saves_with_backups');
-/// console.log('Saves with backups:', saves);
+/// const rows = await invoke('export_backups_csv_command', {
+///   saveName: 'Survival/MySave',
+///   dstPath: '/home/user/backups.csv'
+/// });
 /// ```
 #[tauri::command]
-fn list_saves_with_backups_command() -> BackupResultT<Vec<String>> {
-    backup::list_saves_with_backups()
+fn export_backups_csv_command(save_name: String, dst_path: String) -> BackupResultT<usize> {
+    backup::export_backups_to_csv(&save_name, Path::new(&dst_path))
 }
 
 /// Tauri command: Counts the number of backups for a specific save.
@@ -546,10 +1445,75 @@ fn generate_backup_name_command(save_name: String) -> String {
 /// });
 /// ```
 #[tauri::command]
-async fn delete_backup_command(save_name: String, backup_name: String) -> BackupResultT<()> {
+async fn delete_backup_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    backup_name: String,
+) -> BackupResultT<()> {
+    let _permit = semaphores.try_acquire(&save_name)?;
     backup::delete_backup_async(&save_name, &backup_name).await
 }
 
+/// Tauri command: Deletes a save's backups older than a given age.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `maxAgeDays` - Backups created more than this many days ago are deleted
+///
+/// # Returns
+/// `BackupResultT<usize>` - Number of backups deleted
+///
+/// # Safety
+/// This is a destructive operation. Frontend should confirm with user before calling.
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const deleted = await invoke('prune_backups_older_than_command', {
+///   saveName: 'Survival',
+///   maxAgeDays: 30
+/// });
+/// ```
+#[tauri::command]
+fn prune_backups_older_than_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    max_age_days: u64,
+) -> BackupResultT<usize> {
+    let _permit = semaphores.try_acquire(&save_name)?;
+    backup::prune_backups_older_than(&save_name, max_age_days)
+}
+
+/// Tauri command: Moves a save's backups to a new save name after the save was renamed.
+///
+/// # Arguments
+/// * `oldName` - Name the backups are stored under
+/// * `newName` - Name to store them under
+///
+/// # Returns
+/// `BackupResultT<()>` - Ok(()) on success, or an error if `newName` already has backups
+/// or a backup of `oldName` is running
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('rename_save_backups_command', {
+///   oldName: 'Sandbox/Survival',
+///   newName: 'Sandbox/Apocalypse'
+/// });
+/// ```
+#[tauri::command]
+fn rename_save_backups_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    old_name: String,
+    new_name: String,
+) -> BackupResultT<()> {
+    let _permits = semaphores.try_acquire_pair(&old_name, &new_name)?;
+    backup::rename_save_backups(&old_name, &new_name)
+}
+
 // ============================================================================
 // Config Commands (CORE-02)
 // ============================================================================
@@ -581,14 +1545,20 @@ fn get_default_backup_path() -> FileOpsResult<String> {
 /// # Arguments
 /// * `saveName` - Name of the save to restore
 /// * `backupName` - Name of the backup to restore
+/// * `options` - Optional restore options; omitted fields use their defaults
 ///
 /// # Returns
 /// `RestoreResultT<RestoreResult>` - Information about the restore operation
 ///
+/// # Events
+/// With `wait_for_game` set, emits `waiting-for-game` (with `save_name` and
+/// `process_name`) each time the restore keeps waiting for the game to exit
+///
 /// # Safety
 /// This command automatically creates an "undo snapshot" of the current save state
-/// before performing the restore. If the current save doesn't exist, the restore
-/// proceeds without creating a snapshot (first-time restore scenario).
+/// before performing the restore (unless `create_undo_snapshot: false` is passed).
+/// If the current save doesn't exist, the restore proceeds without creating a
+/// snapshot (first-time restore scenario).
 ///
 /// # Example (Frontend)
 /// ```javascript
@@ -596,14 +1566,198 @@ fn get_default_backup_path() -> FileOpsResult<String> {
 ///
 /// const result = await invoke('restore_backup', {
 ///   saveName: 'Survival',
-///   backupName: 'Survival_2024-12-28_14-30-45'
+///   backupName: 'Survival_2024-12-28_14-30-45',
+///   options: { verify_checksum_before_restore: true, wait_for_game: 60 }
 /// });
 /// console.log('Restored to:', result.save_path);
 /// console.log('Undo snapshot created:', result.has_undo_snapshot);
 /// ```
 #[tauri::command]
-async fn restore_backup_command(save_name: String, backup_name: String) -> RestoreResultT<RestoreResult> {
-    restore::restore_backup_async(&save_name, &backup_name).await
+async fn restore_backup_command(
+    app: tauri::AppHandle,
+    cancellations: tauri::State<'_, CancellationRegistry>,
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    backup_name: String,
+    options: Option<RestoreOptions>,
+) -> RestoreResultT<RestoreResult> {
+    use tauri::Emitter;
+
+    let _permit = semaphores.try_acquire(&save_name)?;
+
+    let event_save_name = save_name.clone();
+    let on_wait: restore::GameWaitFn = Box::new(move |process_name| {
+        let _ = app.emit("waiting-for-game", GameWaitEvent {
+            save_name: event_save_name.clone(),
+            process_name: process_name.to_string(),
+        });
+    });
+    let cancellation = cancellations
+        .register(OperationKind::Restore, &save_name)
+        .ok_or_else(|| RestoreError::AlreadyInProgress(save_name.clone()))?;
+    let options = RestoreOptions {
+        cancel: Some(cancellation.flag()),
+        ..options.unwrap_or_default()
+    };
+    restore::restore_backup_async(&save_name, &backup_name, options, Some(on_wait)).await
+}
+
+/// Tauri command: Cancels the restore running for a save.
+///
+/// # Arguments
+/// * `saveName` - Name of the save being restored
+///
+/// # Returns
+/// `bool` - true if a restore was running; it fails with "Operation was cancelled".
+/// A partially extracted save is removed and the undo snapshot (if one was taken)
+/// is extracted back in its place
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('cancel_restore', { saveName: 'Survival' });
+/// ```
+#[tauri::command]
+fn cancel_restore(
+    cancellations: tauri::State<'_, CancellationRegistry>,
+    save_name: String,
+) -> bool {
+    cancellations.cancel(OperationKind::Restore, &save_name)
+}
+
+/// Tauri command: Restores a backup as a different save, leaving the original untouched.
+///
+/// # Arguments
+/// * `saveName` - Name of the save the backup belongs to
+/// * `backupName` - Name of the backup to restore
+/// * `targetSaveName` - Name of the save to create
+/// * `overwrite` - Replace the target save if it already exists
+///
+/// # Returns
+/// `RestoreResultT<RestoreResult>` - Information about the restore operation,
+/// or a "Target save already exists" error
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke('restore_backup_as_command', {
+///   saveName: 'Survival/MySave',
+///   backupName: '2024-12-28_14-30-45.tar.gz',
+///   targetSaveName: 'Survival/MySave_experiment',
+///   overwrite: false
+/// });
+/// console.log('Restored to:', result.save_path);
+/// ```
+#[tauri::command]
+async fn restore_backup_as_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    backup_name: String,
+    target_save_name: String,
+    overwrite: bool,
+) -> RestoreResultT<RestoreResult> {
+    let _permits = semaphores.try_acquire_pair(&target_save_name, &save_name)?;
+    restore::restore_backup_as_async(&save_name, &backup_name, &target_save_name, overwrite).await
+}
+
+/// Tauri command: Imports an archive from anywhere on disk as a new save.
+///
+/// # Arguments
+/// * `archivePath` - Path to a .tar.gz or .zip archive of a save
+/// * `targetSaveName` - Name of the save to create
+///
+/// # Returns
+/// `RestoreResultT<String>` - Path of the new save directory,
+/// or a "Target save already exists" error
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const savePath = await invoke('import_save_command', {
+///   archivePath: '/downloads/MySave.zip',
+///   targetSaveName: 'Survival/Imported'
+/// });
+/// ```
+#[tauri::command]
+async fn import_save_command(
+    archive_path: String,
+    target_save_name: String,
+    cache: tauri::State<'_, SaveListCache>,
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+) -> RestoreResultT<String> {
+    let _permit = semaphores.try_acquire(&target_save_name)?;
+    let save_dir =
+        restore::import_save_async(PathBuf::from(archive_path), &target_save_name).await?;
+    cache.invalidate();
+    Ok(save_dir.display().to_string())
+}
+
+/// Tauri command: Restores only some files or directories of a backup over the current save.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup to restore from
+/// * `entryPaths` - Paths relative to the save root; a directory restores every file under it
+///
+/// # Returns
+/// `RestoreResultT<RestoreResult>` - Information about the restore operation, or a
+/// "Backup not found" error listing the paths the backup doesn't contain
+///
+/// # Safety
+/// An undo snapshot of the whole save is taken before any file is replaced.
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke('restore_partial_command', {
+///   saveName: 'Survival/MySave',
+///   backupName: '2024-12-28_14-30-45.tar.gz',
+///   entryPaths: ['players.db', 'map_p.bin']
+/// });
+/// console.log('Replaced files:', result.overwritten_files);
+/// ```
+#[tauri::command]
+async fn restore_partial_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    backup_name: String,
+    entry_paths: Vec<String>,
+) -> RestoreResultT<RestoreResult> {
+    let _permit = semaphores.try_acquire(&save_name)?;
+    restore::restore_partial_async(&save_name, &backup_name, entry_paths).await
+}
+
+/// Tauri command: Previews what restoring a backup would change, without touching the save.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `backupName` - Name of the backup to preview
+///
+/// # Returns
+/// `RestoreResultT<RestorePreview>` - Files that would be added, overwritten and deleted,
+/// with their current and backup sizes, plus the change in save size
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const preview = await invoke('preview_restore_command', {
+///   saveName: 'Survival/MySave',
+///   backupName: '2024-12-28_14-30-45.tar.gz'
+/// });
+/// console.log(`${preview.deleted.length} files would be deleted`);
+/// console.log(`Size change: ${preview.size_delta_bytes} bytes`);
+/// ```
+#[tauri::command]
+fn preview_restore_command(
+    save_name: String,
+    backup_name: String,
+) -> RestoreResultT<RestorePreview> {
+    restore::preview_restore(&save_name, &backup_name)
 }
 
 /// Tauri command: Checks if Project Zomboid is currently running.
@@ -655,11 +1809,57 @@ fn list_undo_snapshots_command(save_name: String) -> RestoreResultT<Vec<UndoSnap
     restore::list_undo_snapshots(&save_name)
 }
 
+/// Tauri command: Lists one page of undo snapshots for a specific save.
+///
+/// # Arguments
+/// * `saveName` - Name of the save
+/// * `options` - Optional paging options (`limit`, `offset`, `sort_dir`: "ascending" | "descending")
+///
+/// # Returns
+/// `RestoreResultT<PagedUndoSnapshots>` - The requested page and the total snapshot count
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const page = await invoke('list_undo_snapshots_paged_command', {
+///   saveName: 'Survival',
+///   options: { limit: 10, offset: 10 }
+/// });
+/// console.log(`Showing ${page.items.length} of ${page.total}`);
+/// ```
+#[tauri::command]
+fn list_undo_snapshots_paged_command(
+    save_name: String,
+    options: Option<ListSnapshotsOptions>,
+) -> RestoreResultT<PagedUndoSnapshots> {
+    restore::list_undo_snapshots_paged(&save_name, &options.unwrap_or_default())
+}
+
+/// Tauri command: Deletes undo snapshots beyond the configured retention for all saves.
+///
+/// # Returns
+/// `RestoreResultT<PruneReport>` - Number of snapshots kept and deleted
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke('prune_all_undo_snapshots_command');
+/// console.log(`Deleted ${report.deleted_count} undo snapshots`);
+/// ```
+#[tauri::command]
+fn prune_all_undo_snapshots_command() -> RestoreResultT<PruneReport> {
+    restore::prune_all_undo_snapshots()
+}
+
 /// Tauri command: Restores from an undo snapshot.
 ///
 /// # Arguments
 /// * `saveName` - Name of the save
 /// * `snapshotName` - Name of the undo snapshot to restore from
+/// * `options` - Optional restore options; when omitted the current state is kept in a new
+///   `undo_pre_undo_*` snapshot first
 ///
 /// # Returns
 /// `RestoreResultT<RestoreResult>` - Information about the restore operation
@@ -670,16 +1870,20 @@ fn list_undo_snapshots_command(save_name: String) -> RestoreResultT<Vec<UndoSnap
 ///
 /// const result = await invoke('restore_from_undo_snapshot', {
 ///   saveName: 'Survival',
-///   snapshotName: 'undo_2024-12-28_14-30-45'
+///   snapshotName: 'undo_2024-12-28_14-30-45-123.tar.gz'
 /// });
 /// console.log('Restored from snapshot to:', result.save_path);
 /// ```
 #[tauri::command]
 async fn restore_from_undo_snapshot_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
     save_name: String,
     snapshot_name: String,
+    options: Option<RestoreOptions>,
 ) -> RestoreResultT<RestoreResult> {
-    restore::restore_from_undo_snapshot_async(&save_name, &snapshot_name).await
+    let _permit = semaphores.try_acquire(&save_name)?;
+    let options = options.unwrap_or_default();
+    restore::restore_from_undo_snapshot_async(&save_name, &snapshot_name, options).await
 }
 
 /// Tauri command: Deletes an undo snapshot (async).
@@ -697,11 +1901,16 @@ async fn restore_from_undo_snapshot_command(
 ///
 /// await invoke('delete_undo_snapshot', {
 ///   saveName: 'Survival',
-///   snapshotName: 'undo_2024-12-28_14-30-45'
+///   snapshotName: 'undo_2024-12-28_14-30-45-123.tar.gz'
 /// });
 /// ```
 #[tauri::command]
-async fn delete_undo_snapshot_command(save_name: String, snapshot_name: String) -> RestoreResultT<()> {
+async fn delete_undo_snapshot_command(
+    semaphores: tauri::State<'_, GlobalBackupSemaphores>,
+    save_name: String,
+    snapshot_name: String,
+) -> RestoreResultT<()> {
+    let _permit = semaphores.try_acquire(&save_name)?;
     restore::delete_undo_snapshot_async(&save_name, &snapshot_name).await
 }
 
@@ -718,6 +1927,7 @@ async fn delete_undo_snapshot_command(save_name: String, snapshot_name: String)
 /// - Fetches the latest release from GitHub
 /// - Compares with current version
 /// - Skips pre-releases
+/// - Skips the version set with `skip_version_command`
 ///
 /// # Example (Frontend)
 /// ```javascript
@@ -734,6 +1944,30 @@ async fn check_for_updates() -> Result<UpdateInfo, String> {
     update_checker::check_for_updates().await
 }
 
+/// Tauri command: Tests whether the GitHub API is reachable for update checks.
+///
+/// # Returns
+/// `Result<(), String>` - Ok if GitHub answered, otherwise the failure reason
+///
+/// # Behavior
+/// Uses the same proxy settings as `check_for_updates` (config `proxy_url`,
+/// then `HTTPS_PROXY` / `ALL_PROXY`).
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// try {
+///   await invoke('test_update_check_connectivity_command');
+/// } catch (reason) {
+///   console.error('Cannot reach GitHub:', reason);
+/// }
+/// ```
+#[tauri::command]
+async fn test_update_check_connectivity_command() -> Result<(), String> {
+    update_checker::check_connectivity().await
+}
+
 /// Tauri command: Gets the current application version.
 ///
 /// # Returns
@@ -791,6 +2025,47 @@ fn set_auto_check_updates(enabled: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// Tauri command: Skips a release so it is no longer reported as an update.
+///
+/// # Arguments
+/// * `version` - Release version to skip (e.g., "1.2.0")
+///
+/// # Returns
+/// `Result<(), String>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('skip_version_command', { version: info.latest_version });
+/// ```
+#[tauri::command]
+fn skip_version_command(version: String) -> Result<(), String> {
+    let mut config = config::load_config().map_err(|e| e.to_string())?;
+    config.skipped_version = Some(version);
+    config::save_config(&config).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Tauri command: Clears the skipped release so it is reported again.
+///
+/// # Returns
+/// `Result<(), String>` - Ok(()) on success
+///
+/// # Example (Frontend)
+/// ```javascript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('clear_skipped_version_command');
+/// ```
+#[tauri::command]
+fn clear_skipped_version_command() -> Result<(), String> {
+    let mut config = config::load_config().map_err(|e| e.to_string())?;
+    config.skipped_version = None;
+    config::save_config(&config).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 // ============================================================================
 // Tags Commands
 // ============================================================================
@@ -867,9 +2142,16 @@ fn get_backup_tags_command(save_name: String, backup_name: String) -> TagsResult
 /// # Returns
 /// `TagsResultT<()>` - Ok(()) on success
 #[tauri::command]
-fn add_tags_to_save_command(relative_path: String, tags: Vec<String>) -> TagsResultT<()> {
+fn add_tags_to_save_command(
+    relative_path: String,
+    tags: Vec<String>,
+    cache: tauri::State<'_, SaveListCache>,
+) -> TagsResultT<()> {
     tags::add_tags_to_save(&relative_path, tags)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    // Save entries carry their tags, so the cached list is now stale
+    cache.invalidate();
+    Ok(())
 }
 
 /// Tauri command: Removes tags from a save.
@@ -881,9 +2163,15 @@ fn add_tags_to_save_command(relative_path: String, tags: Vec<String>) -> TagsRes
 /// # Returns
 /// `TagsResultT<()>` - Ok(()) on success
 #[tauri::command]
-fn remove_tags_from_save_command(relative_path: String, tags: Vec<String>) -> TagsResultT<()> {
+fn remove_tags_from_save_command(
+    relative_path: String,
+    tags: Vec<String>,
+    cache: tauri::State<'_, SaveListCache>,
+) -> TagsResultT<()> {
     tags::remove_tags_from_save(&relative_path, tags)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    cache.invalidate();
+    Ok(())
 }
 
 /// Tauri command: Gets tags for a save.
@@ -921,9 +2209,11 @@ fn create_tag_command(name: String, color: String) -> TagsResultT<()> {
 /// # Returns
 /// `TagsResultT<()>` - Ok(()) on success
 #[tauri::command]
-fn delete_tag_command(name: String) -> TagsResultT<()> {
+fn delete_tag_command(name: String, cache: tauri::State<'_, SaveListCache>) -> TagsResultT<()> {
     tags::delete_tag(name)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    cache.invalidate();
+    Ok(())
 }
 
 /// Tauri command: Gets all defined tags.
@@ -941,10 +2231,28 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .manage(SaveListCache::new())
+        .manage(GlobalBackupSemaphores::new())
+        .manage(CancellationRegistry::new())
+        .setup(|app| {
+            use tauri::Emitter;
+
+            // Move undo snapshots from the old per-game-mode layout; failures are not fatal
+            let _ = restore::migrate_undo_snapshot_dirs();
+
+            // Warn about paths that went stale, e.g. after moving the Zomboid installation
+            if let Ok(validation) = config::validate_config_paths() {
+                if !validation.is_valid() {
+                    let _ = app.handle().emit("config_paths_invalid", validation);
+                }
+            }
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             copy_dir_recursive,
             delete_dir_recursive,
+            preview_delete_dir_command,
             get_dir_size,
             format_size,
             show_in_file_manager,
@@ -954,31 +2262,77 @@ pub fn run() {
             update_save_path,
             update_backup_path,
             update_retention_count,
+            estimate_retention_disk_usage_command,
+            is_first_run_command,
+            check_save_path_health_command,
+            validate_config_paths_command,
+            skip_first_run_command,
+            update_undo_snapshot_retention,
+            update_min_save_size,
+            update_archive_format,
+            update_use_local_time,
+            update_ui_theme,
+            update_ui_language,
+            export_config_command,
+            import_config_command,
+            set_io_rate_limit,
+            update_max_backup_age,
+            add_custom_process_name,
+            remove_custom_process_name,
             update_last_selected_save,
+            get_last_backup_times_command,
             list_save_directories,
             list_save_entries,
+            list_save_entries_detailed,
             list_save_entries_by_game_mode,
+            validate_save_entry_command,
+            invalidate_save_list_cache_command,
             detect_zomboid_save_path,
             get_default_backup_path,
             // Backup commands (CORE-03)
             create_backup_command,
+            cancel_backup,
             list_backups_command,
             get_backup_info_command,
+            verify_backup_command,
+            get_backup_manifest_command,
+            list_backup_contents_command,
+            diff_backups_command,
+            set_backup_pinned_command,
             list_saves_with_backups_command,
             count_backups_command,
+            get_backup_summary_command,
+            get_backup_storage_stats_command,
+            get_backup_dir_stats_command,
+            check_backup_space,
+            export_backups_csv_command,
+            get_backup_statistics_command,
+            reset_statistics_command,
             generate_backup_name_command,
             delete_backup_command,
+            prune_backups_older_than_command,
+            rename_save_backups_command,
             // Restore commands (CORE-04)
             check_game_running_command,
             restore_backup_command,
+            cancel_restore,
+            restore_backup_as_command,
+            import_save_command,
+            restore_partial_command,
+            preview_restore_command,
             list_undo_snapshots_command,
+            list_undo_snapshots_paged_command,
+            prune_all_undo_snapshots_command,
             restore_from_undo_snapshot_command,
             delete_undo_snapshot_command,
             // Update checker commands
             check_for_updates,
+            test_update_check_connectivity_command,
             get_app_version,
             get_auto_check_updates,
             set_auto_check_updates,
+            skip_version_command,
+            clear_skipped_version_command,
             // Tags commands
             add_tags_to_backup_command,
             remove_tags_from_backup_command,
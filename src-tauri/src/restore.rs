@@ -6,13 +6,32 @@
 //! - Atomic restore operations with rollback capability
 //! - Game process detection to prevent restore while game is running
 
-use crate::backup::{get_save_backup_dir, BackupError};
+use crate::backup::{
+    archive_entry_path, backup_file_sizes, backup_parent_path, configured_use_local_time,
+    extract_backup_with_options, get_save_backup_dir, incremental_file_sources,
+    name_timestamp_now, read_archive_entries, relative_backup_path, resolve_against_base,
+    validate_backup_name, validate_save_name, BackupError,
+};
 use crate::config as config_module;
-use crate::config::ConfigError;
-use crate::file_ops::{create_tar_gz, delete_dir_recursive, extract_tar_gz, FileOpsError};
+use crate::config::{ConfigError, RetentionMode};
+use crate::file_ops::{
+    check_cancelled, create_tar_gz, delete_dir_recursive, extract_archive, extract_archive_files,
+    extract_archive_with_options, verify_archive, CancelFlag, ExtractOptions, FileOpsError,
+};
 use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeSet, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// How often to re-check the game process while waiting for it to exit, at first.
+const GAME_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Upper bound for the poll interval, which doubles after each check.
+const GAME_POLL_MAX_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Callback invoked with the game's process name each time a restore keeps waiting for it.
+pub type GameWaitFn = Box<dyn FnMut(&str) + Send>;
 
 /// Result of game process check.
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,7 +56,168 @@ pub struct RestoreResult {
     /// Path to the undo snapshot (if created)
     pub undo_snapshot_path: Option<String>,
     /// Whether an undo snapshot was created
+    /// (for dry runs: whether one would be created)
     pub has_undo_snapshot: bool,
+    /// Whether this was a dry run (nothing was changed on disk)
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Snapshot of the state that was replaced (if one was created)
+    #[serde(default)]
+    pub pre_restore_snapshot: Option<UndoSnapshotInfo>,
+    /// Number of existing files replaced by the restore (0 for dry runs)
+    #[serde(default)]
+    pub overwritten_files: usize,
+    /// Number of files the restore added that weren't in the save before (0 for dry runs)
+    #[serde(default)]
+    pub added_files: usize,
+    /// Number of files in the save that the restored state doesn't have (0 for dry runs)
+    #[serde(default)]
+    pub removed_files: usize,
+    /// Problems found after extraction that did not stop the restore
+    #[serde(default)]
+    pub warnings: Vec<RestoreWarning>,
+    /// Number of old undo snapshots deleted by `undo_snapshot_retention` after the restore
+    #[serde(default)]
+    pub undo_snapshots_pruned: usize,
+}
+
+/// A file that a restore would add, overwrite or delete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestorePreviewFile {
+    /// Path relative to the save directory (using `/` separators)
+    pub path: String,
+    /// Size of the file in the save now (None if the restore adds it)
+    pub current_size: Option<u64>,
+    /// Size of the file in the backup (None if the restore deletes it)
+    pub backup_size: Option<u64>,
+}
+
+/// What restoring a backup would change, computed without touching the save.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestorePreview {
+    /// Files in the backup that the save doesn't have
+    pub added: Vec<RestorePreviewFile>,
+    /// Files in both the save and the backup, which the restore replaces
+    pub overwritten: Vec<RestorePreviewFile>,
+    /// Files in the save that the backup doesn't have
+    pub deleted: Vec<RestorePreviewFile>,
+    /// Total size of the save now, in bytes
+    pub current_bytes: u64,
+    /// Total size of the save after the restore, in bytes
+    pub restored_bytes: u64,
+    /// `restored_bytes - current_bytes` (negative if the save shrinks)
+    pub size_delta_bytes: i64,
+}
+
+/// Problems that did not stop a restore from completing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum RestoreWarning {
+    /// The restored save failed `verify_restore` (the backup may be corrupt)
+    SaveHealthCheckFailed(String),
+}
+
+/// Options controlling a restore operation.
+///
+/// Missing fields deserialize to their defaults, so the frontend only needs to
+/// send the options it wants to change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RestoreOptions {
+    /// Snapshot the current save before overwriting it (default: true)
+    pub create_undo_snapshot: bool,
+    /// Verify the archive's gzip checksum before touching the save (default: false)
+    pub verify_checksum_before_restore: bool,
+    /// Seconds to wait for the game to exit before failing; None fails immediately
+    pub wait_for_game: Option<u64>,
+    /// Label stored alongside the undo snapshot created by this restore
+    pub label: Option<String>,
+    /// Validate and report what would happen without changing anything (default: false)
+    pub dry_run: bool,
+    /// Check the restored save with `verify_restore`, reporting problems as warnings
+    /// (default: false)
+    pub verify_after_restore: bool,
+    /// Set to stop the restore early with `FileOpsError::Cancelled` (see
+    /// `CancellationRegistry`); never sent by the frontend
+    #[serde(skip)]
+    pub cancel: Option<CancelFlag>,
+}
+
+impl Default for RestoreOptions {
+    fn default() -> Self {
+        Self {
+            create_undo_snapshot: true,
+            verify_checksum_before_restore: false,
+            wait_for_game: None,
+            label: None,
+            dry_run: false,
+            verify_after_restore: false,
+            cancel: None,
+        }
+    }
+}
+
+/// Default page size for `list_undo_snapshots_paged`.
+pub const DEFAULT_SNAPSHOT_PAGE_SIZE: usize = 20;
+
+/// Sort order by creation time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    /// Oldest first
+    Ascending,
+    /// Newest first
+    #[default]
+    Descending,
+}
+
+/// Options controlling `list_undo_snapshots_paged`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListSnapshotsOptions {
+    /// Maximum number of snapshots to return (default: `DEFAULT_SNAPSHOT_PAGE_SIZE`)
+    pub limit: usize,
+    /// Number of snapshots to skip
+    pub offset: usize,
+    /// Sort order (default: newest first)
+    pub sort_dir: SortDirection,
+}
+
+impl Default for ListSnapshotsOptions {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_SNAPSHOT_PAGE_SIZE,
+            offset: 0,
+            sort_dir: SortDirection::default(),
+        }
+    }
+}
+
+/// One page of undo snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PagedUndoSnapshots {
+    /// Snapshots on this page
+    pub items: Vec<UndoSnapshotInfo>,
+    /// Total number of snapshots for the save
+    pub total: usize,
+}
+
+/// Outcome of pruning undo snapshots.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PruneReport {
+    /// Number of undo snapshot directories examined
+    pub dirs_scanned: usize,
+    /// Number of snapshots kept
+    pub retained_count: usize,
+    /// Number of snapshots deleted
+    pub deleted_count: usize,
+}
+
+/// Sidecar metadata stored next to an undo snapshot (`<snapshot>.json`).
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UndoSnapshotManifest {
+    #[serde(default)]
+    label: Option<String>,
 }
 
 /// Information about an undo snapshot.
@@ -55,6 +235,62 @@ pub struct UndoSnapshotInfo {
     pub created_at: String,
     /// Name of the save this snapshot belongs to
     pub save_name: String,
+    /// Optional label given when the snapshot was created
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+impl UndoSnapshotInfo {
+    /// Builds snapshot information from a snapshot archive on disk.
+    ///
+    /// # Arguments
+    /// * `snapshot_path` - Path to the snapshot archive
+    /// * `save_name` - Name of the save the snapshot belongs to
+    ///
+    /// # Returns
+    /// `RestoreResultT<UndoSnapshotInfo>` - Snapshot information with `path` set to `snapshot_path`
+    ///
+    /// # Behavior
+    /// - The name is the file name of `snapshot_path`
+    /// - The label (the reason the snapshot was taken) comes from the sidecar manifest, if present
+    /// - Size and creation time come from filesystem metadata
+    pub fn from_path(snapshot_path: &Path, save_name: &str) -> RestoreResultT<UndoSnapshotInfo> {
+        let name = snapshot_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| RestoreError::BackupNotFound(snapshot_path.display().to_string()))?
+            .to_string();
+
+        let (size_bytes, size_formatted) = crate::file_ops::get_file_size_human(snapshot_path)?;
+
+        let metadata = fs::metadata(snapshot_path)?;
+        let created = metadata
+            .created()
+            .or_else(|_| metadata.modified())
+            .unwrap_or_else(|_| std::time::SystemTime::now());
+        let created_dt: chrono::DateTime<chrono::Utc> = created.into();
+
+        Ok(UndoSnapshotInfo {
+            name,
+            path: snapshot_path.to_string_lossy().to_string(),
+            size_bytes,
+            size_formatted,
+            created_at: created_dt.to_rfc3339(),
+            save_name: save_name.to_string(),
+            label: read_undo_snapshot_label(snapshot_path),
+        })
+    }
+
+    /// Resolves this snapshot's path to an absolute path.
+    ///
+    /// # Arguments
+    /// * `backup_base` - Current backup base directory
+    ///
+    /// # Returns
+    /// `path` joined onto `backup_base` if it is relative, otherwise `path` unchanged
+    pub fn resolve_path(&self, backup_base: &Path) -> PathBuf {
+        resolve_against_base(&self.path, backup_base)
+    }
 }
 
 /// Error type for restore operations.
@@ -76,6 +312,12 @@ pub enum RestoreError {
     UndoSnapshotFailed(String),
     /// Game is currently running
     GameRunning(String),
+    /// Archive failed checksum verification
+    IntegrityCheckFailed(String),
+    /// Restore target save already exists and overwriting was not requested
+    TargetExists(String),
+    /// Another restore of the same save is still running
+    AlreadyInProgress(String),
 }
 
 impl From<FileOpsError> for RestoreError {
@@ -84,6 +326,12 @@ impl From<FileOpsError> for RestoreError {
     }
 }
 
+impl From<std::io::Error> for RestoreError {
+    fn from(err: std::io::Error) -> Self {
+        RestoreError::FileOp(FileOpsError::Io(err))
+    }
+}
+
 impl From<BackupError> for RestoreError {
     fn from(err: BackupError) -> Self {
         RestoreError::Backup(err)
@@ -113,6 +361,15 @@ impl std::fmt::Display for RestoreError {
             RestoreError::GameRunning(process_name) => {
                 write!(f, "Project Zomboid is currently running ({}). Please close the game before restoring.", process_name)
             }
+            RestoreError::IntegrityCheckFailed(msg) => {
+                write!(f, "Archive integrity check failed: {}", msg)
+            }
+            RestoreError::TargetExists(name) => {
+                write!(f, "Target save already exists: {}", name)
+            }
+            RestoreError::AlreadyInProgress(name) => {
+                write!(f, "A restore of {} is already in progress", name)
+            }
         }
     }
 }
@@ -140,33 +397,194 @@ impl Serialize for RestoreError {
 /// Result type for restore operations.
 pub type RestoreResultT<T> = Result<T, RestoreError>;
 
+/// Directory under the backup base path that holds all undo snapshot directories.
+pub(crate) const UNDO_SNAPSHOT_ROOT: &str = "_undo";
+
 /// Gets the undo snapshot directory for a specific save.
 ///
 /// # Arguments
 /// * `backup_base_path` - Base backup directory
-/// * `save_name` - Name of the save
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
 ///
 /// # Returns
-/// Path to the save's undo snapshot subdirectory
+/// Path to the save's undo snapshot directory, e.g. `<base>/_undo/Survival/MySave_undo`
+///
+/// # Behavior
+/// All undo directories live under `<base>/_undo`, mirroring the save's relative path,
+/// so they never mix with the backup directories of saves in the same game mode and
+/// saves like "A/B" and "A_B" keep separate directories.
 pub fn get_undo_snapshot_dir(backup_base_path: &Path, save_name: &str) -> PathBuf {
-    backup_base_path.join(format!("{}_undo", save_name))
+    backup_base_path
+        .join(UNDO_SNAPSHOT_ROOT)
+        .join(format!("{}_undo", save_name))
+}
+
+/// Gets the directory holding the undo snapshot directories of saves nested below a save.
+///
+/// # Arguments
+/// * `backup_base_path` - Base backup directory
+/// * `save_name` - Relative path of the save or game mode (e.g., "Survival")
+///
+/// # Returns
+/// Path like `<base>/_undo/Survival`, which holds `MySave_undo` for "Survival/MySave"
+pub fn get_nested_undo_snapshot_root(backup_base_path: &Path, save_name: &str) -> PathBuf {
+    backup_base_path.join(UNDO_SNAPSHOT_ROOT).join(save_name)
+}
+
+/// Moves undo snapshot directories from older layouts to `get_undo_snapshot_dir`.
+///
+/// # Returns
+/// `RestoreResultT<usize>` - Number of legacy directories migrated
+///
+/// # Behavior
+/// - Looks for legacy `*_undo` directories at the backup base path and one level below it
+/// - Looks for flattened directories like `<base>/_undo/Survival_MySave_undo`; the
+///   flattening is ambiguous, so they are only moved when a backup directory exists
+///   for exactly one reading of the name (see `unflatten_undo_dir_name`)
+/// - Renames the directory if the new location is free; otherwise moves its files one
+///   by one, keeping any snapshot that already exists at the new location
+/// - Safe to run repeatedly (does nothing once everything is migrated)
+pub fn migrate_undo_snapshot_dirs() -> RestoreResultT<usize> {
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    migrate_undo_snapshot_dirs_in(&backup_base_path)
+}
+
+/// Implementation of `migrate_undo_snapshot_dirs` for an explicit backup base path.
+fn migrate_undo_snapshot_dirs_in(backup_base_path: &Path) -> RestoreResultT<usize> {
+    if !backup_base_path.is_dir() {
+        return Ok(0);
+    }
+
+    let mut legacy_dirs = Vec::new();
+    for legacy_dir in find_legacy_undo_snapshot_dirs(backup_base_path)? {
+        let save_name = legacy_dir
+            .strip_prefix(backup_base_path)
+            .ok()
+            .and_then(|rel| rel.to_str())
+            .and_then(|rel| rel.strip_suffix("_undo"))
+            .map(|rel| rel.replace('\\', "/"));
+        if let Some(save_name) = save_name {
+            legacy_dirs.push((legacy_dir, save_name));
+        }
+    }
+    let undo_root = backup_base_path.join(UNDO_SNAPSHOT_ROOT);
+    if undo_root.is_dir() {
+        for entry in fs::read_dir(&undo_root)? {
+            let flat_dir = entry?.path();
+            let save_name = flat_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix("_undo"))
+                .filter(|_| flat_dir.is_dir())
+                .and_then(|flat_name| unflatten_undo_dir_name(backup_base_path, flat_name));
+            if let Some(save_name) = save_name {
+                legacy_dirs.push((flat_dir, save_name));
+            }
+        }
+    }
+
+    let mut migrated = 0;
+    for (legacy_dir, save_name) in legacy_dirs {
+        let new_dir = get_undo_snapshot_dir(backup_base_path, &save_name);
+        if !new_dir.exists() {
+            if let Some(parent) = new_dir.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(&legacy_dir, &new_dir)?;
+        } else {
+            for entry in fs::read_dir(&legacy_dir)? {
+                let entry = entry?;
+                let target = new_dir.join(entry.file_name());
+                if !target.exists() {
+                    fs::rename(entry.path(), target)?;
+                }
+            }
+            delete_dir_recursive(&legacy_dir)?;
+        }
+        migrated += 1;
+    }
+
+    Ok(migrated)
+}
+
+/// Recovers the save name of a flattened `<base>/_undo/<flat_name>_undo` directory.
+///
+/// # Returns
+/// `Some("Survival/MySave")` for `flat_name` "Survival_MySave" when only that save has
+/// a backup directory; `None` when the name has no `_`, already names a top-level save
+/// with backups (so it's in the right place), or can't be resolved unambiguously
+fn unflatten_undo_dir_name(backup_base_path: &Path, flat_name: &str) -> Option<String> {
+    if !flat_name.contains('_') || backup_base_path.join(flat_name).is_dir() {
+        return None;
+    }
+
+    let mut candidates = flat_name
+        .match_indices('_')
+        .map(|(i, _)| format!("{}/{}", &flat_name[..i], &flat_name[i + 1..]))
+        .filter(|save_name| backup_base_path.join(save_name).is_dir());
+    match (candidates.next(), candidates.next()) {
+        (Some(save_name), None) => Some(save_name),
+        _ => None,
+    }
 }
 
 /// Generates a timestamped undo snapshot name.
 ///
 /// # Format
-/// `undo_{YYYY-MM-DD}_{HH-mm-ss}.tar.gz`
+/// `undo_{YYYY-MM-DD}_{HH-mm-ss-mmm}.tar.gz` (millisecond precision, so snapshots
+/// taken in quick succession get distinct names)
 ///
 /// # Example
 /// ```no_run
 /// use tauri_app_lib::restore::generate_undo_snapshot_name;
 /// let name = generate_undo_snapshot_name();
-/// // Returns: "undo_2024-12-28_14-30-45.tar.gz"
+/// // Returns: "undo_2024-12-28_14-30-45-123.tar.gz"
 /// ```
 pub fn generate_undo_snapshot_name() -> String {
-    let now = chrono::Utc::now();
-    let timestamp = now.format("%Y-%m-%d_%H-%M-%S");
-    format!("undo_{}.tar.gz", timestamp)
+    generate_undo_snapshot_name_with_prefix(UNDO_SNAPSHOT_PREFIX, configured_use_local_time())
+}
+
+/// Name prefix of undo snapshots taken before restoring a backup.
+const UNDO_SNAPSHOT_PREFIX: &str = "undo_";
+
+/// Name prefix of undo snapshots taken before restoring another undo snapshot,
+/// so that restoring from undo can itself be undone.
+const PRE_UNDO_SNAPSHOT_PREFIX: &str = "undo_pre_undo_";
+
+/// Generates a timestamped undo snapshot name starting with `prefix`, in local time or
+/// UTC depending on `use_local_time` (see `Config::use_local_time`).
+fn generate_undo_snapshot_name_with_prefix(prefix: &str, use_local_time: bool) -> String {
+    let now = name_timestamp_now(use_local_time);
+    let timestamp = now.format("%Y-%m-%d_%H-%M-%S-%3f");
+    format!("{}{}.tar.gz", prefix, timestamp)
+}
+
+/// Checks whether `name` is an undo snapshot file name.
+///
+/// Accepts both the current `undo_{YYYY-MM-DD}_{HH-mm-ss-mmm}.tar.gz` format and the
+/// older second-precision `undo_{YYYY-MM-DD}_{HH-mm-ss}.tar.gz` format, with or
+/// without the `undo_pre_undo_` prefix.
+fn is_undo_snapshot_name(name: &str) -> bool {
+    let Some(timestamp) = name
+        .strip_prefix(PRE_UNDO_SNAPSHOT_PREFIX)
+        .or_else(|| name.strip_prefix(UNDO_SNAPSHOT_PREFIX))
+        .and_then(|rest| rest.strip_suffix(".tar.gz"))
+    else {
+        return false;
+    };
+
+    let parses = |ts: &str| chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d_%H-%M-%S").is_ok();
+    if parses(timestamp) {
+        return true;
+    }
+
+    match timestamp.rsplit_once('-') {
+        Some((seconds, millis)) => {
+            millis.len() == 3 && millis.chars().all(|c| c.is_ascii_digit()) && parses(seconds)
+        }
+        None => false,
+    }
 }
 
 /// Checks if Project Zomboid is currently running.
@@ -178,6 +596,7 @@ pub fn generate_undo_snapshot_name() -> String {
 /// - **Windows**: Checks for ProjectZomboid64.exe or ProjectZomboid.exe processes
 /// - **macOS**: Checks for ProjectZomboid process
 /// - **Linux**: Checks for ProjectZomboid, projectzomboid, or java processes with Zomboid in command line
+/// - Afterwards checks any `custom_game_process_names` from the configuration
 ///
 /// # Example
 /// ```no_run
@@ -189,96 +608,104 @@ pub fn generate_undo_snapshot_name() -> String {
 /// }
 /// ```
 pub fn check_game_running() -> GameProcessCheckResult {
+    let custom_names = config_module::load_config()
+        .map(|config| config.custom_game_process_names)
+        .unwrap_or_default();
+    check_game_running_with(&custom_names)
+}
+
+/// Checks if Project Zomboid is running, also matching additional process names.
+///
+/// # Arguments
+/// * `custom_names` - Extra process names to check after the built-in ones
+///   (e.g. launchers or wrapper scripts)
+///
+/// # Returns
+/// `GameProcessCheckResult` - Contains whether game is running and process name if found
+pub fn check_game_running_with(custom_names: &[String]) -> GameProcessCheckResult {
     #[cfg(target_os = "windows")]
     {
-        check_game_running_windows()
+        check_game_running_windows(custom_names)
     }
 
     #[cfg(target_os = "macos")]
     {
-        check_game_running_macos()
+        check_custom_processes_unix(check_game_running_macos(), custom_names)
     }
 
     #[cfg(target_os = "linux")]
     {
-        check_game_running_linux()
+        check_custom_processes_unix(check_game_running_linux(), custom_names)
     }
 }
 
 /// Windows-specific game detection using Windows API.
+///
+/// Uses a ToolHelp process snapshot, which lists process names without opening
+/// each process, so it also sees elevated processes when running unelevated.
 #[cfg(target_os = "windows")]
-fn check_game_running_windows() -> GameProcessCheckResult {
-    use windows::Win32::Foundation::CloseHandle;
-    use windows::Win32::System::ProcessStatus::{EnumProcesses, K32GetModuleBaseNameW};
-    use windows::Win32::System::Threading::OpenProcess;
-    use windows::Win32::System::Threading::{PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
-
-    const PROCESS_COUNT: u32 = 1024;
-    let mut process_ids = [0u32; PROCESS_COUNT as usize];
-    let mut bytes_returned = 0;
-
-    // Get list of process IDs
-    if unsafe {
-        EnumProcesses(
-            process_ids.as_mut_ptr(),
-            std::mem::size_of_val(&process_ids) as u32,
-            &mut bytes_returned,
-        )
-    }.is_err() {
-        return GameProcessCheckResult {
-            is_running: false,
-            process_name: None,
-        };
-    }
-
-    let process_count = bytes_returned / std::mem::size_of::<u32>() as u32;
-
-    // Target process names to check
-    let target_names = [
-        "ProjectZomboid64.exe",
-        "ProjectZomboid.exe",
+fn check_game_running_windows(custom_names: &[String]) -> GameProcessCheckResult {
+    // Target process names to check (built-in names first, then custom ones)
+    let mut target_names: Vec<String> = vec![
+        "ProjectZomboid64.exe".to_string(),
+        "ProjectZomboid.exe".to_string(),
     ];
-
-    // Check each process
-    for i in 0..process_count as usize {
-        let pid = process_ids[i];
-        if pid == 0 {
-            continue;
+    for name in custom_names {
+        if name.to_ascii_lowercase().ends_with(".exe") {
+            target_names.push(name.clone());
+        } else {
+            target_names.push(format!("{}.exe", name));
         }
+    }
 
-        // Open the process to query its name
-        let handle = unsafe {
-            OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid)
-        };
-
-        if let Ok(h) = handle {
-            let mut name_buffer = [0u16; 260];
-            let name_len = unsafe {
-                K32GetModuleBaseNameW(h, None, &mut name_buffer)
-            };
-
-            if name_len > 0 {
-                let process_name = String::from_utf16_lossy(&name_buffer[..name_len as usize]);
+    let process_name = find_running_process_windows(&target_names);
+    GameProcessCheckResult {
+        is_running: process_name.is_some(),
+        process_name,
+    }
+}
 
-                for target in &target_names {
-                    if process_name.eq_ignore_ascii_case(target) {
-                        unsafe { let _ = CloseHandle(h); }
-                        return GameProcessCheckResult {
-                            is_running: true,
-                            process_name: Some(target.to_string()),
-                        };
-                    }
-                }
+/// Returns the first of `target_names` (case-insensitive) that matches a running process.
+#[cfg(target_os = "windows")]
+fn find_running_process_windows(target_names: &[String]) -> Option<String> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+        TH32CS_SNAPPROCESS,
+    };
+
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) }.ok()?;
+    let mut entry = PROCESSENTRY32W {
+        dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+        ..Default::default()
+    };
+
+    let mut found = None;
+    if unsafe { Process32FirstW(snapshot, &mut entry) }.is_ok() {
+        loop {
+            let name_len = entry
+                .szExeFile
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(entry.szExeFile.len());
+            let process_name = String::from_utf16_lossy(&entry.szExeFile[..name_len]);
+
+            if let Some(target) = target_names
+                .iter()
+                .find(|target| process_name.eq_ignore_ascii_case(target))
+            {
+                found = Some(target.clone());
+                break;
             }
 
-            unsafe { let _ = CloseHandle(h); }
+            if unsafe { Process32NextW(snapshot, &mut entry) }.is_err() {
+                break;
+            }
         }
     }
 
-    GameProcessCheckResult {
-        is_running: false,
-        process_name: None,
-    }
+    unsafe { let _ = CloseHandle(snapshot); }
+    found
 }
 
 /// macOS-specific game detection using pgrep and ps.
@@ -380,11 +807,94 @@ fn check_game_running_linux() -> GameProcessCheckResult {
     }
 }
 
+/// Falls back to checking custom process names with `pgrep` if the game itself wasn't found.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn check_custom_processes_unix(
+    builtin: GameProcessCheckResult,
+    custom_names: &[String],
+) -> GameProcessCheckResult {
+    use std::process::Command;
+
+    if builtin.is_running {
+        return builtin;
+    }
+
+    for name in custom_names {
+        if let Ok(output) = Command::new("pgrep").args(["-x", name]).output() {
+            if !output.stdout.is_empty() {
+                return GameProcessCheckResult {
+                    is_running: true,
+                    process_name: Some(name.clone()),
+                };
+            }
+        }
+    }
+
+    builtin
+}
+
+/// Ensures the game is not running, optionally waiting for it to exit.
+///
+/// # Arguments
+/// * `wait_for_game` - Seconds to keep polling before giving up; None checks once
+/// * `on_wait` - Called with the process name before each wait
+///
+/// # Returns
+/// `RestoreResultT<()>` - Ok(()) once the game is not running, GameRunning otherwise
+///
+/// # Behavior
+/// Polls after `GAME_POLL_INTERVAL`, doubling the interval up to `GAME_POLL_MAX_INTERVAL`.
+fn wait_for_game_exit(
+    wait_for_game: Option<u64>,
+    mut on_wait: Option<&mut dyn FnMut(&str)>,
+) -> RestoreResultT<()> {
+    let deadline = wait_for_game.map(|secs| Instant::now() + Duration::from_secs(secs));
+    let mut interval = GAME_POLL_INTERVAL;
+
+    loop {
+        let game_check = check_game_running();
+        if !game_check.is_running {
+            return Ok(());
+        }
+
+        let process_name = game_check
+            .process_name
+            .unwrap_or_else(|| "ProjectZomboid".to_string());
+        let now = Instant::now();
+        match deadline {
+            Some(deadline) if now < deadline => {
+                if let Some(on_wait) = on_wait.as_mut() {
+                    on_wait(&process_name);
+                }
+                std::thread::sleep(interval.min(deadline - now));
+                interval = (interval * 2).min(GAME_POLL_MAX_INTERVAL);
+            }
+            _ => return Err(RestoreError::GameRunning(process_name)),
+        }
+    }
+}
+
+/// Returns the path of the sidecar manifest for an undo snapshot.
+fn undo_snapshot_manifest_path(snapshot_path: &Path) -> PathBuf {
+    let mut name = snapshot_path.as_os_str().to_os_string();
+    name.push(".json");
+    PathBuf::from(name)
+}
+
+/// Reads the label from an undo snapshot's manifest, if there is one.
+fn read_undo_snapshot_label(snapshot_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(undo_snapshot_manifest_path(snapshot_path)).ok()?;
+    serde_json::from_str::<UndoSnapshotManifest>(&content).ok()?.label
+}
+
 /// Creates an undo snapshot of the current save state.
 ///
 /// # Arguments
 /// * `save_path` - Path to the current save directory
 /// * `undo_snapshot_dir` - Directory to store undo snapshots
+/// * `label` - Optional label written to the snapshot's sidecar manifest
+/// * `prefix` - Snapshot name prefix (`UNDO_SNAPSHOT_PREFIX` or `PRE_UNDO_SNAPSHOT_PREFIX`)
+/// * `use_local_time` - Whether the name's timestamp is local time (`Config::use_local_time`)
 ///
 /// # Returns
 /// `RestoreResultT<UndoSnapshotInfo>` - Information about the created snapshot
@@ -392,10 +902,14 @@ fn check_game_running_linux() -> GameProcessCheckResult {
 /// # Behavior
 /// - Creates a compressed timestamped snapshot of the current save
 /// - Returns Ok(None) if save doesn't exist (nothing to snapshot)
-/// - If snapshot with same name exists, deletes it first before creating new one
+/// - If a snapshot with the same name exists, waits for the next millisecond so the
+///   existing snapshot is kept
 fn create_undo_snapshot(
     save_path: &Path,
     undo_snapshot_dir: &Path,
+    label: Option<&str>,
+    prefix: &str,
+    use_local_time: bool,
 ) -> RestoreResultT<Option<UndoSnapshotInfo>> {
     // If current save doesn't exist, return Ok(None) - nothing to snapshot
     if !save_path.exists() {
@@ -410,49 +924,39 @@ fn create_undo_snapshot(
 
     // Create undo snapshot directory if it doesn't exist
     if !undo_snapshot_dir.exists() {
-        fs::create_dir_all(undo_snapshot_dir)
-            .map_err(FileOpsError::Io)?;
+        fs::create_dir_all(undo_snapshot_dir)?;
     }
 
-    // Generate snapshot name and path
-    let snapshot_name = generate_undo_snapshot_name();
-    let snapshot_path = undo_snapshot_dir.join(&snapshot_name);
-
-    // Delete existing snapshot if it exists (same timestamp scenario)
-    if snapshot_path.exists() {
-        crate::file_ops::delete_file(&snapshot_path)?;
+    // Generate snapshot name and path, never overwriting an existing snapshot
+    let mut snapshot_name = generate_undo_snapshot_name_with_prefix(prefix, use_local_time);
+    while undo_snapshot_dir.join(&snapshot_name).exists() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        snapshot_name = generate_undo_snapshot_name_with_prefix(prefix, use_local_time);
     }
+    let snapshot_path = undo_snapshot_dir.join(&snapshot_name);
 
     // Compress current save to snapshot location
     create_tar_gz(save_path, &snapshot_path)?;
 
-    // Get snapshot metadata
-    let size_bytes = crate::file_ops::get_file_size(&snapshot_path)?;
-    let size_formatted = crate::file_ops::format_size(size_bytes);
-
-    let metadata = fs::metadata(&snapshot_path)
-        .map_err(FileOpsError::Io)?;
-    let created = metadata
-        .created()
-        .or_else(|_| metadata.modified())
-        .unwrap_or_else(|_| std::time::SystemTime::now());
-    let created_dt: chrono::DateTime<chrono::Utc> = created.into();
-    let created_at = created_dt.to_rfc3339();
+    // Record the label (if any) in a sidecar manifest
+    let manifest_path = undo_snapshot_manifest_path(&snapshot_path);
+    if let Some(label) = label {
+        let manifest = UndoSnapshotManifest {
+            label: Some(label.to_string()),
+        };
+        let json = serde_json::to_string_pretty(&manifest)
+            .map_err(|e| RestoreError::UndoSnapshotFailed(e.to_string()))?;
+        fs::write(&manifest_path, json)?;
+    } else if manifest_path.exists() {
+        crate::file_ops::delete_file(&manifest_path)?;
+    }
 
     let save_name = save_path
         .file_name()
         .and_then(|n| n.to_str())
-        .unwrap_or("unknown")
-        .to_string();
-
-    Ok(Some(UndoSnapshotInfo {
-        name: snapshot_name,
-        path: snapshot_path.to_string_lossy().to_string(),
-        size_bytes,
-        size_formatted,
-        created_at,
-        save_name,
-    }))
+        .unwrap_or("unknown");
+
+    Ok(Some(UndoSnapshotInfo::from_path(&snapshot_path, save_name)?))
 }
 
 /// Restores a backup to the save directory with undo snapshot creation (async version).
@@ -460,6 +964,9 @@ fn create_undo_snapshot(
 /// # Arguments
 /// * `save_name` - Relative path of the save to restore (e.g., "sandbox/aaa")
 /// * `backup_name` - Name of the backup tar.gz file to restore (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
+/// * `options` - Restore options (see `RestoreOptions`)
+/// * `on_wait` - Called with the game's process name while `options.wait_for_game`
+///   keeps the restore waiting for the game to exit
 ///
 /// # Returns
 /// `RestoreResultT<RestoreResult>` - Information about the restore operation
@@ -469,22 +976,32 @@ fn create_undo_snapshot(
 /// blocking the Tauri event loop. This prevents UI freezing during large restores.
 ///
 /// # Safety
-/// - Creates undo snapshot before any destructive operations
+/// - Creates undo snapshot before any destructive operations (unless disabled in `options`)
 /// - If current save doesn't exist, proceeds without snapshot (first-time restore scenario)
 ///
 /// # Warning
 /// If Project Zomboid is running and has the save files open, this operation
 /// may fail due to file locks. The frontend should detect if the game is running
 /// and warn the user before attempting a restore.
-pub async fn restore_backup_async(save_name: &str, backup_name: &str) -> RestoreResultT<RestoreResult> {
+pub async fn restore_backup_async(
+    save_name: &str,
+    backup_name: &str,
+    options: RestoreOptions,
+    on_wait: Option<GameWaitFn>,
+) -> RestoreResultT<RestoreResult> {
     let save_name = save_name.to_string();
     let backup_name = backup_name.to_string();
-    tokio::task::spawn_blocking(move || restore_backup(&save_name, &backup_name))
-        .await
-        .map_err(|e| RestoreError::FileOp(FileOpsError::Io(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Task join error: {}", e),
-        ))))?
+    tokio::task::spawn_blocking(move || match on_wait {
+        Some(mut on_wait) => {
+            restore_backup_from_options(&save_name, &backup_name, &options, Some(&mut on_wait))
+        }
+        None => restore_backup_with_options(&save_name, &backup_name, &options),
+    })
+    .await
+    .map_err(|e| std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("Task join error: {}", e),
+    ))?
 }
 
 /// Restores a backup to the save directory with undo snapshot creation.
@@ -510,121 +1027,789 @@ pub async fn restore_backup_async(save_name: &str, backup_name: &str) -> Restore
 /// If Project Zomboid is running and has the save files open, this operation
 /// will be blocked with an error. The user must close the game before restoring.
 pub fn restore_backup(save_name: &str, backup_name: &str) -> RestoreResultT<RestoreResult> {
+    restore_backup_with_options(save_name, backup_name, &RestoreOptions::default())
+}
+
+/// Restores a backup to the save directory with explicit options.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save to restore (e.g., "sandbox/aaa")
+/// * `backup_name` - Name of the backup tar.gz file to restore (e.g., "aaa_2024-12-28_14-30-45.tar.gz")
+/// * `options` - Restore options (see `RestoreOptions`)
+///
+/// # Returns
+/// `RestoreResultT<RestoreResult>` - Information about the restore operation
+///
+/// # Behavior
+/// Same as `restore_backup`, except:
+/// - `wait_for_game` keeps polling until the game exits instead of failing immediately
+/// - `verify_checksum_before_restore` checks the archive before anything is deleted
+/// - `create_undo_snapshot: false` skips the undo snapshot (for scripted/batch restores)
+/// - `label` is stored with the undo snapshot
+/// - `dry_run` performs all checks but leaves the save and snapshots untouched
+/// - `verify_after_restore` adds a `SaveHealthCheckFailed` warning if the restored
+///   save fails `verify_restore`
+///
+/// # Example
+/// ```no_run
+/// use tauri_app_lib::restore::{restore_backup_with_options, RestoreOptions};
+///
+/// let options = RestoreOptions {
+///     verify_checksum_before_restore: true,
+///     dry_run: true,
+///     ..Default::default()
+/// };
+/// let result = restore_backup_with_options("Survival", "Survival_2024-12-28_14-30-45.tar.gz", &options).unwrap();
+/// println!("Would restore to {}", result.save_path);
+/// ```
+pub fn restore_backup_with_options(
+    save_name: &str,
+    backup_name: &str,
+    options: &RestoreOptions,
+) -> RestoreResultT<RestoreResult> {
+    restore_backup_from_options(save_name, backup_name, options, None)
+}
+
+/// Shared implementation of `restore_backup_with_options` and `restore_backup_async`.
+fn restore_backup_from_options(
+    save_name: &str,
+    backup_name: &str,
+    options: &RestoreOptions,
+    on_wait: Option<&mut dyn FnMut(&str)>,
+) -> RestoreResultT<RestoreResult> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+
     // Check if Project Zomboid is running before proceeding
-    let game_check = check_game_running();
-    if game_check.is_running {
-        return Err(RestoreError::GameRunning(
-            game_check.process_name.unwrap_or_else(|| "ProjectZomboid".to_string()),
-        ));
-    }
+    wait_for_game_exit(options.wait_for_game, on_wait)?;
 
     let config = config_module::load_config()?;
     let save_path = config.get_save_path()?;
     let backup_base_path = config.get_backup_path()?;
 
     let save_dir = save_path.join(save_name);
-    let backup_save_dir = get_save_backup_dir(&backup_base_path, save_name);
-    let backup_file = backup_save_dir.join(backup_name);
-
-    // Validate backup file exists
-    if !backup_file.exists() {
-        return Err(RestoreError::BackupNotFound(
-            backup_file.to_string_lossy().to_string(),
-        ));
-    }
-    if !backup_file.is_file() {
-        return Err(RestoreError::BackupNotFound(format!(
-            "{} is not a file",
-            backup_file.display()
-        )));
-    }
-
-    // Create undo snapshot of current save (if it exists)
-    let undo_snapshot_dir = get_undo_snapshot_dir(&backup_base_path, save_name);
-    let undo_snapshot = create_undo_snapshot(&save_dir, &undo_snapshot_dir)?;
-
-    // Clear current save directory if it exists
-    if save_dir.exists() {
-        delete_dir_recursive(&save_dir)?;
-    }
+    let backup_file = find_backup_file(&backup_base_path, save_name, backup_name)?;
 
-    // Extract the backup tar.gz to save directory
-    extract_tar_gz(&backup_file, &save_dir)?;
+    apply_restore(
+        &config,
+        save_name,
+        &save_dir,
+        &backup_file,
+        backup_name,
+        UNDO_SNAPSHOT_PREFIX,
+        options,
+    )
+}
 
-    Ok(RestoreResult {
-        save_path: save_dir.to_string_lossy().to_string(),
-        save_name: save_name.to_string(),
-        backup_path: backup_file.to_string_lossy().to_string(),
-        backup_name: backup_name.to_string(),
-        undo_snapshot_path: undo_snapshot.as_ref().map(|u| u.path.clone()),
-        has_undo_snapshot: undo_snapshot.is_some(),
+/// Restores a backup of one save as a different save (async version).
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save the backup belongs to
+/// * `backup_name` - Name of the backup file to restore
+/// * `target_save_name` - Relative path of the save to create
+/// * `overwrite` - Replace `target_save_name` if it already exists
+///
+/// # Behavior
+/// Runs `restore_backup_as` in a blocking thread pool to avoid blocking the
+/// Tauri event loop.
+pub async fn restore_backup_as_async(
+    save_name: &str,
+    backup_name: &str,
+    target_save_name: &str,
+    overwrite: bool,
+) -> RestoreResultT<RestoreResult> {
+    let save_name = save_name.to_string();
+    let backup_name = backup_name.to_string();
+    let target_save_name = target_save_name.to_string();
+    tokio::task::spawn_blocking(move || {
+        restore_backup_as(&save_name, &backup_name, &target_save_name, overwrite)
     })
+    .await
+    .map_err(|e| std::io::Error::other(format!("Task join error: {}", e)))?
 }
 
-/// Lists all undo snapshots for a specific save.
+/// Restores a backup of one save as a different save, leaving the original untouched.
 ///
 /// # Arguments
-/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `save_name` - Relative path of the save the backup belongs to (e.g., "Survival/MySave")
+/// * `backup_name` - Name of the backup file to restore
+/// * `target_save_name` - Relative path of the save to create (e.g., "Survival/MySave_copy")
+/// * `overwrite` - Replace `target_save_name` if it already exists
 ///
 /// # Returns
-/// `RestoreResultT<Vec<UndoSnapshotInfo>>` - List of undo snapshots sorted by creation time (newest first)
-pub fn list_undo_snapshots(save_name: &str) -> RestoreResultT<Vec<UndoSnapshotInfo>> {
+/// `RestoreResultT<RestoreResult>` - Information about the restore, with `save_name` set to
+/// `target_save_name`; `TargetExists` if the target exists and `overwrite` is false
+///
+/// # Behavior
+/// - Extracts into `save_path/target_save_name`
+/// - No undo snapshot is created for a new target, since nothing is replaced
+/// - With `overwrite`, an existing target is snapshotted like in `restore_backup`,
+///   and the game must not be running
+pub fn restore_backup_as(
+    save_name: &str,
+    backup_name: &str,
+    target_save_name: &str,
+    overwrite: bool,
+) -> RestoreResultT<RestoreResult> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+    validate_save_name(target_save_name)?;
+
     let config = config_module::load_config()?;
+    let save_path = config.get_save_path()?;
     let backup_base_path = config.get_backup_path()?;
-    let undo_snapshot_dir = get_undo_snapshot_dir(&backup_base_path, save_name);
 
-    if !undo_snapshot_dir.exists() {
-        return Ok(Vec::new());
+    let target_dir = save_path.join(target_save_name);
+    let target_exists = target_dir.exists();
+    if target_exists && !overwrite {
+        return Err(RestoreError::TargetExists(target_save_name.to_string()));
+    }
+    if target_exists {
+        wait_for_game_exit(None, None)?;
     }
 
-    let mut snapshots = Vec::new();
+    let backup_file = find_backup_file(&backup_base_path, save_name, backup_name)?;
+
+    let options = RestoreOptions {
+        create_undo_snapshot: target_exists,
+        ..RestoreOptions::default()
+    };
+    apply_restore(
+        &config,
+        target_save_name,
+        &target_dir,
+        &backup_file,
+        backup_name,
+        UNDO_SNAPSHOT_PREFIX,
+        &options,
+    )
+}
+
+/// Imports an archive from anywhere on disk as a new save (async version).
+///
+/// # Arguments
+/// * `archive_path` - Path to a .tar.gz or .zip archive of a save
+/// * `target_save_name` - Relative path of the save to create (e.g., "Survival/Imported")
+///
+/// # Behavior
+/// Runs `import_save` in a blocking thread pool to avoid blocking the Tauri
+/// event loop.
+pub async fn import_save_async(
+    archive_path: PathBuf,
+    target_save_name: &str,
+) -> RestoreResultT<PathBuf> {
+    let target_save_name = target_save_name.to_string();
+    tokio::task::spawn_blocking(move || import_save(&archive_path, &target_save_name))
+        .await
+        .map_err(|e| std::io::Error::other(format!("Task join error: {}", e)))?
+}
+
+/// Imports an archive from anywhere on disk as a new save.
+///
+/// # Arguments
+/// * `archive_path` - Path to a .tar.gz or .zip archive of a save
+/// * `target_save_name` - Relative path of the save to create (e.g., "Survival/Imported")
+///
+/// # Returns
+/// `RestoreResultT<PathBuf>` - Path of the new save directory; `TargetExists` if the
+/// target already exists, `BackupNotFound` if the archive doesn't exist
+///
+/// # Behavior
+/// - Archives made by other tools often wrap everything in one top-level directory
+///   (e.g., `MySave/`); when every file sits under the same one, it is stripped so the
+///   files land directly in the new save
+/// - A failed extraction removes the partially created save
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use tauri_app_lib::restore::import_save;
+///
+/// let dir = import_save(Path::new("/downloads/MySave.zip"), "Survival/Imported").unwrap();
+/// println!("Imported to {}", dir.display());
+/// ```
+pub fn import_save(archive_path: &Path, target_save_name: &str) -> RestoreResultT<PathBuf> {
+    validate_save_name(target_save_name)?;
+    if !archive_path.is_file() {
+        return Err(RestoreError::BackupNotFound(archive_path.display().to_string()));
+    }
+
+    let config = config_module::load_config()?;
+    let target_dir = config.get_save_path()?.join(target_save_name);
+    if target_dir.exists() {
+        return Err(RestoreError::TargetExists(target_save_name.to_string()));
+    }
+
+    let entries = read_archive_entries(archive_path)?;
+    let options = ExtractOptions {
+        strip_components: usize::from(has_single_top_level_dir(&entries)),
+        ..ExtractOptions::default()
+    };
+    if let Some(parent) = target_dir.parent() {
+        fs::create_dir_all(parent).map_err(FileOpsError::Io)?;
+    }
+    if let Err(e) = extract_archive_with_options(archive_path, &target_dir, &options) {
+        let _ = fs::remove_dir_all(&target_dir);
+        return Err(e.into());
+    }
+
+    Ok(target_dir)
+}
+
+/// Checks whether every file of an archive sits under one shared top-level directory.
+fn has_single_top_level_dir(entries: &[crate::backup::BackupEntry]) -> bool {
+    let mut top = None;
+    for entry in entries.iter().filter(|entry| !entry.is_dir) {
+        let Some((first, _)) = entry.path.split_once('/') else {
+            return false;
+        };
+        if *top.get_or_insert(first) != first {
+            return false;
+        }
+    }
+    top.is_some()
+}
+
+/// Restores selected files of a backup over the current save (async version).
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
+/// * `backup_name` - Name of the backup file to restore from
+/// * `entry_paths` - Files or directories to restore (see `restore_partial`)
+///
+/// # Behavior
+/// Runs `restore_partial` in a blocking thread pool to avoid blocking the Tauri
+/// event loop.
+pub async fn restore_partial_async(
+    save_name: &str,
+    backup_name: &str,
+    entry_paths: Vec<String>,
+) -> RestoreResultT<RestoreResult> {
+    let save_name = save_name.to_string();
+    let backup_name = backup_name.to_string();
+    tokio::task::spawn_blocking(move || restore_partial(&save_name, &backup_name, entry_paths))
+        .await
+        .map_err(|e| std::io::Error::other(format!("Task join error: {}", e)))?
+}
+
+/// Restores only some files or directories of a backup over the current save.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
+/// * `backup_name` - Name of the backup file to restore from
+/// * `entry_paths` - Paths relative to the save root (e.g., "players.db" or "map");
+///   a directory restores every file under it
+///
+/// # Returns
+/// `RestoreResultT<RestoreResult>` - Information about the restore, or `BackupNotFound`
+/// listing every requested path the backup doesn't contain
+///
+/// # Behavior
+/// - Nothing is changed unless every requested path is found
+/// - Takes an undo snapshot of the whole save first, like `restore_backup`
+/// - The selected files replace the save's files at the same path; everything else is
+///   left alone, so `removed_files` is always 0
+/// - Only the selected entries are extracted, straight into the save; an incremental
+///   backup's files are each taken from the archive holding them (it or its parent)
+///
+/// # Example
+/// ```no_run
+/// use tauri_app_lib::restore::restore_partial;
+///
+/// let paths = vec!["players.db".to_string()];
+/// let result = restore_partial("Survival/MySave", "2024-12-28_14-30-45.tar.gz", paths).unwrap();
+/// println!("Replaced {} files", result.overwritten_files);
+/// ```
+pub fn restore_partial(
+    save_name: &str,
+    backup_name: &str,
+    entry_paths: Vec<String>,
+) -> RestoreResultT<RestoreResult> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+    wait_for_game_exit(None, None)?;
+
+    let config = config_module::load_config()?;
+    let save_dir = config.get_save_path()?.join(save_name);
+    let backup_base_path = config.get_backup_path()?;
+    let backup_file = find_backup_file(&backup_base_path, save_name, backup_name)?;
+
+    // Resolve each requested path to the backup files it covers
+    let backup_files = backup_file_sizes(&backup_file)?;
+    let mut selected = BTreeSet::new();
+    let mut missing = Vec::new();
+    for entry_path in &entry_paths {
+        let matches: Vec<&String> = match archive_entry_path(Path::new(entry_path)) {
+            Some(path) => backup_files
+                .keys()
+                .filter(|file| {
+                    file.strip_prefix(path.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        if matches.is_empty() {
+            missing.push(entry_path.as_str());
+        }
+        selected.extend(matches);
+    }
+    if !missing.is_empty() {
+        return Err(RestoreError::BackupNotFound(format!(
+            "{} does not contain: {}",
+            backup_file.display(),
+            missing.join(", ")
+        )));
+    }
+    if selected.is_empty() {
+        return Err(RestoreError::BackupNotFound(format!(
+            "{}: no files requested",
+            backup_file.display()
+        )));
+    }
+
+    let sources = partial_restore_sources(&backup_file, &selected)?;
+
+    let undo_snapshot_dir = get_undo_snapshot_dir(&backup_base_path, save_name);
+    let undo_snapshot = create_undo_snapshot(
+        &save_dir,
+        &undo_snapshot_dir,
+        None,
+        UNDO_SNAPSHOT_PREFIX,
+        config.use_local_time,
+    )?;
+
+    let overwritten_files = selected
+        .iter()
+        .filter(|file| save_dir.join(file.as_str()).exists())
+        .count();
+    let added_files = selected.len() - overwritten_files;
+    for (archive, files) in &sources {
+        extract_archive_files(archive, &save_dir, files)?;
+    }
+
+    let _ = crate::backup_stats::update_statistics(&backup_base_path, |stats| {
+        stats.record_restore(save_name)
+    });
+    let undo_snapshots_pruned = if undo_snapshot.is_some() {
+        prune_after_restore(&backup_base_path, save_name, config.undo_snapshot_retention)
+    } else {
+        0
+    };
+
+    Ok(RestoreResult {
+        save_path: save_dir.to_string_lossy().to_string(),
+        save_name: save_name.to_string(),
+        backup_path: backup_file.to_string_lossy().to_string(),
+        backup_name: backup_name.to_string(),
+        undo_snapshot_path: undo_snapshot.as_ref().map(|u| u.path.clone()),
+        has_undo_snapshot: undo_snapshot.is_some(),
+        dry_run: false,
+        pre_restore_snapshot: undo_snapshot,
+        overwritten_files,
+        added_files,
+        removed_files: 0,
+        warnings: Vec::new(),
+        undo_snapshots_pruned,
+    })
+}
+
+/// Groups the files of a partial restore by the archive that holds them.
+///
+/// # Returns
+/// `RestoreResultT<Vec<(PathBuf, HashSet<String>)>>` - Each archive with the files
+/// (archive paths) to extract from it
+///
+/// # Behavior
+/// A full backup holds every file itself. An incremental backup's manifest records,
+/// for each file, whether it is in the backup or in its parent (`file_sources`).
+fn partial_restore_sources(
+    backup_file: &Path,
+    files: &BTreeSet<&String>,
+) -> RestoreResultT<Vec<(PathBuf, HashSet<String>)>> {
+    let all_files = || files.iter().map(|file| file.to_string()).collect::<HashSet<_>>();
+    let Some(parent_path) = backup_parent_path(backup_file)? else {
+        return Ok(vec![(backup_file.to_path_buf(), all_files())]);
+    };
+
+    let parent_name = parent_path.file_name().map(|name| name.to_string_lossy());
+    // Manifest paths use the platform separator; archive paths always use `/`
+    let from_parent: HashSet<String> = incremental_file_sources(backup_file)?
+        .into_iter()
+        .filter(|(_, source)| parent_name.as_deref() == Some(source.as_str()))
+        .filter_map(|(path, _)| archive_entry_path(Path::new(&path)))
+        .collect();
+    let (parent_files, own_files): (HashSet<String>, HashSet<String>) =
+        all_files().into_iter().partition(|file| from_parent.contains(file));
+
+    Ok(vec![(parent_path, parent_files), (backup_file.to_path_buf(), own_files)]
+        .into_iter()
+        .filter(|(_, files)| !files.is_empty())
+        .collect())
+}
+
+/// Previews what restoring a backup would change, without touching the save.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "Survival/MySave")
+/// * `backup_name` - Name of the backup file to preview
+///
+/// # Returns
+/// `RestoreResultT<RestorePreview>` - Files that would be added, overwritten and deleted
+/// (each sorted by path) and the change in save size
+///
+/// # Behavior
+/// - Compares the archive's entry headers against the files currently in the save;
+///   nothing is extracted and no undo snapshot is created
+/// - Incremental backups are compared as the full save they restore to
+/// - A missing save directory counts as empty, so every file is added
+pub fn preview_restore(save_name: &str, backup_name: &str) -> RestoreResultT<RestorePreview> {
+    validate_save_name(save_name)?;
+    validate_backup_name(backup_name)?;
+
+    let config = config_module::load_config()?;
+    let save_dir = config.get_save_path()?.join(save_name);
+    let backup_base_path = config.get_backup_path()?;
+    let backup_file = find_backup_file(&backup_base_path, save_name, backup_name)?;
+
+    let backup_files = backup_file_sizes(&backup_file)?;
+    let mut current_files = std::collections::BTreeMap::new();
+    for relative in list_relative_files(&save_dir)? {
+        if let Some(path) = archive_entry_path(&relative) {
+            current_files.insert(path, fs::metadata(save_dir.join(&relative))?.len());
+        }
+    }
+
+    let mut preview = RestorePreview {
+        current_bytes: current_files.values().sum(),
+        restored_bytes: backup_files.values().sum(),
+        ..Default::default()
+    };
+    preview.size_delta_bytes = preview.restored_bytes as i64 - preview.current_bytes as i64;
+
+    for (path, &backup_size) in &backup_files {
+        let current_size = current_files.get(path).copied();
+        let file = RestorePreviewFile {
+            path: path.clone(),
+            current_size,
+            backup_size: Some(backup_size),
+        };
+        if current_size.is_some() {
+            preview.overwritten.push(file);
+        } else {
+            preview.added.push(file);
+        }
+    }
+    for (path, &current_size) in &current_files {
+        if !backup_files.contains_key(path) {
+            preview.deleted.push(RestorePreviewFile {
+                path: path.clone(),
+                current_size: Some(current_size),
+                backup_size: None,
+            });
+        }
+    }
+
+    Ok(preview)
+}
+
+/// Returns the path of a save's backup, or `BackupNotFound` if it is not an existing file.
+fn find_backup_file(
+    backup_base_path: &Path,
+    save_name: &str,
+    backup_name: &str,
+) -> RestoreResultT<PathBuf> {
+    let backup_file = get_save_backup_dir(backup_base_path, save_name).join(backup_name);
+
+    if !backup_file.exists() {
+        return Err(RestoreError::BackupNotFound(
+            backup_file.to_string_lossy().to_string(),
+        ));
+    }
+    if !backup_file.is_file() {
+        return Err(RestoreError::BackupNotFound(format!(
+            "{} is not a file",
+            backup_file.display()
+        )));
+    }
 
-    for entry in fs::read_dir(&undo_snapshot_dir).map_err(FileOpsError::Io)? {
-        let entry = entry.map_err(FileOpsError::Io)?;
+    Ok(backup_file)
+}
+
+/// Replaces a save with the contents of an archive, honoring `options`.
+///
+/// Shared by backup and undo snapshot restores once the archive has been located;
+/// `undo_snapshot_prefix` names the snapshot of the state being replaced.
+fn apply_restore(
+    config: &config_module::Config,
+    save_name: &str,
+    save_dir: &Path,
+    archive_file: &Path,
+    archive_name: &str,
+    undo_snapshot_prefix: &str,
+    options: &RestoreOptions,
+) -> RestoreResultT<RestoreResult> {
+    let backup_base_path = &config.get_backup_path()?;
+
+    // Verify the archive before anything destructive happens
+    if options.verify_checksum_before_restore {
+        let failure = match verify_archive(archive_file) {
+            Ok(verification) => verification.error,
+            Err(err) => Some(err.to_string()),
+        };
+        if let Some(err) = failure {
+            return Err(RestoreError::IntegrityCheckFailed(format!(
+                "{}: {}",
+                archive_file.display(),
+                err
+            )));
+        }
+    }
+
+    let mut result = RestoreResult {
+        save_path: save_dir.to_string_lossy().to_string(),
+        save_name: save_name.to_string(),
+        backup_path: archive_file.to_string_lossy().to_string(),
+        backup_name: archive_name.to_string(),
+        undo_snapshot_path: None,
+        has_undo_snapshot: false,
+        dry_run: options.dry_run,
+        pre_restore_snapshot: None,
+        overwritten_files: 0,
+        added_files: 0,
+        removed_files: 0,
+        warnings: Vec::new(),
+        undo_snapshots_pruned: 0,
+    };
+
+    // An incremental backup can't be restored without its parent; fail before the save
+    // is touched
+    backup_parent_path(archive_file)?;
+
+    if options.dry_run {
+        result.has_undo_snapshot = options.create_undo_snapshot && save_dir.is_dir();
+        return Ok(result);
+    }
+
+    // Create undo snapshot of current save (if it exists)
+    if options.create_undo_snapshot {
+        let undo_snapshot_dir = get_undo_snapshot_dir(backup_base_path, save_name);
+        let undo_snapshot = create_undo_snapshot(
+            save_dir,
+            &undo_snapshot_dir,
+            options.label.as_deref(),
+            undo_snapshot_prefix,
+            config.use_local_time,
+        )?;
+        result.undo_snapshot_path = undo_snapshot.as_ref().map(|u| u.path.clone());
+        result.has_undo_snapshot = undo_snapshot.is_some();
+        result.pre_restore_snapshot = undo_snapshot;
+    }
+
+    // Remember the current files so the result can report what changed
+    let files_before = list_relative_files(save_dir)?;
+
+    // Last chance to back out before the save is touched
+    check_cancelled(options.cancel.as_ref())?;
+
+    // Clear current save directory if it exists
+    if save_dir.exists() {
+        delete_dir_recursive(save_dir)?;
+    }
+
+    // Extract the archive to save directory (tar.gz or zip, by extension), layering
+    // incremental backups over their parent
+    let extract_options = ExtractOptions {
+        cancel: options.cancel.clone(),
+        ..Default::default()
+    };
+    if let Err(err) = extract_backup_with_options(archive_file, save_dir, &extract_options) {
+        if matches!(err, BackupError::FileOp(FileOpsError::Cancelled)) {
+            discard_cancelled_restore(save_dir, result.undo_snapshot_path.as_deref());
+            // Report it like a cancellation before extraction
+            return Err(FileOpsError::Cancelled.into());
+        }
+        return Err(err.into());
+    }
+
+    let files_after = list_relative_files(save_dir)?;
+    result.overwritten_files = files_after.intersection(&files_before).count();
+    result.added_files = files_after.difference(&files_before).count();
+    result.removed_files = files_before.difference(&files_after).count();
+
+    if options.verify_after_restore {
+        if let Err(problem) = verify_restore(save_dir) {
+            result.warnings.push(RestoreWarning::SaveHealthCheckFailed(problem));
+        }
+    }
+    let _ = crate::backup_stats::update_statistics(backup_base_path, |stats| {
+        stats.record_restore(save_name)
+    });
+
+    if result.has_undo_snapshot {
+        result.undo_snapshots_pruned =
+            prune_after_restore(backup_base_path, save_name, config.undo_snapshot_retention);
+    }
+
+    Ok(result)
+}
+
+/// Enforces undo snapshot retention after a restore, returning the number of snapshots
+/// deleted.
+///
+/// Only run once the restore is done, so the archive just restored from cannot be
+/// pruned away. Failures are ignored like in backup GC.
+fn prune_after_restore(
+    backup_base_path: &Path,
+    save_name: &str,
+    retention: RetentionMode,
+) -> usize {
+    let undo_snapshot_dir = get_undo_snapshot_dir(backup_base_path, save_name);
+    prune_undo_snapshot_dir(&undo_snapshot_dir, retention)
+        .map(|report| report.deleted_count)
+        .unwrap_or(0)
+}
+
+/// Removes the partially extracted save of a cancelled restore and, if an undo snapshot
+/// was taken, puts the previous save back from it. Failures are ignored: the restore
+/// already reports `Cancelled`, and the undo snapshot is kept either way.
+fn discard_cancelled_restore(save_dir: &Path, undo_snapshot_path: Option<&str>) {
+    if save_dir.exists() {
+        let _ = delete_dir_recursive(save_dir);
+    }
+    if let Some(snapshot) = undo_snapshot_path {
+        let _ = extract_archive(Path::new(snapshot), save_dir);
+    }
+}
+
+/// Checks that a restored save looks like a usable Project Zomboid save.
+///
+/// # Arguments
+/// * `save_dir` - Path to the restored save directory
+///
+/// # Returns
+/// `Result<(), String>` - Ok(()) if the save looks healthy, otherwise a description of the problem
+///
+/// # Behavior
+/// Only basic sanity checks are made: the directory exists and contains a
+/// non-empty `save.bin`.
+pub fn verify_restore(save_dir: &Path) -> Result<(), String> {
+    if !save_dir.is_dir() {
+        return Err(format!("{} is not a directory", save_dir.display()));
+    }
+
+    let save_bin = save_dir.join("save.bin");
+    match fs::metadata(&save_bin) {
+        Ok(metadata) if metadata.is_file() && metadata.len() > 0 => Ok(()),
+        Ok(_) => Err(format!("{} is empty or not a file", save_bin.display())),
+        Err(_) => Err(format!("{} is missing", save_bin.display())),
+    }
+}
+
+/// Lists the files under `dir` as paths relative to it (empty if `dir` doesn't exist).
+fn list_relative_files(dir: &Path) -> RestoreResultT<HashSet<PathBuf>> {
+    let mut files = HashSet::new();
+    if !dir.is_dir() {
+        return Ok(files);
+    }
+
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry.map_err(std::io::Error::from)?;
+        if entry.file_type().is_file() {
+            if let Ok(relative) = entry.path().strip_prefix(dir) {
+                files.insert(relative.to_path_buf());
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Lists all undo snapshots for a specific save.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+///
+/// # Returns
+/// `RestoreResultT<Vec<UndoSnapshotInfo>>` - List of undo snapshots sorted by creation time (newest first)
+pub fn list_undo_snapshots(save_name: &str) -> RestoreResultT<Vec<UndoSnapshotInfo>> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let undo_snapshot_dir = get_undo_snapshot_dir(&backup_base_path, save_name);
+
+    if !undo_snapshot_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut snapshots = Vec::new();
+
+    for entry in fs::read_dir(&undo_snapshot_dir)? {
+        let entry = entry?;
         let path = entry.path();
 
         // Only process .tar.gz files
         if path.is_file() {
             if let Some(name) = path.file_name() {
                 if let Some(name_str) = name.to_str() {
-                    // Check if it's an undo snapshot file (starts with "undo_" and ends with ".tar.gz")
-                    if name_str.starts_with("undo_") && name_str.ends_with(".tar.gz") {
-                        let size_bytes = crate::file_ops::get_file_size(&path)?;
-                        let size_formatted = crate::file_ops::format_size(size_bytes);
-
-                        let metadata = entry.metadata().map_err(FileOpsError::Io)?;
-                        let created = metadata
-                            .created()
-                            .or_else(|_| metadata.modified())
-                            .unwrap_or_else(|_| std::time::SystemTime::now());
-                        let created_dt: chrono::DateTime<chrono::Utc> = created.into();
-                        let created_at = created_dt.to_rfc3339();
-
-                        snapshots.push(UndoSnapshotInfo {
-                            name: name_str.to_string(),
-                            path: path.to_string_lossy().to_string(),
-                            size_bytes,
-                            size_formatted,
-                            created_at,
-                            save_name: save_name.to_string(),
-                        });
+                    // Check if it's an undo snapshot file (old or new timestamp format)
+                    if is_undo_snapshot_name(name_str) {
+                        let mut snapshot = UndoSnapshotInfo::from_path(&path, save_name)?;
+                        if let Some(relative) =
+                            relative_backup_path(&config, &backup_base_path, &path)
+                        {
+                            snapshot.path = relative;
+                        }
+                        snapshots.push(snapshot);
                     }
                 }
             }
         }
     }
 
-    // Sort by creation time (newest first)
-    snapshots.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    // Sort by creation time (newest first); names break ties within the same instant
+    snapshots.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| b.name.cmp(&a.name))
+    });
 
     Ok(snapshots)
 }
 
+/// Lists one page of undo snapshots for a specific save.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `options` - Page size, offset and sort order
+///
+/// # Returns
+/// `RestoreResultT<PagedUndoSnapshots>` - The requested page and the total snapshot count
+pub fn list_undo_snapshots_paged(
+    save_name: &str,
+    options: &ListSnapshotsOptions,
+) -> RestoreResultT<PagedUndoSnapshots> {
+    let mut snapshots = list_undo_snapshots(save_name)?;
+    if options.sort_dir == SortDirection::Ascending {
+        snapshots.reverse();
+    }
+
+    let total = snapshots.len();
+    let items = snapshots
+        .into_iter()
+        .skip(options.offset)
+        .take(options.limit)
+        .collect();
+
+    Ok(PagedUndoSnapshots { items, total })
+}
+
 /// Restores from an undo snapshot (async version).
 ///
 /// # Arguments
 /// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
-/// * `snapshot_name` - Name of the undo snapshot tar.gz file to restore from (e.g., "undo_2024-12-28_14-30-45.tar.gz")
+/// * `snapshot_name` - Name of the undo snapshot tar.gz file to restore from (e.g., "undo_2024-12-28_14-30-45-123.tar.gz")
+/// * `options` - Restore options (see `RestoreOptions`)
 ///
 /// # Returns
 /// `RestoreResultT<RestoreResult>` - Information about the restore operation
@@ -635,22 +1820,25 @@ pub fn list_undo_snapshots(save_name: &str) -> RestoreResultT<Vec<UndoSnapshotIn
 pub async fn restore_from_undo_snapshot_async(
     save_name: &str,
     snapshot_name: &str,
+    options: RestoreOptions,
 ) -> RestoreResultT<RestoreResult> {
     let save_name = save_name.to_string();
     let snapshot_name = snapshot_name.to_string();
-    tokio::task::spawn_blocking(move || restore_from_undo_snapshot(&save_name, &snapshot_name))
+    tokio::task::spawn_blocking(move || {
+        restore_from_undo_snapshot_with_options(&save_name, &snapshot_name, &options)
+    })
         .await
-        .map_err(|e| RestoreError::FileOp(FileOpsError::Io(std::io::Error::new(
+        .map_err(|e| std::io::Error::new(
             std::io::ErrorKind::Other,
             format!("Task join error: {}", e),
-        ))))?
+        ))?
 }
 
 /// Restores from an undo snapshot.
 ///
 /// # Arguments
 /// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
-/// * `snapshot_name` - Name of the undo snapshot tar.gz file to restore from (e.g., "undo_2024-12-28_14-30-45.tar.gz")
+/// * `snapshot_name` - Name of the undo snapshot tar.gz file to restore from (e.g., "undo_2024-12-28_14-30-45-123.tar.gz")
 ///
 /// # Returns
 /// `RestoreResultT<RestoreResult>` - Information about the restore operation
@@ -658,19 +1846,42 @@ pub async fn restore_from_undo_snapshot_async(
 /// # Behavior
 /// 1. Checks if Project Zomboid is running (blocks if yes)
 /// 2. Validates the undo snapshot tar.gz file exists
-/// 3. Clears the current save directory
-/// 4. Extracts the snapshot tar.gz file to the save directory
+/// 3. Snapshots the current save as `undo_pre_undo_{timestamp}.tar.gz`, so the
+///    restore can be redone by restoring from that snapshot
+/// 4. Clears the current save directory
+/// 5. Extracts the snapshot tar.gz file to the save directory
+///
+/// Use `restore_from_undo_snapshot_with_options` with `create_undo_snapshot: false`
+/// to skip the new snapshot.
 pub fn restore_from_undo_snapshot(
     save_name: &str,
     snapshot_name: &str,
 ) -> RestoreResultT<RestoreResult> {
+    restore_from_undo_snapshot_with_options(save_name, snapshot_name, &RestoreOptions::default())
+}
+
+/// Restores from an undo snapshot with explicit options.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+/// * `snapshot_name` - Name of the undo snapshot tar.gz file to restore from (e.g., "undo_2024-12-28_14-30-45-123.tar.gz")
+/// * `options` - Restore options (see `RestoreOptions`)
+///
+/// # Returns
+/// `RestoreResultT<RestoreResult>` - Information about the restore operation
+///
+/// # Behavior
+/// Same as `restore_backup_with_options`, but reads from the save's undo snapshot directory.
+pub fn restore_from_undo_snapshot_with_options(
+    save_name: &str,
+    snapshot_name: &str,
+    options: &RestoreOptions,
+) -> RestoreResultT<RestoreResult> {
+    validate_save_name(save_name)?;
+    validate_backup_name(snapshot_name)?;
+
     // Check if Project Zomboid is running before proceeding
-    let game_check = check_game_running();
-    if game_check.is_running {
-        return Err(RestoreError::GameRunning(
-            game_check.process_name.unwrap_or_else(|| "ProjectZomboid".to_string()),
-        ));
-    }
+    wait_for_game_exit(options.wait_for_game, None)?;
 
     let config = config_module::load_config()?;
     let save_path = config.get_save_path()?;
@@ -693,29 +1904,22 @@ pub fn restore_from_undo_snapshot(
         )));
     }
 
-    // Clear current save directory if it exists
-    if save_dir.exists() {
-        delete_dir_recursive(&save_dir)?;
-    }
-
-    // Extract the snapshot tar.gz to save directory
-    extract_tar_gz(&snapshot_file, &save_dir)?;
-
-    Ok(RestoreResult {
-        save_path: save_dir.to_string_lossy().to_string(),
-        save_name: save_name.to_string(),
-        backup_path: snapshot_file.to_string_lossy().to_string(),
-        backup_name: snapshot_name.to_string(),
-        undo_snapshot_path: None,
-        has_undo_snapshot: false,
-    })
+    apply_restore(
+        &config,
+        save_name,
+        &save_dir,
+        &snapshot_file,
+        snapshot_name,
+        PRE_UNDO_SNAPSHOT_PREFIX,
+        options,
+    )
 }
 
 /// Deletes an undo snapshot (async version).
 ///
 /// # Arguments
 /// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
-/// * `snapshot_name` - Name of the undo snapshot tar.gz file to delete (e.g., "undo_2024-12-28_14-30-45.tar.gz")
+/// * `snapshot_name` - Name of the undo snapshot tar.gz file to delete (e.g., "undo_2024-12-28_14-30-45-123.tar.gz")
 ///
 /// # Returns
 /// `RestoreResultT<()>` - Ok(()) on success
@@ -728,21 +1932,23 @@ pub async fn delete_undo_snapshot_async(save_name: &str, snapshot_name: &str) ->
     let snapshot_name = snapshot_name.to_string();
     tokio::task::spawn_blocking(move || delete_undo_snapshot(&save_name, &snapshot_name))
         .await
-        .map_err(|e| RestoreError::FileOp(FileOpsError::Io(std::io::Error::new(
+        .map_err(|e| std::io::Error::new(
             std::io::ErrorKind::Other,
             format!("Task join error: {}", e),
-        ))))?
+        ))?
 }
 
 /// Deletes an undo snapshot.
 ///
 /// # Arguments
 /// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
-/// * `snapshot_name` - Name of the undo snapshot tar.gz file to delete (e.g., "undo_2024-12-28_14-30-45.tar.gz")
+/// * `snapshot_name` - Name of the undo snapshot tar.gz file to delete (e.g., "undo_2024-12-28_14-30-45-123.tar.gz")
 ///
 /// # Returns
 /// `RestoreResultT<()>` - Ok(()) on success
 pub fn delete_undo_snapshot(save_name: &str, snapshot_name: &str) -> RestoreResultT<()> {
+    validate_save_name(save_name)?;
+    validate_backup_name(snapshot_name)?;
     let config = config_module::load_config()?;
     let backup_base_path = config.get_backup_path()?;
 
@@ -757,11 +1963,165 @@ pub fn delete_undo_snapshot(save_name: &str, snapshot_name: &str) -> RestoreResu
 
     crate::file_ops::delete_file(&snapshot_file)?;
 
+    let manifest_path = undo_snapshot_manifest_path(&snapshot_file);
+    if manifest_path.exists() {
+        crate::file_ops::delete_file(&manifest_path)?;
+    }
+
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
+/// Deletes undo snapshots of a save beyond the configured retention.
+///
+/// # Arguments
+/// * `save_name` - Relative path of the save (e.g., "sandbox/aaa")
+///
+/// # Returns
+/// `RestoreResultT<PruneReport>` - Number of snapshots kept and deleted
+pub fn prune_undo_snapshots(save_name: &str) -> RestoreResultT<PruneReport> {
+    validate_save_name(save_name)?;
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+    let undo_snapshot_dir = get_undo_snapshot_dir(&backup_base_path, save_name);
+
+    prune_undo_snapshot_dir(&undo_snapshot_dir, config.undo_snapshot_retention)
+}
+
+/// Deletes undo snapshots beyond the configured retention for every save.
+///
+/// # Returns
+/// `RestoreResultT<PruneReport>` - Totals over all `*_undo` directories
+///
+/// # Behavior
+/// - Prunes every `*_undo` directory under `<base>/_undo`
+/// - Keeps the newest snapshots in each directory
+pub fn prune_all_undo_snapshots() -> RestoreResultT<PruneReport> {
+    let config = config_module::load_config()?;
+    let backup_base_path = config.get_backup_path()?;
+
+    let mut report = PruneReport::default();
+    if !backup_base_path.exists() {
+        return Ok(report);
+    }
+
+    for undo_snapshot_dir in find_undo_snapshot_dirs(&backup_base_path)? {
+        let dir_report =
+            prune_undo_snapshot_dir(&undo_snapshot_dir, config.undo_snapshot_retention)?;
+        report.dirs_scanned += dir_report.dirs_scanned;
+        report.retained_count += dir_report.retained_count;
+        report.deleted_count += dir_report.deleted_count;
+    }
+
+    Ok(report)
+}
+
+/// Checks whether `path` is a directory named `*_undo`.
+fn is_undo_dir(path: &Path) -> bool {
+    path.is_dir()
+        && path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.ends_with("_undo"))
+}
+
+/// Collects the `*_undo` directories under `<backup_base_path>/_undo`, including those
+/// of nested saves (`_undo/Survival/MySave_undo`).
+fn find_undo_snapshot_dirs(backup_base_path: &Path) -> RestoreResultT<Vec<PathBuf>> {
+    let undo_root = backup_base_path.join(UNDO_SNAPSHOT_ROOT);
+    if !undo_root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut dirs = Vec::new();
+    let mut walker = walkdir::WalkDir::new(&undo_root).min_depth(1).into_iter();
+    while let Some(entry) = walker.next() {
+        let entry = entry.map_err(std::io::Error::from)?;
+        if is_undo_dir(entry.path()) {
+            dirs.push(entry.into_path());
+            // Snapshots are files, so there's nothing more to find inside
+            walker.skip_current_dir();
+        }
+    }
+
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Collects legacy `*_undo` directories at `backup_base_path` and one level below it.
+fn find_legacy_undo_snapshot_dirs(backup_base_path: &Path) -> RestoreResultT<Vec<PathBuf>> {
+    let undo_root = backup_base_path.join(UNDO_SNAPSHOT_ROOT);
+
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(backup_base_path)? {
+        let path = entry?.path();
+        if path == undo_root {
+            continue;
+        }
+        if is_undo_dir(&path) {
+            dirs.push(path);
+        } else if path.is_dir() {
+            for sub_entry in fs::read_dir(&path)? {
+                let sub_path = sub_entry?.path();
+                if is_undo_dir(&sub_path) {
+                    dirs.push(sub_path);
+                }
+            }
+        }
+    }
+
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Deletes the oldest undo snapshots in `undo_snapshot_dir` beyond `retention`.
+fn prune_undo_snapshot_dir(
+    undo_snapshot_dir: &Path,
+    retention: RetentionMode,
+) -> RestoreResultT<PruneReport> {
+    let mut report = PruneReport::default();
+    if !undo_snapshot_dir.is_dir() {
+        return Ok(report);
+    }
+    report.dirs_scanned = 1;
+
+    let mut snapshots = Vec::new();
+    for entry in fs::read_dir(undo_snapshot_dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !entry.path().is_file() || !is_undo_snapshot_name(&name) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        let created = metadata
+            .created()
+            .or_else(|_| metadata.modified())
+            .unwrap_or_else(|_| std::time::SystemTime::now());
+        snapshots.push((created, name));
+    }
+
+    // Newest first; names (timestamps) break ties within the same instant
+    snapshots.sort_by(|a, b| b.cmp(a));
+
+    let keep = match retention {
+        RetentionMode::Limited(count) => count,
+        RetentionMode::Unlimited => snapshots.len(),
+    };
+    for (_, name) in snapshots.iter().skip(keep) {
+        let snapshot_file = undo_snapshot_dir.join(name);
+        crate::file_ops::delete_file(&snapshot_file)?;
+        let manifest_path = undo_snapshot_manifest_path(&snapshot_file);
+        if manifest_path.exists() {
+            crate::file_ops::delete_file(&manifest_path)?;
+        }
+        report.deleted_count += 1;
+    }
+    report.retained_count = snapshots.len().min(keep);
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
     use crate::backup::create_backup;
     use crate::config as config_module;
@@ -778,160 +2138,962 @@ mod tests {
             .unwrap()
             .write_all(b"game state")
             .unwrap();
+        // 1 KB map chunk, so the save passes the default minimum save size
         File::create(save_dir.join("map/pchunk_0_0.dat"))
             .unwrap()
-            .write_all(b"map data")
+            .write_all(&[b'm'; 1024])
             .unwrap();
     }
 
-    /// Helper to setup test config
-    fn setup_test_config(save_dir: &Path, backup_dir: &Path) {
-        let config = Config::with_paths(
-            save_dir.to_str().unwrap().to_string(),
-            backup_dir.to_str().unwrap().to_string(),
-        );
-        config_module::save_config(&config).unwrap();
-    }
+    /// Helper to setup test config
+    fn setup_test_config(save_dir: &Path, backup_dir: &Path) {
+        let config = Config::with_paths(
+            save_dir.to_str().unwrap().to_string(),
+            backup_dir.to_str().unwrap().to_string(),
+        );
+        config_module::save_config(&config).unwrap();
+    }
+
+    /// Helper to modify save content
+    fn modify_save_content(save_dir: &Path, content: &str) {
+        let save_bin = save_dir.join("save.bin");
+        fs::write(&save_bin, content).unwrap();
+    }
+
+    /// Helper to read save content
+    fn read_save_content(save_dir: &Path) -> String {
+        let save_bin = save_dir.join("save.bin");
+        fs::read_to_string(&save_bin).unwrap()
+    }
+
+    #[test]
+    fn test_generate_undo_snapshot_name_format() {
+        let name = generate_undo_snapshot_name();
+        // Format: undo_{YYYY-MM-DD}_{HH-mm-ss-mmm}
+        assert!(name.starts_with("undo_"));
+        let parts: Vec<&str> = name.split('_').collect();
+        assert_eq!(parts.len(), 3);
+        assert!(parts[1].chars().filter(|&c| c == '-').count() == 2); // Date has 2 dashes
+        assert!(parts[2].chars().filter(|&c| c == '-').count() == 3); // Time has 3 dashes
+        assert!(is_undo_snapshot_name(&name));
+    }
+
+    #[test]
+    fn test_is_undo_snapshot_name() {
+        assert!(is_undo_snapshot_name("undo_2024-12-28_14-30-45-123.tar.gz"));
+        assert!(is_undo_snapshot_name("undo_2024-12-28_14-30-45.tar.gz"));
+        assert!(!is_undo_snapshot_name("undo_2024-12-28_14-30-45-12.tar.gz"));
+        assert!(!is_undo_snapshot_name("undo_notes.tar.gz"));
+        assert!(!is_undo_snapshot_name("undo_2024-12-28_14-30-45.tar.gz.json"));
+        assert!(!is_undo_snapshot_name("Survival_2024-12-28_14-30-45.tar.gz"));
+    }
+
+    #[test]
+    fn test_create_undo_snapshots_in_rapid_succession() {
+        let save_base = TempDir::new().unwrap();
+        let undo_dir = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let snapshot = || {
+            create_undo_snapshot(&save_dir, undo_dir.path(), None, UNDO_SNAPSHOT_PREFIX, false)
+                .unwrap()
+                .unwrap()
+        };
+        let first = snapshot();
+        let second = snapshot();
+
+        assert_ne!(first.name, second.name);
+        assert!(undo_dir.path().join(&first.name).exists());
+        assert!(undo_dir.path().join(&second.name).exists());
+    }
+
+    #[test]
+    fn test_get_undo_snapshot_dir() {
+        let base = Path::new("/backups");
+        let undo_dir = get_undo_snapshot_dir(base, "Survival");
+        assert_eq!(undo_dir, Path::new("/backups/_undo/Survival_undo"));
+
+        // Nested saves mirror their path instead of landing next to the game mode's backups
+        let undo_dir = get_undo_snapshot_dir(base, "Survival/MySave");
+        assert_eq!(undo_dir, Path::new("/backups/_undo/Survival/MySave_undo"));
+
+        // Names that only differ by `/` versus `_` don't collide
+        assert_ne!(get_undo_snapshot_dir(base, "A/B"), get_undo_snapshot_dir(base, "A_B"));
+        assert_eq!(
+            get_nested_undo_snapshot_root(base, "Survival"),
+            Path::new("/backups/_undo/Survival")
+        );
+    }
+
+    #[test]
+    fn test_migrate_undo_snapshot_dirs() {
+        let backup_base = TempDir::new().unwrap();
+        let base = backup_base.path();
+
+        let legacy_flat = base.join("Survival_undo");
+        let legacy_nested = base.join("Sandbox/MySave_undo");
+        fs::create_dir_all(&legacy_flat).unwrap();
+        fs::create_dir_all(&legacy_nested).unwrap();
+        fs::write(legacy_flat.join("undo_2024-12-28_10-00-00.tar.gz"), "a").unwrap();
+        fs::write(legacy_nested.join("undo_2024-12-28_11-00-00.tar.gz"), "b").unwrap();
+        fs::write(legacy_nested.join("undo_2024-12-28_12-00-00.tar.gz"), "old").unwrap();
+
+        // A snapshot that already exists at the new location is kept
+        let new_nested = get_undo_snapshot_dir(base, "Sandbox/MySave");
+        fs::create_dir_all(&new_nested).unwrap();
+        fs::write(new_nested.join("undo_2024-12-28_12-00-00.tar.gz"), "new").unwrap();
+
+        assert_eq!(migrate_undo_snapshot_dirs_in(base).unwrap(), 2);
+
+        assert!(!legacy_flat.exists());
+        assert!(!legacy_nested.exists());
+        assert!(base.join("Sandbox").exists());
+        let new_flat = get_undo_snapshot_dir(base, "Survival");
+        assert!(new_flat.join("undo_2024-12-28_10-00-00.tar.gz").exists());
+        assert!(new_nested.join("undo_2024-12-28_11-00-00.tar.gz").exists());
+        assert_eq!(
+            fs::read_to_string(new_nested.join("undo_2024-12-28_12-00-00.tar.gz")).unwrap(),
+            "new"
+        );
+
+        // Running again finds nothing left to migrate
+        assert_eq!(migrate_undo_snapshot_dirs_in(base).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_migrate_flattened_undo_snapshot_dirs() {
+        let backup_base = TempDir::new().unwrap();
+        let base = backup_base.path();
+        let undo_root = base.join(UNDO_SNAPSHOT_ROOT);
+
+        // "Sandbox_MySave" can only be the nested save, which has backups
+        fs::create_dir_all(base.join("Sandbox/MySave")).unwrap();
+        let flattened = undo_root.join("Sandbox_MySave_undo");
+        fs::create_dir_all(&flattened).unwrap();
+        fs::write(flattened.join("undo_2024-12-28_10-00-00.tar.gz"), "a").unwrap();
+
+        // A top-level save with an underscore is already in the right place
+        fs::create_dir_all(base.join("Builder_Two")).unwrap();
+        fs::create_dir_all(undo_root.join("Builder_Two_undo")).unwrap();
+
+        // Without backups the original name can't be told apart, so it stays
+        fs::create_dir_all(undo_root.join("Gone_Save_undo")).unwrap();
+
+        assert_eq!(migrate_undo_snapshot_dirs_in(base).unwrap(), 1);
+        assert!(!flattened.exists());
+        assert!(get_undo_snapshot_dir(base, "Sandbox/MySave")
+            .join("undo_2024-12-28_10-00-00.tar.gz")
+            .exists());
+        assert!(get_undo_snapshot_dir(base, "Builder_Two").is_dir());
+        assert!(undo_root.join("Gone_Save_undo").is_dir());
+
+        // Nested undo directories are found when pruning or listing all snapshots
+        assert_eq!(
+            find_undo_snapshot_dirs(base).unwrap(),
+            vec![
+                undo_root.join("Builder_Two_undo"),
+                undo_root.join("Gone_Save_undo"),
+                get_undo_snapshot_dir(base, "Sandbox/MySave"),
+            ]
+        );
+        assert_eq!(migrate_undo_snapshot_dirs_in(base).unwrap(), 0);
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    fn test_check_game_running_with_custom_process_name() {
+        // Spawn a uniquely named process standing in for a game launcher
+        let temp_dir = TempDir::new().unwrap();
+        let launcher = temp_dir.path().join("pzlaunchtest");
+        fs::copy("/bin/sleep", &launcher).unwrap();
+        let mut child = std::process::Command::new(&launcher).arg("30").spawn().unwrap();
+
+        let custom_names = vec!["pzlaunchtest".to_string()];
+        let result = check_game_running_with(&custom_names);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert!(result.is_running);
+        assert_eq!(result.process_name.as_deref(), Some("pzlaunchtest"));
+    }
+
+    #[test]
+    fn test_check_game_running_with_unknown_custom_name() {
+        let custom_names = vec!["definitely_not_running_process".to_string()];
+        let result = check_game_running_with(&custom_names);
+        assert_ne!(result.process_name.as_deref(), Some("definitely_not_running_process"));
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn test_find_running_process_windows_unknown_name() {
+        let target_names = vec!["definitely_not_running_process.exe".to_string()];
+        assert_eq!(find_running_process_windows(&target_names), None);
+    }
+
+    #[test]
+    fn test_create_undo_snapshot_when_save_exists() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let undo_snapshot_dir = backup_base.path().join("Survival_undo");
+        let snapshot =
+            create_undo_snapshot(&save_dir, &undo_snapshot_dir, None, UNDO_SNAPSHOT_PREFIX, false)
+                .unwrap();
+
+        assert!(snapshot.is_some());
+        let snapshot_info = snapshot.unwrap();
+        assert!(snapshot_info.name.starts_with("undo_"));
+        assert!(snapshot_info.size_bytes > 0);
+        assert!(!snapshot_info.size_formatted.is_empty());
+    }
+
+    #[test]
+    fn test_undo_snapshot_info_from_path() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let undo_snapshot_dir = backup_base.path().join("Survival_undo");
+        let created = create_undo_snapshot(
+            &save_dir,
+            &undo_snapshot_dir,
+            Some("Before restoring day 3"),
+            UNDO_SNAPSHOT_PREFIX,
+            false,
+        )
+        .unwrap()
+        .unwrap();
+
+        let snapshot_path = undo_snapshot_dir.join(&created.name);
+        let info = UndoSnapshotInfo::from_path(&snapshot_path, "Survival").unwrap();
+        assert_eq!(info.name, created.name);
+        assert_eq!(info.path, snapshot_path.to_string_lossy());
+        assert_eq!(info.size_bytes, fs::metadata(&snapshot_path).unwrap().len());
+        assert_eq!(info.size_formatted, created.size_formatted);
+        assert_eq!(info.created_at, created.created_at);
+        assert_eq!(info.save_name, "Survival");
+        assert_eq!(info.label.as_deref(), Some("Before restoring day 3"));
+    }
+
+    #[test]
+    fn test_create_undo_snapshot_when_save_not_exists() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        let undo_snapshot_dir = backup_base.path().join("Survival_undo");
+
+        let snapshot =
+            create_undo_snapshot(&save_dir, &undo_snapshot_dir, None, UNDO_SNAPSHOT_PREFIX, false)
+                .unwrap();
+
+        assert!(snapshot.is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_creates_undo_snapshot() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        let original_content = read_save_content(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        // Create a backup
+        let backup_result = create_backup("Survival").unwrap();
+        let backup_name = backup_result.backup_name;
+
+        // Modify the save
+        modify_save_content(&save_dir, "modified game state");
+        assert_ne!(read_save_content(&save_dir), original_content);
+
+        // Restore from backup
+        let restore_result = restore_backup("Survival", &backup_name).unwrap();
+
+        assert_eq!(restore_result.save_name, "Survival");
+        assert_eq!(restore_result.backup_name, backup_name);
+        assert!(restore_result.has_undo_snapshot);
+        assert!(restore_result.undo_snapshot_path.is_some());
+
+        // Verify save was restored
+        assert_eq!(read_save_content(&save_dir), original_content);
+
+        // Verify undo snapshot tar.gz file exists (can't read directly from tar.gz)
+        let undo_path = restore_result.undo_snapshot_path.unwrap();
+        let undo_file = Path::new(&undo_path);
+        assert!(undo_file.exists());
+        assert!(undo_path.ends_with(".tar.gz"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_reports_file_changes() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        fs::write(save_dir.join("map/pchunk_0_1.dat"), b"chunk").unwrap();
+
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+
+        // Change one file, delete one and add two new ones
+        modify_save_content(&save_dir, "modified game state");
+        fs::remove_file(save_dir.join("map/pchunk_0_1.dat")).unwrap();
+        fs::write(save_dir.join("map/pchunk_1_0.dat"), b"chunk").unwrap();
+        fs::write(save_dir.join("players.db"), b"players").unwrap();
+
+        let result = restore_backup("Survival", &backup_name).unwrap();
+
+        // save.bin and pchunk_0_0.dat exist before and after
+        assert_eq!(result.overwritten_files, 2);
+        // pchunk_0_1.dat comes back from the backup
+        assert_eq!(result.added_files, 1);
+        // pchunk_1_0.dat and players.db are not in the backup
+        assert_eq!(result.removed_files, 2);
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_verify_after_restore_warns_on_corrupt_save() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        // Back up a save that is missing save.bin
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        fs::remove_file(save_dir.join("save.bin")).unwrap();
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+
+        let options = RestoreOptions {
+            verify_after_restore: true,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_name, &options).unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(matches!(
+            &result.warnings[0],
+            RestoreWarning::SaveHealthCheckFailed(msg) if msg.contains("save.bin")
+        ));
+
+        // Without the option the restore does not check the save
+        let result = restore_backup("Survival", &backup_name).unwrap();
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_verify_after_restore_healthy_save() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        create_test_save(&save_base.path().join("Survival"));
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+
+        let options = RestoreOptions {
+            verify_after_restore: true,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_name, &options).unwrap();
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_as_new_save() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+        modify_save_content(&save_dir, "live game");
+
+        let result = restore_backup_as("Survival", &backup_name, "Survival_copy", false).unwrap();
+
+        let target_dir = save_base.path().join("Survival_copy");
+        assert_eq!(result.save_name, "Survival_copy");
+        assert_eq!(read_save_content(&target_dir), "game state");
+        assert!(!result.has_undo_snapshot);
+        // The original save is left alone
+        assert_eq!(read_save_content(&save_dir), "live game");
+
+        // A second restore into the same target needs overwrite
+        let result = restore_backup_as("Survival", &backup_name, "Survival_copy", false);
+        assert!(matches!(result, Err(RestoreError::TargetExists(_))));
+
+        let result = restore_backup_as("Survival", &backup_name, "Survival_copy", true).unwrap();
+        assert!(result.has_undo_snapshot);
+    }
+
+    #[test]
+    #[serial]
+    fn test_import_save_strips_top_level_dir() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let work_dir = TempDir::new().unwrap();
+        setup_test_config(save_base.path(), backup_base.path());
+
+        // An archive made elsewhere, with everything under `MySave/`
+        let source = work_dir.path().join("source");
+        create_test_save(&source.join("MySave"));
+        let archive = work_dir.path().join("MySave.tar.gz");
+        create_tar_gz(&source, &archive).unwrap();
+
+        let target_dir = import_save(&archive, "Survival/Imported").unwrap();
+
+        assert_eq!(target_dir, save_base.path().join("Survival/Imported"));
+        assert_eq!(read_save_content(&target_dir), "game state");
+        assert!(!target_dir.join("MySave").exists());
+
+        let result = import_save(&archive, "Survival/Imported");
+        assert!(matches!(result, Err(RestoreError::TargetExists(_))));
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_partial() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_name = create_backup("Survival").unwrap().backup_name;
+        modify_save_content(&save_dir, "live game");
+        fs::write(save_dir.join("map/pchunk_0_0.dat"), b"new chunk").unwrap();
+
+        // A missing path fails before anything is touched
+        let result = restore_partial(
+            "Survival",
+            &backup_name,
+            vec!["map".to_string(), "players.db".to_string()],
+        );
+        match result {
+            Err(RestoreError::BackupNotFound(message)) => {
+                assert!(message.contains("players.db"));
+                assert!(!message.contains("map,"));
+            }
+            other => panic!("expected BackupNotFound, got {:?}", other),
+        }
+        assert!(list_undo_snapshots("Survival").unwrap().is_empty());
+
+        // Only the map directory is rolled back
+        let result = restore_partial("Survival", &backup_name, vec!["map".to_string()]).unwrap();
+        assert_eq!(fs::read(save_dir.join("map/pchunk_0_0.dat")).unwrap().len(), 1024);
+        assert_eq!(read_save_content(&save_dir), "live game");
+        assert_eq!(result.overwritten_files, 1);
+        assert_eq!(result.added_files, 0);
+        assert!(result.has_undo_snapshot);
+
+        // Nothing is staged next to the backup
+        let staging_dir = backup_base.path().join(format!("Survival/{}.partial.tmp", backup_name));
+        assert!(!staging_dir.exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_partial_incremental() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+        create_backup("Survival").unwrap();
+        std::thread::sleep(Duration::from_millis(1100));
+        modify_save_content(&save_dir, "second state");
+        let incremental = crate::backup::create_incremental_backup("Survival").unwrap();
+
+        modify_save_content(&save_dir, "live game");
+        fs::write(save_dir.join("map/pchunk_0_0.dat"), b"new chunk").unwrap();
+
+        // save.bin comes from the incremental backup, the map chunk from its parent
+        let paths = vec!["save.bin".to_string(), "map/pchunk_0_0.dat".to_string()];
+        let result = restore_partial("Survival", &incremental.backup_name, paths).unwrap();
+        assert_eq!(read_save_content(&save_dir), "second state");
+        assert_eq!(fs::read(save_dir.join("map/pchunk_0_0.dat")).unwrap().len(), 1024);
+        assert_eq!(result.overwritten_files, 2);
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_when_save_not_exists() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        // Create a backup
+        let backup_result = create_backup("Survival").unwrap();
+        let backup_name = backup_result.backup_name;
+
+        // Delete the save
+        delete_dir_recursive(&save_dir).unwrap();
+        assert!(!save_dir.exists());
+
+        // Restore from backup (should work without undo snapshot)
+        let restore_result = restore_backup("Survival", &backup_name).unwrap();
+
+        assert_eq!(restore_result.save_name, "Survival");
+        assert!(!restore_result.has_undo_snapshot);
+        assert!(restore_result.undo_snapshot_path.is_none());
+
+        // Verify save was restored
+        assert!(save_dir.exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_zip_backup() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let options = crate::backup::BackupOptions {
+            compression: crate::file_ops::ArchiveFormat::Zip,
+            verify_after_create: true,
+            ..Default::default()
+        };
+        let backup_result =
+            crate::backup::create_backup_with_options("Survival", &options).unwrap();
+        assert!(backup_result.backup_name.ends_with(".zip"));
+        assert_eq!(crate::backup::list_backups("Survival").unwrap().len(), 1);
+
+        modify_save_content(&save_dir, "modified");
+        restore_backup("Survival", &backup_result.backup_name).unwrap();
+
+        assert_eq!(read_save_content(&save_dir), "game state");
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_not_found() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let result = restore_backup("Survival", "NonExistent");
+        assert!(matches!(result, Err(RestoreError::BackupNotFound(_))));
+    }
+
+    /// Helper to corrupt a tar.gz file's gzip CRC32 trailer
+    fn corrupt_archive_checksum(archive: &Path) {
+        let mut bytes = fs::read(archive).unwrap();
+        let crc_index = bytes.len() - 8;
+        bytes[crc_index] ^= 0xFF;
+        fs::write(archive, bytes).unwrap();
+    }
+
+    /// Helper to spawn a uniquely named process and register it as a game process
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    fn spawn_fake_game(
+        name: &str,
+        save_dir: &Path,
+        backup_dir: &Path,
+    ) -> (TempDir, std::process::Child) {
+        let temp_dir = TempDir::new().unwrap();
+        let binary = temp_dir.path().join(name);
+        fs::copy("/bin/sleep", &binary).unwrap();
+        let child = std::process::Command::new(&binary).arg("30").spawn().unwrap();
+
+        let mut config = Config::with_paths(
+            save_dir.to_str().unwrap().to_string(),
+            backup_dir.to_str().unwrap().to_string(),
+        );
+        config.custom_game_process_names = vec![name.to_string()];
+        config_module::save_config(&config).unwrap();
+
+        (temp_dir, child)
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_without_undo_snapshot() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        let original_content = read_save_content(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
+
+        let options = RestoreOptions {
+            create_undo_snapshot: false,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options).unwrap();
+
+        assert!(!result.has_undo_snapshot);
+        assert!(result.undo_snapshot_path.is_none());
+        assert_eq!(read_save_content(&save_dir), original_content);
+        assert!(list_undo_snapshots("Survival").unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_dry_run() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
+
+        let options = RestoreOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options).unwrap();
+
+        assert!(result.dry_run);
+        assert!(result.has_undo_snapshot);
+        assert!(result.undo_snapshot_path.is_none());
+
+        // Nothing on disk changed
+        assert_eq!(read_save_content(&save_dir), "modified game state");
+        assert!(list_undo_snapshots("Survival").unwrap().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_backup_cancelled() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
 
-    /// Helper to modify save content
-    fn modify_save_content(save_dir: &Path, content: &str) {
-        let save_bin = save_dir.join("save.bin");
-        fs::write(&save_bin, content).unwrap();
-    }
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
 
-    /// Helper to read save content
-    fn read_save_content(save_dir: &Path) -> String {
-        let save_bin = save_dir.join("save.bin");
-        fs::read_to_string(&save_bin).unwrap()
+        let options = RestoreOptions {
+            cancel: Some(CancelFlag::new(true.into())),
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options);
+        assert!(matches!(result, Err(RestoreError::FileOp(FileOpsError::Cancelled))));
+
+        // The save is left as it was
+        assert_eq!(read_save_content(&save_dir), "modified game state");
     }
 
     #[test]
-    fn test_generate_undo_snapshot_name_format() {
-        let name = generate_undo_snapshot_name();
-        // Format: undo_{YYYY-MM-DD}_{HH-mm-ss}
-        assert!(name.starts_with("undo_"));
-        let parts: Vec<&str> = name.split('_').collect();
-        assert_eq!(parts.len(), 3);
-        assert!(parts[1].chars().filter(|&c| c == '-').count() == 2); // Date has 2 dashes
-        assert!(parts[2].chars().filter(|&c| c == '-').count() == 2); // Time has 2 dashes
+    #[serial]
+    fn test_restore_backup_cancelled_mid_extraction() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        // Enough chunks that extraction is still running when the flag is set
+        for i in 0..200 {
+            fs::write(save_dir.join(format!("map/chunk_{}.dat", i)), vec![0u8; 64 * 1024])
+                .unwrap();
+        }
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
+        fs::write(save_dir.join("live_only.txt"), b"not in the backup").unwrap();
+
+        // Cancel as soon as the first entry has been extracted over the cleared save
+        let flag = CancelFlag::default();
+        let watcher = {
+            let flag = flag.clone();
+            let save_dir = save_dir.clone();
+            std::thread::spawn(move || {
+                while save_dir.join("live_only.txt").exists() {
+                    std::thread::yield_now();
+                }
+                loop {
+                    let extracted = walkdir::WalkDir::new(&save_dir)
+                        .into_iter()
+                        .filter_map(Result::ok)
+                        .any(|entry| entry.file_type().is_file());
+                    if extracted {
+                        break;
+                    }
+                    std::thread::yield_now();
+                }
+                flag.store(true, std::sync::atomic::Ordering::Relaxed);
+            })
+        };
+
+        let options = RestoreOptions {
+            cancel: Some(flag),
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options);
+        watcher.join().unwrap();
+        assert!(matches!(result, Err(RestoreError::FileOp(FileOpsError::Cancelled))));
+
+        // The partial extraction is discarded and the save put back from the undo snapshot
+        assert_eq!(read_save_content(&save_dir), "modified game state");
+        assert!(save_dir.join("live_only.txt").exists());
+        assert_eq!(list_undo_snapshots("Survival").unwrap().len(), 1);
     }
 
     #[test]
-    fn test_get_undo_snapshot_dir() {
-        let base = Path::new("/backups");
-        let undo_dir = get_undo_snapshot_dir(base, "Survival");
-        assert_eq!(undo_dir, Path::new("/backups/Survival_undo"));
+    #[serial]
+    fn test_restore_backup_verify_checksum() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        let original_content = read_save_content(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
+
+        let options = RestoreOptions {
+            verify_checksum_before_restore: true,
+            ..Default::default()
+        };
+        restore_backup_with_options("Survival", &backup_result.backup_name, &options).unwrap();
+        assert_eq!(read_save_content(&save_dir), original_content);
     }
 
     #[test]
-    fn test_create_undo_snapshot_when_save_exists() {
+    #[serial]
+    fn test_restore_backup_verify_checksum_rejects_corrupt_backup() {
         let save_base = TempDir::new().unwrap();
         let backup_base = TempDir::new().unwrap();
 
         let save_dir = save_base.path().join("Survival");
         create_test_save(&save_dir);
 
-        let undo_snapshot_dir = backup_base.path().join("Survival_undo");
-        let snapshot = create_undo_snapshot(&save_dir, &undo_snapshot_dir).unwrap();
+        setup_test_config(save_base.path(), backup_base.path());
 
-        assert!(snapshot.is_some());
-        let snapshot_info = snapshot.unwrap();
-        assert!(snapshot_info.name.starts_with("undo_"));
-        assert!(snapshot_info.size_bytes > 0);
-        assert!(!snapshot_info.size_formatted.is_empty());
+        let backup_result = create_backup("Survival").unwrap();
+        corrupt_archive_checksum(Path::new(&backup_result.backup_path));
+        modify_save_content(&save_dir, "modified game state");
+
+        let options = RestoreOptions {
+            verify_checksum_before_restore: true,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options);
+        assert!(matches!(result, Err(RestoreError::IntegrityCheckFailed(_))));
+
+        // The current save and undo snapshots are untouched
+        assert_eq!(read_save_content(&save_dir), "modified game state");
+        assert!(list_undo_snapshots("Survival").unwrap().is_empty());
+
+        // Combined with dry_run, verification still runs
+        let options = RestoreOptions {
+            verify_checksum_before_restore: true,
+            dry_run: true,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options);
+        assert!(matches!(result, Err(RestoreError::IntegrityCheckFailed(_))));
     }
 
     #[test]
-    fn test_create_undo_snapshot_when_save_not_exists() {
+    #[serial]
+    fn test_restore_backup_labels_undo_snapshot() {
         let save_base = TempDir::new().unwrap();
         let backup_base = TempDir::new().unwrap();
 
         let save_dir = save_base.path().join("Survival");
-        let undo_snapshot_dir = backup_base.path().join("Survival_undo");
+        create_test_save(&save_dir);
 
-        let snapshot = create_undo_snapshot(&save_dir, &undo_snapshot_dir).unwrap();
+        setup_test_config(save_base.path(), backup_base.path());
 
-        assert!(snapshot.is_none());
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
+
+        let options = RestoreOptions {
+            label: Some("before horde night".to_string()),
+            verify_checksum_before_restore: true,
+            ..Default::default()
+        };
+        restore_backup_with_options("Survival", &backup_result.backup_name, &options).unwrap();
+
+        let snapshots = list_undo_snapshots("Survival").unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].label.as_deref(), Some("before horde night"));
+
+        // Deleting the snapshot also removes its manifest
+        let snapshot_path = PathBuf::from(&snapshots[0].path);
+        delete_undo_snapshot("Survival", &snapshots[0].name).unwrap();
+        assert!(!undo_snapshot_manifest_path(&snapshot_path).exists());
     }
 
     #[test]
     #[serial]
-    fn test_restore_backup_creates_undo_snapshot() {
+    fn test_restore_backup_dry_run_ignores_label() {
         let save_base = TempDir::new().unwrap();
         let backup_base = TempDir::new().unwrap();
 
         let save_dir = save_base.path().join("Survival");
         create_test_save(&save_dir);
-        let original_content = read_save_content(&save_dir);
 
         setup_test_config(save_base.path(), backup_base.path());
 
-        // Create a backup
         let backup_result = create_backup("Survival").unwrap();
-        let backup_name = backup_result.backup_name;
 
-        // Modify the save
-        modify_save_content(&save_dir, "modified game state");
-        assert_ne!(read_save_content(&save_dir), original_content);
+        let options = RestoreOptions {
+            label: Some("unused".to_string()),
+            create_undo_snapshot: false,
+            dry_run: true,
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options).unwrap();
 
-        // Restore from backup
-        let restore_result = restore_backup("Survival", &backup_name).unwrap();
+        assert!(result.dry_run);
+        assert!(!result.has_undo_snapshot);
+        assert!(list_undo_snapshots("Survival").unwrap().is_empty());
+    }
 
-        assert_eq!(restore_result.save_name, "Survival");
-        assert_eq!(restore_result.backup_name, backup_name);
-        assert!(restore_result.has_undo_snapshot);
-        assert!(restore_result.undo_snapshot_path.is_some());
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    #[serial]
+    fn test_restore_backup_fails_while_game_running() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
 
-        // Verify save was restored
-        assert_eq!(read_save_content(&save_dir), original_content);
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
 
-        // Verify undo snapshot tar.gz file exists (can't read directly from tar.gz)
-        let undo_path = restore_result.undo_snapshot_path.unwrap();
-        let undo_file = Path::new(&undo_path);
-        assert!(undo_file.exists());
-        assert!(undo_path.ends_with(".tar.gz"));
+        setup_test_config(save_base.path(), backup_base.path());
+        let backup_result = create_backup("Survival").unwrap();
+
+        let (_bin_dir, mut child) = spawn_fake_game("pznowaittest", save_base.path(), backup_base.path());
+        let result = restore_backup("Survival", &backup_result.backup_name);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert!(matches!(result, Err(RestoreError::GameRunning(_))));
     }
 
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     #[test]
     #[serial]
-    fn test_restore_backup_when_save_not_exists() {
+    fn test_restore_backup_waits_for_game() {
         let save_base = TempDir::new().unwrap();
         let backup_base = TempDir::new().unwrap();
 
         let save_dir = save_base.path().join("Survival");
         create_test_save(&save_dir);
+        let original_content = read_save_content(&save_dir);
 
         setup_test_config(save_base.path(), backup_base.path());
-
-        // Create a backup
         let backup_result = create_backup("Survival").unwrap();
-        let backup_name = backup_result.backup_name;
+        modify_save_content(&save_dir, "modified game state");
 
-        // Delete the save
-        delete_dir_recursive(&save_dir).unwrap();
-        assert!(!save_dir.exists());
+        let (_bin_dir, mut child) = spawn_fake_game("pzwaittest", save_base.path(), backup_base.path());
 
-        // Restore from backup (should work without undo snapshot)
-        let restore_result = restore_backup("Survival", &backup_name).unwrap();
+        // "Close the game" shortly after the restore starts waiting
+        let closer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(1));
+            child.kill().unwrap();
+            child.wait().unwrap();
+        });
 
-        assert_eq!(restore_result.save_name, "Survival");
-        assert!(!restore_result.has_undo_snapshot);
-        assert!(restore_result.undo_snapshot_path.is_none());
+        let options = RestoreOptions {
+            wait_for_game: Some(10),
+            ..Default::default()
+        };
+        let result = restore_backup_with_options("Survival", &backup_result.backup_name, &options);
+        closer.join().unwrap();
 
-        // Verify save was restored
-        assert!(save_dir.exists());
+        assert!(result.is_ok());
+        assert_eq!(read_save_content(&save_dir), original_content);
     }
 
-    #[test]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[tokio::test]
     #[serial]
-    fn test_restore_backup_not_found() {
+    async fn test_restore_backup_async_reports_waiting_for_game() {
         let save_base = TempDir::new().unwrap();
         let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
 
         setup_test_config(save_base.path(), backup_base.path());
+        let backup_result = create_backup("Survival").unwrap();
 
-        let result = restore_backup("Survival", "NonExistent");
-        assert!(matches!(result, Err(RestoreError::BackupNotFound(_))));
+        let (_bin_dir, mut child) =
+            spawn_fake_game("pzwaitevent", save_base.path(), backup_base.path());
+        let closer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_secs(1));
+            child.kill().unwrap();
+            child.wait().unwrap();
+        });
+
+        let waits = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = waits.clone();
+        let on_wait: GameWaitFn = Box::new(move |process_name| {
+            recorded.lock().unwrap().push(process_name.to_string());
+        });
+        let options = RestoreOptions {
+            wait_for_game: Some(10),
+            ..Default::default()
+        };
+        let result = restore_backup_async(
+            "Survival",
+            &backup_result.backup_name,
+            options,
+            Some(on_wait),
+        )
+        .await;
+        closer.join().unwrap();
+
+        assert!(result.is_ok());
+        let waits = waits.lock().unwrap();
+        assert!(!waits.is_empty());
+        assert!(waits.iter().all(|name| name == "pzwaitevent"));
+    }
+
+    #[test]
+    fn test_restore_options_defaults() {
+        let options: RestoreOptions = serde_json::from_str("{}").unwrap();
+        assert!(options.create_undo_snapshot);
+        assert!(!options.verify_checksum_before_restore);
+        assert!(options.wait_for_game.is_none());
+        assert!(options.label.is_none());
+        assert!(!options.dry_run);
     }
 
     #[test]
@@ -964,6 +3126,130 @@ mod tests {
         assert!(snapshots[1].name.starts_with("undo_"));
     }
 
+    #[test]
+    #[serial]
+    fn test_list_undo_snapshots_paged() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let undo_dir = get_undo_snapshot_dir(backup_base.path(), "Survival");
+        for _ in 0..10 {
+            create_undo_snapshot(&save_dir, &undo_dir, None, UNDO_SNAPSHOT_PREFIX, false).unwrap();
+        }
+        let all = list_undo_snapshots("Survival").unwrap();
+        assert_eq!(all.len(), 10);
+
+        let options = ListSnapshotsOptions {
+            limit: 3,
+            offset: 3,
+            ..Default::default()
+        };
+        let page = list_undo_snapshots_paged("Survival", &options).unwrap();
+        assert_eq!(page.total, 10);
+        let names: Vec<&str> = page.items.iter().map(|s| s.name.as_str()).collect();
+        let expected: Vec<&str> = all[3..6].iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, expected);
+
+        // Ascending order starts from the oldest snapshot
+        let options = ListSnapshotsOptions {
+            limit: 3,
+            offset: 8,
+            sort_dir: SortDirection::Ascending,
+        };
+        let page = list_undo_snapshots_paged("Survival", &options).unwrap();
+        let names: Vec<&str> = page.items.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec![all[1].name.as_str(), all[0].name.as_str()]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_reports_pruned_undo_snapshots() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        create_test_save(&save_base.path().join("Survival"));
+
+        let mut config = Config::with_paths(
+            save_base.path().to_str().unwrap().to_string(),
+            backup_base.path().to_str().unwrap().to_string(),
+        );
+        config.undo_snapshot_retention = RetentionMode::Limited(2);
+        config_module::save_config(&config).unwrap();
+        let backup = create_backup("Survival").unwrap();
+
+        let pruned: Vec<usize> = (0..3)
+            .map(|_| {
+                restore_backup("Survival", &backup.backup_name)
+                    .unwrap()
+                    .undo_snapshots_pruned
+            })
+            .collect();
+        assert_eq!(pruned, vec![0, 0, 1]);
+        assert_eq!(list_undo_snapshots("Survival").unwrap().len(), 2);
+    }
+
+    #[test]
+    #[serial]
+    fn test_prune_all_undo_snapshots() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let mut config = Config::with_paths(
+            save_base.path().to_str().unwrap().to_string(),
+            backup_base.path().to_str().unwrap().to_string(),
+        );
+        config.undo_snapshot_retention = RetentionMode::Limited(2);
+        config_module::save_config(&config).unwrap();
+
+        for save_name in ["Survival", "Builder"] {
+            let save_dir = save_base.path().join(save_name);
+            create_test_save(&save_dir);
+            let undo_dir = get_undo_snapshot_dir(backup_base.path(), save_name);
+            for _ in 0..5 {
+                create_undo_snapshot(&save_dir, &undo_dir, None, UNDO_SNAPSHOT_PREFIX, false)
+                    .unwrap();
+            }
+        }
+
+        let report = prune_all_undo_snapshots().unwrap();
+        assert_eq!(report.dirs_scanned, 2);
+        assert_eq!(report.deleted_count, 6);
+        assert_eq!(report.retained_count, 4);
+
+        for save_name in ["Survival", "Builder"] {
+            assert_eq!(list_undo_snapshots(save_name).unwrap().len(), 2);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_list_undo_snapshots_relative_paths() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        let mut config = Config::with_paths(
+            save_base.path().to_str().unwrap().to_string(),
+            backup_base.path().to_str().unwrap().to_string(),
+        );
+        config.use_relative_paths = true;
+        config_module::save_config(&config).unwrap();
+
+        let backup_result = create_backup("Survival").unwrap();
+        restore_backup("Survival", &backup_result.backup_name).unwrap();
+
+        let snapshots = list_undo_snapshots("Survival").unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert!(Path::new(&snapshots[0].path).is_relative());
+        assert!(snapshots[0].resolve_path(backup_base.path()).exists());
+    }
+
     #[test]
     #[serial]
     fn test_list_undo_snapshots_empty() {
@@ -1009,10 +3295,55 @@ mod tests {
         let undo_restore_result = restore_from_undo_snapshot("Survival", snapshot_name).unwrap();
 
         assert_eq!(undo_restore_result.save_name, "Survival");
-        assert!(!undo_restore_result.has_undo_snapshot);
+        assert!(undo_restore_result.has_undo_snapshot);
 
         // Verify we got back the "modified state" content
         assert_eq!(read_save_content(&save_dir), "modified state");
+
+        // The replaced state was kept in a pre-undo snapshot, so the undo can be redone
+        let pre_undo = undo_restore_result.pre_restore_snapshot.unwrap();
+        assert!(pre_undo.name.starts_with("undo_pre_undo_"));
+        assert!(Path::new(&pre_undo.path).exists());
+        assert!(list_undo_snapshots("Survival")
+            .unwrap()
+            .iter()
+            .any(|s| s.name == pre_undo.name));
+
+        restore_from_undo_snapshot("Survival", &pre_undo.name).unwrap();
+        assert_eq!(read_save_content(&save_dir), "another modification");
+    }
+
+    #[test]
+    #[serial]
+    fn test_restore_from_undo_snapshot_with_undo_snapshot() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup_result = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified state");
+        restore_backup("Survival", &backup_result.backup_name).unwrap();
+        let snapshot_name = list_undo_snapshots("Survival").unwrap()[0].name.clone();
+
+        // Ensure the new snapshot gets a different timestamped name
+        std::thread::sleep(Duration::from_millis(1100));
+
+        let options = RestoreOptions {
+            label: Some("redo".to_string()),
+            ..Default::default()
+        };
+        let result = restore_from_undo_snapshot_with_options("Survival", &snapshot_name, &options).unwrap();
+
+        assert!(result.has_undo_snapshot);
+        assert_eq!(read_save_content(&save_dir), "modified state");
+
+        let snapshots = list_undo_snapshots("Survival").unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots.iter().any(|s| s.label.as_deref() == Some("redo")));
     }
 
     #[test]
@@ -1084,6 +3415,15 @@ mod tests {
             backup_name: "Survival_2024-12-28_10-00-00".to_string(),
             undo_snapshot_path: Some("/backups/Survival_undo/undo_2024-12-28_10-05-00".to_string()),
             has_undo_snapshot: true,
+            dry_run: false,
+            pre_restore_snapshot: None,
+            overwritten_files: 2,
+            added_files: 0,
+            removed_files: 0,
+            warnings: vec![RestoreWarning::SaveHealthCheckFailed(
+                "save.bin is missing".to_string(),
+            )],
+            undo_snapshots_pruned: 1,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -1092,6 +3432,7 @@ mod tests {
         assert_eq!(parsed.save_name, "Survival");
         assert!(parsed.has_undo_snapshot);
         assert!(parsed.undo_snapshot_path.is_some());
+        assert_eq!(parsed.warnings, result.warnings);
     }
 
     #[test]
@@ -1103,6 +3444,7 @@ mod tests {
             size_formatted: "2.00 KB".to_string(),
             created_at: "2024-12-28T10:00:00Z".to_string(),
             save_name: "Survival".to_string(),
+            label: None,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -1128,6 +3470,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_restore_error_from_io_error() {
+        let err = RestoreError::from(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"));
+        match err {
+            RestoreError::FileOp(FileOpsError::Io(io_err)) => {
+                assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("unexpected variant: {:?}", other),
+        }
+    }
+
     #[test]
     #[serial]
     fn test_full_restore_cycle() {
@@ -1174,4 +3527,43 @@ mod tests {
         restore_backup("Survival", &backup_v1.backup_name).unwrap();
         assert_eq!(read_save_content(&save_dir), v1_content);
     }
+
+    #[test]
+    #[serial]
+    fn test_preview_restore_lists_changes_without_touching_save() {
+        let save_base = TempDir::new().unwrap();
+        let backup_base = TempDir::new().unwrap();
+        let save_dir = save_base.path().join("Survival");
+        create_test_save(&save_dir);
+        setup_test_config(save_base.path(), backup_base.path());
+
+        let backup = create_backup("Survival").unwrap();
+        modify_save_content(&save_dir, "modified game state");
+        fs::remove_file(save_dir.join("map/pchunk_0_0.dat")).unwrap();
+        fs::write(save_dir.join("notes.txt"), "new").unwrap();
+
+        let preview = preview_restore("Survival", &backup.backup_name).unwrap();
+        assert_eq!(
+            preview.added,
+            vec![RestorePreviewFile {
+                path: "map/pchunk_0_0.dat".to_string(),
+                current_size: None,
+                backup_size: Some(1024),
+            }]
+        );
+        assert_eq!(preview.overwritten.len(), 1);
+        assert_eq!(preview.overwritten[0].path, "save.bin");
+        assert_eq!(preview.overwritten[0].current_size, Some(19));
+        assert_eq!(preview.overwritten[0].backup_size, Some(10));
+        assert_eq!(preview.deleted.len(), 1);
+        assert_eq!(preview.deleted[0].path, "notes.txt");
+        assert_eq!(preview.current_bytes, 19 + 3);
+        assert_eq!(preview.restored_bytes, 1024 + 10);
+        assert_eq!(preview.size_delta_bytes, 1034 - 22);
+
+        // Nothing was restored and no undo snapshot was taken
+        assert_eq!(read_save_content(&save_dir), "modified game state");
+        assert!(!save_dir.join("map/pchunk_0_0.dat").exists());
+        assert!(list_undo_snapshots("Survival").unwrap().is_empty());
+    }
 }
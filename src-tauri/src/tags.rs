@@ -417,6 +417,40 @@ pub fn get_backup_tags(save_name: &str, backup_name: &str) -> TagsResult<Vec<Tag
     Ok(result)
 }
 
+/// Moves backup tags from one save name to another, e.g. after the save's backups
+/// were moved with `rename_save_backups`.
+///
+/// # Arguments
+/// * `old_save_name` - Save name the tags are stored under
+/// * `new_save_name` - Save name to store them under instead
+///
+/// # Returns
+/// `TagsResult<usize>` - Number of backups whose tags were moved
+///
+/// # Behavior
+/// Backups of saves nested below `old_save_name` (e.g. `Survival/MySave` for
+/// `Survival`) move along with it.
+pub fn rename_backup_tags_save(old_save_name: &str, new_save_name: &str) -> TagsResult<usize> {
+    let mut db = load_tags_db()?;
+    let mut moved = 0;
+    for association in &mut db.associations {
+        if let TagTarget::Backup { save_name, .. } = &mut association.target {
+            let Some(rest) = save_name.strip_prefix(old_save_name) else {
+                continue;
+            };
+            if rest.is_empty() || rest.starts_with('/') {
+                *save_name = format!("{}{}", new_save_name, rest);
+                moved += 1;
+            }
+        }
+    }
+
+    if moved > 0 {
+        save_tags_db(&db)?;
+    }
+    Ok(moved)
+}
+
 /// Adds tags to a save.
 ///
 /// # Arguments
@@ -9,6 +9,9 @@ use std::cmp::Ordering;
 const GITHUB_REPO: &str = "woxqaq/project-zombiod-save-auto-backup";
 const GITHUB_API: &str = "https://api.github.com";
 
+/// Environment variables checked (in order) for a proxy URL.
+const PROXY_ENV_VARS: &[&str] = &["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"];
+
 /// GitHub release information from the API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubRelease {
@@ -40,15 +43,32 @@ pub struct UpdateInfo {
 /// - Fetches the latest release from GitHub
 /// - Compares with current version from Cargo.toml
 /// - Skips pre-releases
+/// - Skips the version stored in the config's `skipped_version`
 /// - Returns update info if a newer version is available
 pub async fn check_for_updates() -> Result<UpdateInfo, String> {
-    let current_version = get_current_version();
-    let client = reqwest::Client::builder()
-        .user_agent("pz-backup-tool")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let config = crate::config::load_config().ok();
+    let proxy_url = config.as_ref().and_then(|c| c.proxy_url.as_deref());
+    let skipped_version = config.as_ref().and_then(|c| c.skipped_version.as_deref());
+    let client = build_client(proxy_url)?;
 
-    let url = format!("{}/repos/{}/releases/latest", GITHUB_API, GITHUB_REPO);
+    fetch_update_info(&client, GITHUB_API, &get_current_version(), skipped_version).await
+}
+
+/// Fetches the latest release from `api_base` and compares it with `current_version`.
+///
+/// # Arguments
+/// * `client` - HTTP client to use
+/// * `api_base` - GitHub API base URL (`GITHUB_API` outside of tests)
+/// * `current_version` - Version of the running application
+/// * `skipped_version` - Version the user chose to skip, if any
+async fn fetch_update_info(
+    client: &reqwest::Client,
+    api_base: &str,
+    current_version: &str,
+    skipped_version: Option<&str>,
+) -> Result<UpdateInfo, String> {
+    let current_version = current_version.to_string();
+    let url = format!("{}/repos/{}/releases/latest", api_base, GITHUB_REPO);
 
     let response = client
         .get(&url)
@@ -85,7 +105,11 @@ pub async fn check_for_updates() -> Result<UpdateInfo, String> {
         .strip_prefix('v')
         .unwrap_or(&release.tag_name);
 
-    let has_update = compare_versions(&current_version, latest_version) == Ordering::Less;
+    let is_skipped = skipped_version
+        .map(|v| v.strip_prefix('v').unwrap_or(v))
+        .is_some_and(|v| v == latest_version);
+    let has_update =
+        !is_skipped && compare_versions(&current_version, latest_version) == Ordering::Less;
 
     Ok(UpdateInfo {
         has_update,
@@ -97,6 +121,79 @@ pub async fn check_for_updates() -> Result<UpdateInfo, String> {
     })
 }
 
+/// Checks that the GitHub API can be reached with the current proxy settings.
+///
+/// # Returns
+/// `Result<(), String>` - Ok if GitHub answered, otherwise the failure reason
+pub async fn check_connectivity() -> Result<(), String> {
+    let client = build_client(configured_proxy_url().as_deref())?;
+
+    let response = client
+        .get(GITHUB_API)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach GitHub: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "GitHub API returned error: {}",
+            response.status()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the proxy override from the config, if one is set.
+fn configured_proxy_url() -> Option<String> {
+    crate::config::load_config().ok()?.proxy_url
+}
+
+/// Picks the proxy URL to use for update checks.
+///
+/// # Arguments
+/// * `config_proxy` - Proxy URL from the config (takes precedence)
+/// * `env` - Environment lookup (`std::env::var` outside of tests)
+///
+/// # Returns
+/// `Option<String>` - The first non-empty value of the config override,
+/// `HTTPS_PROXY` or `ALL_PROXY`
+fn resolve_proxy_url(
+    config_proxy: Option<&str>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    config_proxy
+        .map(str::to_string)
+        .into_iter()
+        .chain(PROXY_ENV_VARS.iter().filter_map(|name| env(name)))
+        .map(|url| url.trim().to_string())
+        .find(|url| !url.is_empty())
+}
+
+/// Builds the HTTP client used to talk to GitHub.
+///
+/// # Arguments
+/// * `config_proxy` - Proxy URL from the config, if any
+///
+/// # Behavior
+/// - Uses the config proxy, or else `HTTPS_PROXY` / `ALL_PROXY`
+/// - Otherwise (or if the URL is malformed) reqwest's own proxy detection applies,
+///   e.g. the system proxy settings
+fn build_client(config_proxy: Option<&str>) -> Result<reqwest::Client, String> {
+    let builder = reqwest::Client::builder().user_agent("pz-backup-tool");
+
+    let proxy = resolve_proxy_url(config_proxy, |name| std::env::var(name).ok())
+        .and_then(|url| reqwest::Proxy::all(url).ok());
+    let builder = match proxy {
+        Some(proxy) => builder.proxy(proxy),
+        None => builder,
+    };
+
+    builder
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
 /// Compares two version strings (semantic versioning).
 ///
 /// # Arguments
@@ -141,6 +238,126 @@ pub fn get_current_version() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn env_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| {
+            vars.iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.clone())
+        }
+    }
+
+    #[test]
+    fn test_resolve_proxy_url_prefers_config() {
+        let env = env_from(&[("HTTPS_PROXY", "http://env:8080")]);
+        assert_eq!(
+            resolve_proxy_url(Some("http://config:3128"), &env),
+            Some("http://config:3128".to_string())
+        );
+        assert_eq!(resolve_proxy_url(None, &env), Some("http://env:8080".to_string()));
+        // An empty override falls through to the environment
+        assert_eq!(resolve_proxy_url(Some("  "), &env), Some("http://env:8080".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_proxy_url_all_proxy_fallback() {
+        let env = env_from(&[("ALL_PROXY", "socks5://env:1080")]);
+        assert_eq!(resolve_proxy_url(None, &env), Some("socks5://env:1080".to_string()));
+        assert_eq!(resolve_proxy_url(None, env_from(&[])), None);
+    }
+
+    #[test]
+    fn test_build_client_malformed_proxy_falls_back() {
+        assert!(build_client(Some("not a url")).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_build_client_uses_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy_url = format!("http://user:pass@{}", listener.local_addr().unwrap());
+
+        // Minimal proxy that records the request head and refuses it
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                head.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .unwrap();
+            String::from_utf8_lossy(&head).to_lowercase()
+        });
+
+        let client = build_client(Some(&proxy_url)).unwrap();
+        assert!(client.get(GITHUB_API).send().await.is_err());
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("connect api.github.com:443"));
+        assert!(head.contains("proxy-authorization: basic dxnlcjpwyxnz"));
+    }
+
+    /// Serves `body` as the latest release for `requests` requests and returns the API base URL.
+    fn serve_latest_release(body: &'static str, requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let api_base = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            for _ in 0..requests {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut head = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    head.extend_from_slice(&buf[..n]);
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        api_base
+    }
+
+    #[tokio::test]
+    async fn test_fetch_update_info_respects_skipped_version() {
+        let release = r#"{
+            "tag_name": "v1.2.0",
+            "name": "v1.2.0",
+            "html_url": "https://github.com/example/releases/v1.2.0",
+            "body": "notes",
+            "published_at": "2024-12-28T00:00:00Z",
+            "prerelease": false
+        }"#;
+        let api_base = serve_latest_release(release, 2);
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+
+        let info = fetch_update_info(&client, &api_base, "1.0.0", Some("1.2.0")).await.unwrap();
+        assert!(!info.has_update);
+        assert_eq!(info.latest_version, "1.2.0");
+
+        let info = fetch_update_info(&client, &api_base, "1.0.0", None).await.unwrap();
+        assert!(info.has_update);
+    }
 
     #[test]
     fn test_compare_versions_equal() {
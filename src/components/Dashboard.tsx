@@ -6,6 +6,7 @@ import { SaveSelector } from "./SaveSelector";
 
 interface BackupResult {
   backup_path: string;
+  backup_dir: string;
   backup_name: string;
   retained_count: number;
   deleted_count: number;